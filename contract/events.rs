@@ -0,0 +1,427 @@
+//! Every event the registry emits, and the only place that publishes them.
+//!
+//! Schema (version `EVENT_SCHEMA_VERSION`):
+//! - topics: `(module: Symbol, action: Symbol, subject)`
+//! - data:   `(schema_version: u32, seq: u64, payload)`
+//!
+//! `seq` increases by one per event so indexers can detect gaps; `payload`
+//! is `()` when an event carries nothing beyond its subject. Changing a
+//! layout below means bumping `EVENT_SCHEMA_VERSION`.
+//!
+//! `Config::event_level` trims or widens payloads: `Minimal` sends `()`
+//! everywhere, `Verbose` swaps in the full record for events that have one.
+//! The layouts below are the `Standard` ones.
+
+use soroban_sdk::{symbol_short, Address, BytesN, Env, IntoVal, String, Symbol, TryFromVal, Val};
+
+use crate::{
+    AbsenceAttestation, Config, DataKey, DocType, Document, DocumentStatus, DocumentVersion,
+    EventLevel, Fee, HookMode, Stake,
+};
+
+/// Version of the topic/data layout described above
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Bump and return the event sequence
+fn next_seq(env: &Env) -> u64 {
+    let seq = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::EventSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&DataKey::EventSeq, &seq);
+    seq
+}
+
+/// Configured event level (read fresh so a change applies to the next event)
+fn level(env: &Env) -> EventLevel {
+    env.storage()
+        .instance()
+        .get::<_, Config>(&DataKey::Config)
+        .map_or(EventLevel::Standard, |config| config.event_level)
+}
+
+/// Emit one event in the shared layout and return its sequence number
+fn publish<S, P>(env: &Env, module: Symbol, action: Symbol, subject: S, payload: P) -> u64
+where
+    Val: TryFromVal<Env, S>,
+    Val: TryFromVal<Env, P>,
+{
+    let payload: Val = match level(env) {
+        EventLevel::Minimal => ().into_val(env),
+        _ => payload.into_val(env),
+    };
+    let seq = next_seq(env);
+    env.events()
+        .publish((module, action, subject), (EVENT_SCHEMA_VERSION, seq, payload));
+    seq
+}
+
+/// `publish`, sending `verbose` instead of `payload` at `EventLevel::Verbose`
+fn publish_detailed<S, P, V>(
+    env: &Env,
+    module: Symbol,
+    action: Symbol,
+    subject: S,
+    payload: P,
+    verbose: V,
+) -> u64
+where
+    Val: TryFromVal<Env, S>,
+    Val: TryFromVal<Env, P>,
+    Val: TryFromVal<Env, V>,
+{
+    if level(env) == EventLevel::Verbose {
+        publish(env, module, action, subject, verbose)
+    } else {
+        publish(env, module, action, subject, payload)
+    }
+}
+
+// ---------- CONFIG ----------
+
+pub fn config_updated(env: &Env, owner: &Address, config: &Config) {
+    publish(env, symbol_short!("config"), symbol_short!("updated"), owner.clone(), config.clone());
+}
+
+// ---------- WHITELIST ----------
+
+pub fn whitelist_added(env: &Env, address: &Address) {
+    publish(env, symbol_short!("whitelist"), symbol_short!("added"), address.clone(), ());
+}
+
+pub fn whitelist_removed(env: &Env, address: &Address, reason: &String, removed_at: u64) {
+    let payload = (reason.clone(), removed_at);
+    publish(env, symbol_short!("whitelist"), symbol_short!("removed"), address.clone(), payload);
+}
+
+/// `label` is empty when the label was cleared
+pub fn actor_labeled(env: &Env, address: &Address, label: &String) {
+    let action = symbol_short!("labeled");
+    publish(env, symbol_short!("whitelist"), action, address.clone(), label.clone());
+}
+
+pub fn profile_set(env: &Env, address: &Address, org_name: &String) {
+    let action = symbol_short!("profile");
+    publish(env, symbol_short!("whitelist"), action, address.clone(), org_name.clone());
+}
+
+pub fn type_grant_changed(env: &Env, address: &Address, doc_type: DocType, allowed: bool) {
+    let action = Symbol::new(env, "type_grant");
+    publish(env, symbol_short!("whitelist"), action, address.clone(), (doc_type, allowed));
+}
+
+pub fn whitelist_requested(env: &Env, applicant: &Address, note: &String) {
+    let action = symbol_short!("requested");
+    publish(env, symbol_short!("whitelist"), action, applicant.clone(), note.clone());
+}
+
+pub fn whitelist_request_denied(env: &Env, applicant: &Address, reason: &String) {
+    let action = Symbol::new(env, "request_denied");
+    publish(env, symbol_short!("whitelist"), action, applicant.clone(), reason.clone());
+}
+
+pub fn whitelist_rotated(env: &Env, new: &Address, old: &Address) {
+    publish(env, symbol_short!("whitelist"), symbol_short!("rotated"), new.clone(), old.clone());
+}
+
+// ---------- OWNERSHIP ----------
+
+pub fn ownership_transferred(env: &Env, new_owner: &Address, previous: &Address) {
+    let action = symbol_short!("transfer");
+    publish(env, symbol_short!("ownership"), action, new_owner.clone(), previous.clone());
+}
+
+pub fn ownership_proposed(env: &Env, candidate: &Address, owner: &Address) {
+    let action = symbol_short!("proposed");
+    publish(env, symbol_short!("ownership"), action, candidate.clone(), owner.clone());
+}
+
+pub fn ownership_accepted(env: &Env, new_owner: &Address, previous: &Address) {
+    let action = symbol_short!("accepted");
+    publish(env, symbol_short!("ownership"), action, new_owner.clone(), previous.clone());
+}
+
+pub fn ownership_cancelled(env: &Env, candidate: &Address, owner: &Address) {
+    let action = symbol_short!("cancelled");
+    publish(env, symbol_short!("ownership"), action, candidate.clone(), owner.clone());
+}
+
+// ---------- RECOVERY ----------
+
+pub fn guardians_set(env: &Env, count: u32, threshold: u32) {
+    let subject = env.current_contract_address();
+    let action = symbol_short!("guardians");
+    publish(env, symbol_short!("recovery"), action, subject, (count, threshold));
+}
+
+pub fn recovery_initiated(env: &Env, proposed_owner: &Address, guardian: &Address) {
+    let action = symbol_short!("initiated");
+    publish(env, symbol_short!("recovery"), action, proposed_owner.clone(), guardian.clone());
+}
+
+pub fn recovery_supported(env: &Env, proposed_owner: &Address, guardian: &Address) {
+    let action = symbol_short!("supported");
+    publish(env, symbol_short!("recovery"), action, proposed_owner.clone(), guardian.clone());
+}
+
+/// `previous` is the owner the recovery replaced
+pub fn recovery_finalized(env: &Env, new_owner: &Address, previous: &Address) {
+    let action = symbol_short!("finalized");
+    publish(env, symbol_short!("recovery"), action, new_owner.clone(), previous.clone());
+}
+
+pub fn recovery_vetoed(env: &Env, proposed_owner: &Address, owner: &Address) {
+    let action = symbol_short!("vetoed");
+    publish(env, symbol_short!("recovery"), action, proposed_owner.clone(), owner.clone());
+}
+
+// ---------- DOCUMENTS ----------
+
+/// Returns the event's sequence number, recorded in the store receipt.
+/// Verbose payload: the stored `Document`.
+pub fn document_stored(env: &Env, doc: &Document) -> u64 {
+    let action = symbol_short!("stored");
+    let subject = doc.hash.clone();
+    let added_by = doc.added_by.clone();
+    publish_detailed(env, symbol_short!("document"), action, subject, added_by, doc.clone())
+}
+
+pub fn document_deleted(env: &Env, hash: &String) {
+    publish(env, symbol_short!("document"), symbol_short!("deleted"), hash.clone(), ());
+}
+
+pub fn document_restored(env: &Env, hash: &String, added_by: &Address) {
+    let action = symbol_short!("restored");
+    publish(env, symbol_short!("document"), action, hash.clone(), added_by.clone());
+}
+
+pub fn document_revoked(env: &Env, hash: &String, actor: &Address) {
+    publish(env, symbol_short!("document"), symbol_short!("revoked"), hash.clone(), actor.clone());
+}
+
+/// `note` is empty for quarantines triggered by a whitelist removal
+pub fn document_quarantined(env: &Env, hash: &String, note: &String) {
+    let action = Symbol::new(env, "quarantined");
+    publish(env, symbol_short!("document"), action, hash.clone(), note.clone());
+}
+
+pub fn quarantine_cleared(env: &Env, hash: &String) {
+    publish(env, symbol_short!("document"), symbol_short!("cleared"), hash.clone(), ());
+}
+
+pub fn document_vetoed(env: &Env, hash: &String, reason: &String) {
+    publish(env, symbol_short!("document"), symbol_short!("vetoed"), hash.clone(), reason.clone());
+}
+
+pub fn document_pinned(env: &Env, hash: &String) {
+    publish(env, symbol_short!("document"), symbol_short!("pinned"), hash.clone(), ());
+}
+
+pub fn document_unpinned(env: &Env, hash: &String) {
+    publish(env, symbol_short!("document"), symbol_short!("unpinned"), hash.clone(), ());
+}
+
+pub fn reference_added(env: &Env, hash: &String, uri: &String) {
+    publish(env, symbol_short!("document"), symbol_short!("ref_added"), hash.clone(), uri.clone());
+}
+
+pub fn reference_removed(env: &Env, hash: &String, index: u32) {
+    let action = Symbol::new(env, "ref_removed");
+    publish(env, symbol_short!("document"), action, hash.clone(), index);
+}
+
+pub fn controller_added(env: &Env, hash: &String, controller: &Address) {
+    let action = Symbol::new(env, "controller_added");
+    publish(env, symbol_short!("document"), action, hash.clone(), controller.clone());
+}
+
+pub fn controller_removed(env: &Env, hash: &String, controller: &Address) {
+    let action = Symbol::new(env, "controller_removed");
+    publish(env, symbol_short!("document"), action, hash.clone(), controller.clone());
+}
+
+pub fn document_witnessed(env: &Env, hash: &String, witness: &Address) {
+    let action = symbol_short!("witnessed");
+    publish(env, symbol_short!("document"), action, hash.clone(), witness.clone());
+}
+
+pub fn document_superseded(env: &Env, old_hash: &String, new_hash: &String) {
+    let action = Symbol::new(env, "superseded");
+    publish(env, symbol_short!("document"), action, old_hash.clone(), new_hash.clone());
+}
+
+pub fn deletion_requested(env: &Env, hash: &String, subject: &Address) {
+    let action = Symbol::new(env, "deletion_requested");
+    publish(env, symbol_short!("document"), action, hash.clone(), subject.clone());
+}
+
+pub fn deletion_approved(env: &Env, hash: &String) {
+    publish(env, symbol_short!("document"), symbol_short!("erased"), hash.clone(), ());
+}
+
+pub fn deletion_denied(env: &Env, hash: &String, reason: &String) {
+    let action = Symbol::new(env, "deletion_denied");
+    publish(env, symbol_short!("document"), action, hash.clone(), reason.clone());
+}
+
+pub fn consent_revoked(env: &Env, hash: &String, subject: &Address, reason: &String) {
+    let action = Symbol::new(env, "consent_revoked");
+    let payload = (subject.clone(), reason.clone());
+    publish(env, symbol_short!("document"), action, hash.clone(), payload);
+}
+
+pub fn document_versioned(env: &Env, hash: &String, entry: &DocumentVersion) -> u64 {
+    let action = symbol_short!("versioned");
+    let payload = (entry.version, entry.added_by.clone());
+    publish(env, symbol_short!("document"), action, hash.clone(), payload)
+}
+
+pub fn absence_attested(env: &Env, attestation: &AbsenceAttestation) {
+    let payload = (attestation.ledger_sequence, attestation.timestamp);
+    let subject = attestation.hash.clone();
+    publish(env, symbol_short!("document"), symbol_short!("absent"), subject, payload);
+}
+
+pub fn verification_anchored(env: &Env, hash: &String, status: DocumentStatus) {
+    let action = Symbol::new(env, "verified_at");
+    let payload = (status, env.ledger().sequence(), env.ledger().timestamp());
+    publish(env, symbol_short!("document"), action, hash.clone(), payload);
+}
+
+pub fn document_renamed(env: &Env, hash: &String, old_name: &String, new_name: &String) {
+    let payload = (old_name.clone(), new_name.clone());
+    publish(env, symbol_short!("document"), symbol_short!("renamed"), hash.clone(), payload);
+}
+
+pub fn legal_hold_set(env: &Env, hash: &String, held: bool) {
+    publish(env, symbol_short!("document"), symbol_short!("hold"), hash.clone(), held);
+}
+
+pub fn document_redacted(env: &Env, hash: &String) {
+    publish(env, symbol_short!("document"), symbol_short!("redacted"), hash.clone(), ());
+}
+
+pub fn embargo_lifted(env: &Env, hash: &String) {
+    publish(env, symbol_short!("embargo"), symbol_short!("lifted"), hash.clone(), ());
+}
+
+// ---------- NAMESPACES ----------
+
+pub fn namespace_created(env: &Env, ns: &Symbol, ns_admin: &Address) {
+    let action = symbol_short!("created");
+    publish(env, symbol_short!("namespace"), action, ns.clone(), ns_admin.clone());
+}
+
+pub fn namespace_whitelist_added(env: &Env, ns: &Symbol, address: &Address) {
+    let action = symbol_short!("wl_added");
+    publish(env, symbol_short!("namespace"), action, ns.clone(), address.clone());
+}
+
+pub fn namespace_whitelist_removed(env: &Env, ns: &Symbol, address: &Address) {
+    let action = Symbol::new(env, "wl_removed");
+    publish(env, symbol_short!("namespace"), action, ns.clone(), address.clone());
+}
+
+pub fn namespace_stored(env: &Env, ns: &Symbol, hash: &String, actor: &Address) {
+    let payload = (hash.clone(), actor.clone());
+    publish(env, symbol_short!("namespace"), symbol_short!("stored"), ns.clone(), payload);
+}
+
+pub fn namespace_moved(env: &Env, hash: &String, ns_from: &Symbol, ns_to: &Symbol) {
+    let payload = (ns_from.clone(), ns_to.clone());
+    publish(env, symbol_short!("namespace"), symbol_short!("moved"), hash.clone(), payload);
+}
+
+// ---------- REGISTRY ----------
+
+pub fn registry_sealed(env: &Env, sealed_at: u64, document_count: u64) {
+    let subject = env.current_contract_address();
+    let payload = (sealed_at, document_count);
+    publish(env, symbol_short!("registry"), symbol_short!("sealed"), subject, payload);
+}
+
+pub fn checkpoint_published(env: &Env, id: u32, root: &BytesN<32>, doc_count: u64) {
+    let subject = env.current_contract_address();
+    let payload = (id, root.clone(), doc_count);
+    publish(env, symbol_short!("registry"), symbol_short!("checkpt"), subject, payload);
+}
+
+pub fn registry_decommissioned(env: &Env, successor: &Address) {
+    let subject = env.current_contract_address();
+    let action = Symbol::new(env, "decommissioned");
+    publish(env, symbol_short!("registry"), action, subject, successor.clone());
+}
+
+pub fn maintainer_set(env: &Env, address: &Address, enabled: bool) {
+    let action = symbol_short!("maint");
+    publish(env, symbol_short!("registry"), action, address.clone(), enabled);
+}
+
+pub fn store_hook_set(env: &Env, contract: &Address, mode: HookMode) {
+    let action = symbol_short!("hook_set");
+    publish(env, symbol_short!("registry"), action, contract.clone(), mode);
+}
+
+pub fn store_hook_cleared(env: &Env, contract: &Address) {
+    let action = symbol_short!("hook_clr");
+    publish(env, symbol_short!("registry"), action, contract.clone(), ());
+}
+
+pub fn jurisdiction_allowed(env: &Env, jurisdiction: &Symbol, allowed: bool) {
+    let action = symbol_short!("juris");
+    publish(env, symbol_short!("registry"), action, jurisdiction.clone(), allowed);
+}
+
+pub fn auth_provider_set(env: &Env, contract: &Address) {
+    let action = symbol_short!("acl_set");
+    publish(env, symbol_short!("registry"), action, contract.clone(), ());
+}
+
+pub fn auth_provider_cleared(env: &Env, contract: &Address) {
+    let action = symbol_short!("acl_clr");
+    publish(env, symbol_short!("registry"), action, contract.clone(), ());
+}
+
+// ---------- FEES ----------
+
+pub fn free_quota_used(env: &Env, actor: &Address, free: u32) {
+    publish(env, symbol_short!("fee"), symbol_short!("free"), actor.clone(), free);
+}
+
+pub fn category_fee_set(env: &Env, category: &Symbol, fee: &Fee) {
+    let action = symbol_short!("category");
+    publish(env, symbol_short!("fee"), action, category.clone(), fee.clone());
+}
+
+// ---------- BUNDLES ----------
+
+pub fn bundle_created(env: &Env, bundle_id: &String, creator: &Address) {
+    let action = symbol_short!("created");
+    publish(env, symbol_short!("bundle"), action, bundle_id.clone(), creator.clone());
+}
+
+pub fn bundle_added(env: &Env, bundle_id: &String, hash: &String) {
+    publish(env, symbol_short!("bundle"), symbol_short!("added"), bundle_id.clone(), hash.clone());
+}
+
+pub fn bundle_sealed(env: &Env, bundle_id: &String, member_count: u32) {
+    publish(env, symbol_short!("bundle"), symbol_short!("sealed"), bundle_id.clone(), member_count);
+}
+
+// ---------- STAKES ----------
+
+/// Payload is the stake after the deposit
+pub fn staked(env: &Env, actor: &Address, stake: &Stake) {
+    publish(env, symbol_short!("stake"), symbol_short!("deposit"), actor.clone(), stake.clone());
+}
+
+pub fn unstaked(env: &Env, actor: &Address, amount: i128) {
+    publish(env, symbol_short!("stake"), symbol_short!("withdrawn"), actor.clone(), amount);
+}
+
+pub fn slashed(env: &Env, actor: &Address, amount: i128, reason: &String) {
+    let payload = (amount, reason.clone());
+    publish(env, symbol_short!("stake"), symbol_short!("slashed"), actor.clone(), payload);
+}
@@ -1,5 +1,15 @@
 #![cfg_attr(not(test), no_std)]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{
+    contract, contractimpl, contractmeta, contracttype, symbol_short, token, Address, Bytes,
+    BytesN, Env, IntoVal, InvokeError, Map, String, Symbol, TryFromVal, Val, Vec,
+};
+
+mod events;
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
+
+contractmeta!(key = "interface_version", val = "1");
 
 #[contract]
 pub struct Contract;
@@ -9,31 +19,1162 @@ pub struct Contract;
 enum DataKey {
     /// Instance-scoped owner (no rent burden like maps of docs)
     Owner,
-    /// Persistent map: Document keyed by its hash string
+    /// Persistent map: DocumentCore keyed by its hash string (legacy entries
+    /// written before the core/ext split hold a full `Document`)
     Document(String),
     /// Persistent map: Whitelist keyed by Address (value = bool)
     Whitelist(Address),
+    /// Instance-scoped running count of stored documents
+    DocumentCount,
+    /// Instance-scoped seal record; once present the registry is read-only
+    Sealed,
+    /// Persistent map: NamespaceInfo keyed by namespace symbol
+    Namespace(Symbol),
+    /// Persistent map: Document keyed by (namespace, hash)
+    NsDocument(Symbol, String),
+    /// Persistent map: per-namespace whitelist keyed by (namespace, Address)
+    NsWhitelist(Symbol, Address),
+    /// Persistent map: (namespace, index slot) -> hash
+    NsDocIndex(Symbol, u32),
+    /// Instance: number of index slots ever used in a namespace
+    NsDocIndexCount(Symbol),
+    /// Persistent map: index slot of a namespaced document
+    NsDocSlot(Symbol, String),
+    /// Instance: number of documents currently held by a namespace
+    NsDocCount(Symbol),
+    /// Instance: number of addresses on a namespace whitelist
+    NsWhitelistCount(Symbol),
+    /// Persistent map: creation-order slot -> namespace symbol
+    NamespaceIndex(u32),
+    /// Instance: number of namespaces ever created
+    NamespaceIndexCount,
+    /// Persistent map: number of documents stored by an actor
+    ActorDocCount(Address),
+    /// Persistent map: actor's n-th stored document hash, keyed by (actor, n)
+    ActorDoc(Address, u32),
+    /// Persistent map: non-active document status keyed by hash (missing => Active)
+    Status(String),
+    /// Instance-scoped owner-tunable settings (see `Config`)
+    Config,
+    /// Persistent map: pinned flag keyed by hash (missing => not pinned)
+    Pinned(String),
+    /// Persistent map: last ledger a document is known to live until
+    LiveUntil(String),
+    /// Persistent map: restoration timestamp for owner-restored documents
+    Restored(String),
+    /// Persistent map: external reference URIs keyed by hash
+    DocRefs(String),
+    /// Instance-scoped sequence number of the last emitted event
+    EventSeq,
+    /// Persistent map: controller set keyed by hash (missing => [added_by])
+    Controllers(String),
+    /// Instance-scoped candidate owner of an in-flight two-step transfer
+    PendingOwner,
+    /// Persistent map: most recent whitelist removal keyed by Address
+    LastRemoval(Address),
+    /// Persistent map: times an address was removed from the whitelist
+    PrincipalEpoch(Address),
+    /// Instance-scoped id of the latest checkpoint (0 => none)
+    CheckpointCount,
+    /// Persistent map: published checkpoint keyed by id (1-based)
+    Checkpoint(u32),
+    /// Persistent map: actual ledger registration time of a backdated document
+    RegisteredAt(String),
+    /// Persistent map: documents stored by an actor in a day, keyed by (actor, epoch)
+    EpochCount(Address, u64),
+    /// Persistent map: ledger time of an actor's most recent store
+    LastStore(Address),
+    /// Instance: successor registry set by `decommission`
+    Successor,
+    /// Persistent map: category a document was registered under
+    Category(String),
+    /// Persistent map: per-category fee override
+    CategoryFee(Symbol),
+    /// Persistent map: time before which a document's name is redacted
+    Embargo(String),
+    /// Persistent map: cold fields of a document (see `DocumentExt`)
+    DocumentExt(String),
+    /// Persistent map: compact id interned for a submitter address
+    ActorId(Address),
+    /// Persistent map: submitter address behind a compact id
+    ActorById(u32),
+    /// Instance: next compact actor id to assign
+    ActorIdCount,
+    /// Persistent map: global document index slot -> hash (removed on delete)
+    DocIndex(u32),
+    /// Instance: number of global document index slots assigned
+    DocIndexCount,
+    /// Persistent map: global index slot of a document
+    DocSlot(String),
+    /// Persistent map: whitelist index slot -> address (removed on removal)
+    WhitelistIndex(u32),
+    /// Instance: number of whitelist index slots assigned
+    WhitelistIndexCount,
+    /// Persistent map: whitelist index slot of a currently whitelisted address
+    WhitelistSlot(Address),
+    /// Instance: ActivityInfo timestamps
+    Activity,
+    /// Instance: number of addresses currently on the whitelist
+    WhitelistCount,
+    /// Instance: number of stored documents with `Revoked` status
+    RevokedCount,
+    /// Persistent map: note and time of a manual quarantine
+    QuarantineNote(String),
+    /// Persistent map: end of a whitelisted submission's veto window
+    VetoUntil(String),
+    /// Persistent map: why and when the owner vetoed a submission
+    Veto(String),
+    /// Persistent map: previous key of a rotated whitelisted address
+    RotatedFrom(Address),
+    /// Persistent map: key that replaced a rotated-out address
+    RotatedTo(Address),
+    /// Persistent map: owner-only internal remark on a document
+    OwnerNote(String),
+    /// Persistent map: alternate digest -> primary (sha256) hash
+    Alias(String),
+    /// Persistent map: primary hash -> its alternate digest
+    DocAlias(String),
+    /// Persistent map: Bundle keyed by its id
+    Bundle(String),
+    /// Persistent map: time `address` witnessed a document, keyed by (hash, address)
+    Witness(String, Address),
+    /// Persistent map: number of witnesses of a document
+    WitnessCount(String),
+    /// Persistent map: ledger sequence and event seq of a document's store
+    StoreMeta(String),
+    /// Persistent map: owner-assigned display label of an address
+    ActorLabel(Address),
+    /// Persistent map: self-published ActorProfile of a whitelisted address
+    Profile(Address),
+    /// Persistent map: DocType a document was registered with
+    DocType(String),
+    /// Persistent map: (type, i) -> hash; per-type registration index
+    TypeDoc(DocType, u32),
+    /// Persistent map: number of slots in a type's index
+    TypeDocCount(DocType),
+    /// Persistent map: (address, type) -> bool; type grants for strict mode
+    TypeGrant(Address, DocType),
+    /// Persistent map: hash -> hash of the document it supersedes
+    Supersedes(String),
+    /// Persistent map: hash -> hash of the document that superseded it
+    SupersededBy(String),
+    /// Persistent map: UpdateInfo of a document mutated after registration
+    Updated(String),
+    /// Persistent map: address a document is about (its data subject)
+    Subject(String),
+    /// Persistent map: open DeletionRequest for a document
+    DeletionRequest(String),
+    /// Persistent map: deletion queue slot -> hash
+    DeletionQueue(u32),
+    /// Instance: number of slots ever used in the deletion queue
+    DeletionQueueCount,
+    /// Persistent map: deletion queue slot of a document's open request
+    DeletionSlot(String),
+    /// Persistent map: retention period in seconds per DocType
+    Retention(DocType),
+    /// Persistent map: true while a document is under legal hold
+    LegalHold(String),
+    /// Persistent map: ledger time a document's personal fields were redacted
+    Redacted(String),
+    /// Instance: StoreHook notified after every store
+    StoreHook,
+    /// Instance: ACL contract consulted by `assert_owner_or_whitelisted_actor`
+    AuthProvider,
+    /// Instance: hash-chain head after the most recent registration
+    ChainHead,
+    /// Persistent map: chain head a document's registration extended
+    PrevHead(String),
+    /// Persistent map: client-asserted creation time of a document
+    CreatedAt(String),
+    /// Persistent map: jurisdiction tag of a document
+    Jurisdiction(String),
+    /// Persistent map: language tag of a document
+    Language(String),
+    /// Persistent map: true for jurisdictions documents may be tagged with
+    AllowedJurisdiction(Symbol),
+    /// Persistent map: (jurisdiction, i) -> hash; per-jurisdiction index
+    JurisdictionDoc(Symbol, u32),
+    /// Persistent map: number of slots in a jurisdiction's index
+    JurisdictionDocCount(Symbol),
+    /// Persistent map: a document's slot in its jurisdiction's index
+    JurisdictionSlot(String),
+    /// Instance: ValidationRules applied to new registrations
+    ValidationRules,
+    /// Persistent map: name -> hash of the first live document using it
+    NameIndex(String),
+    /// Persistent map: times a document was renamed
+    RenameCount(String),
+    /// Persistent map: external RFC 3161 timestamp proof (see `attach_tsa_proof`)
+    TsaProof(String),
+    /// Persistent map: views recorded through `record_view`
+    Views(String),
+    /// Persistent map: ledger sequence of the last counted view
+    LastView(String),
+    /// Persistent map: open WhitelistRequest of an applicant
+    WhitelistRequest(Address),
+    /// Persistent map: whitelist request queue slot -> applicant
+    WhitelistRequestQueue(u32),
+    /// Instance: number of slots ever used in the whitelist request queue
+    WhitelistRequestQueueCount,
+    /// Persistent map: queue slot of an applicant's open request
+    WhitelistRequestSlot(Address),
+    /// Persistent map: escrowed access deposit of an actor
+    Stake(Address),
+    /// Instance: DiscountTier list for batch registrations
+    DiscountTiers,
+    /// Temporary map: (actor, idempotency key) -> hash it registered
+    Idem(Address, BytesN<32>),
+    /// Persistent map: documents stored by an actor in a statistics epoch
+    ActorEpochCount(Address, u64),
+    /// Persistent map: (statistics epoch, slot) -> actor active in it
+    EpochActor(u64, u32),
+    /// Persistent map: number of actors active in a statistics epoch
+    EpochActorCount(u64),
+    /// Persistent map: HashAlgo of a document imported through `store_legacy_document`
+    LegacyAlgo(String),
+    /// Instance flag: `legacy_hash_import` was switched off for good
+    LegacyImportClosed,
+    /// Persistent map: tracked live-until ledger of a whitelist entry
+    WhitelistLiveUntil(Address),
+    /// Persistent map: addresses allowed to run maintenance entrypoints
+    Maintainer(Address),
+    /// Persistent map: ConsentRevocation recorded by a document's subject
+    ConsentRevocation(String),
+    /// Persistent map: DocumentVersion keyed by (hash, version), from 1
+    DocVersion(String, u32),
+    /// Persistent map: latest version number of a document (absent => 0)
+    DocVersionCount(String),
+    /// Persistent map: number of `anchor_verification` calls for a document
+    VerificationAnchors(String),
+    /// Persistent map: document ID -> hash (see `document_id`)
+    ById(BytesN<32>),
+    /// Instance: name prefixes only the owner may register
+    ReservedPrefixes,
+    /// Persistent map: stored documents per category
+    CategoryCount(Symbol),
+    /// Instance: every category a document has been filed under, in first-use order
+    Categories,
+    /// Instance: GuardianSet allowed to recover ownership
+    Guardians,
+    /// Instance: in-flight Recovery, if any
+    Recovery,
 }
 
-/// Stored document data
-#[derive(Clone)]
+/// Max documents quarantined per `remove_and_quarantine` call
+const QUARANTINE_BATCH_SIZE: u32 = 10;
+const DAY_IN_LEDGERS: u32 = 17_280;
+/// Length of a daily-limit epoch (`epoch = timestamp / EPOCH_SECS`)
+const EPOCH_SECS: u64 = 86_400;
+/// Length of a statistics epoch (`stats_epoch = timestamp / STATS_EPOCH_SECS`, 30 days)
+const STATS_EPOCH_SECS: u64 = 2_592_000;
+/// TTL (in ledgers) given to a document entry when it is stored
+const DOC_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+/// Remaining TTL (in ledgers) below which an authorizing whitelist entry is bumped
+const WHITELIST_BUMP_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS;
+/// TTL (in ledgers) a whitelist entry is bumped to on authorization
+const WHITELIST_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+/// TTL (in ledgers) of an idempotency key in temporary storage
+const IDEM_TTL_LEDGERS: u32 = DAY_IN_LEDGERS;
+/// Version of the document storage layout (3 = compact core + ext entries)
+const SCHEMA_VERSION: u32 = 3;
+/// Version of the public entrypoint surface; keep the `contractmeta!` below in sync
+const INTERFACE_VERSION: u32 = 1;
+/// Default remaining-TTL threshold below which a document is `NearExpiry`
+const DEFAULT_TTL_WARNING_LEDGERS: u32 = 7 * DAY_IN_LEDGERS;
+/// Max external references per document
+const MAX_DOC_REFS: u32 = 10;
+/// Max controllers per document
+const MAX_CONTROLLERS: u32 = 5;
+/// Max batch fee discount tiers
+const MAX_DISCOUNT_TIERS: u32 = 8;
+/// Denominator of `DiscountTier::discount_bps`
+const BPS_DENOMINATOR: u32 = 10_000;
+/// Max distinct categories tracked by `category_breakdown`
+const MAX_CATEGORIES: u32 = 50;
+/// Max reserved name prefixes
+const MAX_RESERVED_PREFIXES: u32 = 10;
+/// Fixed part of `estimate_entry_size`: entry tags, timestamp and actor id
+const ENTRY_OVERHEAD_BYTES: u32 = 64;
+/// Max byte length of a reserved name prefix
+const MAX_PREFIX_LEN: u32 = 32;
+/// Max versions `DuplicatePolicy::Version` appends to a single document
+const MAX_DOC_VERSIONS: u32 = 50;
+/// Max times a single document may be renamed
+const MAX_RENAMES: u32 = 10;
+/// Max guardians in a recovery set
+const MAX_GUARDIANS: u32 = 10;
+/// Upper bound for `ValidationRules::max_name_len` (and its default)
+const MAX_NAME_LEN: u32 = 256;
+/// Longest hash `ValidationRules` may allow
+const MAX_HASH_LEN: u32 = 128;
+/// Max distinct lengths in `ValidationRules::hash_lengths`
+const MAX_HASH_LENGTHS: u32 = 8;
+/// Max key rotations followed back when checking inherited controller rights
+const MAX_ROTATION_HOPS: u32 = 8;
+/// Max documents in a bundle
+const MAX_BUNDLE_SIZE: u32 = 25;
+/// Max byte length of a whitelist removal reason
+const MAX_REASON_LEN: u32 = 256;
+/// Max byte length of a checkpoint note
+const MAX_NOTE_LEN: u32 = 256;
+/// Max byte length of a single reference URI
+const MAX_URI_LEN: u32 = 256;
+/// Domain tag prefixed to every canonical document encoding
+const DIGEST_DOMAIN: &[u8] = b"AITT-DOC-V1";
+/// Jurisdiction/language reported for documents without that tag
+const UNSPECIFIED_TAG: &str = "unspecified";
+/// Name a redacted document reads back with
+const REDACTION_MARKER: &str = "[REDACTED]";
+/// Max byte length of an actor label
+const MAX_LABEL_LEN: u32 = 64;
+/// Max byte length of an actor profile's organization name
+const MAX_ORG_NAME_LEN: u32 = 128;
+/// Max entries returned by a single listing call
+const MAX_PAGE_SIZE: u32 = 50;
+/// Max supersede links `get_document_chain` may follow
+const MAX_CHAIN_HOPS: u32 = MAX_PAGE_SIZE - 1;
+/// Default batch limits (see `BatchLimits`)
+const DEFAULT_MAX_BATCH_STORE: u32 = 20;
+const DEFAULT_MAX_BATCH_READ: u32 = 50;
+const DEFAULT_MAX_BATCH_WHITELIST: u32 = 20;
+const DEFAULT_MAX_BATCH_BUMP: u32 = 50;
+/// Default window in which a submitter may delete their own document
+const DEFAULT_SELF_DELETE_GRACE_SECS: u64 = 3_600;
+/// Default lifetime of an unanswered subject deletion request (30 days)
+const DEFAULT_DELETION_REQUEST_TTL_SECS: u64 = 2_592_000;
+/// Default wait between reaching the guardian threshold and finalizing (7 days)
+const DEFAULT_RECOVERY_DELAY_SECS: u64 = 604_800;
+/// Default lifetime of an unanswered whitelist request (30 days)
+const DEFAULT_WHITELIST_REQUEST_TTL_SECS: u64 = 2_592_000;
+/// Default wait between leaving the whitelist and withdrawing a stake (7 days)
+const DEFAULT_STAKE_COOLDOWN_SECS: u64 = 604_800;
+
+/// Document data as returned by `read_document` (core + ext assembled)
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct Document {
     pub name: String,
     pub hash: String,
     pub timestamp: u64,
     pub added_by: Address,
+    /// Ledger sequence at registration; 0 for entries stored before it was
+    /// recorded and for restored documents
+    pub sequence: u32,
+    /// Renames through `update_document_name` (at most `MAX_RENAMES`)
+    pub rename_count: u32,
+    /// External timestamp authority proof, if one was anchored
+    pub tsa_proof: TsaAttachment,
 }
 
-/// Result used by verify_document (adds a boolean flag)
+/// Digest of an RFC 3161 token from an external TSA, with the time it attests
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TsaProof {
+    pub token_hash: BytesN<32>,
+    pub tsa_time: u64,
+}
+
+/// TSA proof slot of `Document` and `VerifiedDocument` (an `Option` of a
+/// contract type can't be a `#[contracttype]` field)
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum TsaAttachment {
+    None,
+    Attached(TsaProof),
+}
+
+impl TsaAttachment {
+    /// The proof, if one is attached
+    pub fn proof(&self) -> Option<TsaProof> {
+        match self {
+            TsaAttachment::None => None,
+            TsaAttachment::Attached(proof) => Some(proof.clone()),
+        }
+    }
+}
+
+impl From<Option<TsaProof>> for TsaAttachment {
+    fn from(proof: Option<TsaProof>) -> Self {
+        proof.map_or(TsaAttachment::None, TsaAttachment::Attached)
+    }
+}
+
+/// `Document` as stored under `DataKey::NsDocument` (namespaced documents
+/// can't be renamed)
 #[derive(Clone)]
 #[contracttype]
-pub struct VerifiedDocument {
+struct NsStoredDocument {
+    name: String,
+    hash: String,
+    timestamp: u64,
+    added_by: Address,
+    sequence: u32,
+}
+
+/// `Document` as stored before `sequence` existed: the main entry before the
+/// core/ext split, and namespace entries
+#[derive(Clone)]
+#[contracttype]
+struct LegacyDocument {
+    name: String,
+    hash: String,
+    timestamp: u64,
+    added_by: Address,
+}
+
+/// Hot fields stored under `DataKey::Document`; all verification needs
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DocumentCore {
+    pub hash: String,
+    pub timestamp: u64,
+    pub added_by: Address,
+}
+
+/// Cold fields stored under `DataKey::DocumentExt`, loaded only by full reads
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DocumentExt {
     pub name: String,
+}
+
+/// What `DataKey::Document` holds for new writes: the core with the
+/// submitter interned as a compact id (see `DataKey::ActorById`)
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+struct CompactCore {
+    hash: String,
+    timestamp: u64,
+    actor: u32,
+}
+
+/// Storage layouts a `DataKey::Document` entry may be in, newest first
+enum StoredEntry {
+    /// `CompactCore` (resolved) plus its actor id
+    Compact(DocumentCore, u32),
+    /// Address-bearing `DocumentCore` from before actor interning
+    Split(DocumentCore),
+    /// Full document from before the core/ext split
+    Legacy(LegacyDocument),
+}
+
+/// Result of `attest_absence`: whether `hash` was unregistered at this ledger
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AbsenceAttestation {
+    pub hash: String,
+    pub ledger_sequence: u32,
+    pub timestamp: u64,
+    pub absent: bool,
+}
+
+/// Result used by verify_document (adds a boolean flag)
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VerifiedDocument {
     pub hash: String,
     pub timestamp: u64,
     pub added_by: Address,
     pub verified_document: bool,
+    /// True when the record was re-created by `restore_evicted_document`
+    pub restored: bool,
+    pub restored_at: Option<u64>,
+    /// Ledger time of registration; differs from `timestamp` for backdated imports
+    pub registered_at: u64,
+    /// True for backdated imports (`store_document_backdated`, `init_with_documents`)
+    pub imported: bool,
+    /// True once this registry has been decommissioned
+    pub deprecated: bool,
+    /// Where new registrations live after decommissioning
+    pub successor: Option<Address>,
+    /// Lifecycle status; `verified_document` is true only for `Active`
+    pub status: DocumentStatus,
+    /// Tracked TTL lower bound (see `document_live_until`); 0 when untracked
+    pub live_until_ledger: u32,
+    /// True when within `Config::ttl_warning_ledgers` of archival
+    pub near_expiry: bool,
+    /// True while `read_document` withholds the name under an embargo
+    pub embargoed: bool,
+    /// Addresses that recorded `witness_document` for this hash
+    pub witness_count: u32,
+    /// Type given at registration; `Other` for untyped documents
+    pub doc_type: DocType,
+    /// Ledger sequence at registration (see `Document::sequence`)
+    pub sequence: u32,
+    /// True once `redact_document` removed the name and personal fields
+    pub redacted: bool,
+    pub redacted_at: Option<u64>,
+    /// Client-asserted creation time, if given at registration
+    pub created_at: Option<u64>,
+    /// External timestamp authority proof, if one was anchored
+    pub tsa_proof: TsaAttachment,
+    /// Algorithm of a legacy MD5/SHA-1 import (`HashAlgo::None` for regular
+    /// registrations)
+    pub legacy_hash: HashAlgo,
+    /// True once the subject withdrew consent (the status is unaffected)
+    pub consent_revoked: bool,
+}
+
+/// Everything known about one document, returned by `get_document_full`.
+/// Features a document doesn't use come back empty or as their defaults.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DocumentFull {
+    /// As `read_document` returns it (name withheld while embargoed)
+    pub document: Document,
+    /// As `verify_document` returns it (status, witnesses, TTL, embargo, ...)
+    pub verification: VerifiedDocument,
+    pub controllers: Vec<Address>,
+    pub references: Vec<String>,
+    pub category: Option<Symbol>,
+    pub subject: Option<Address>,
+    pub pinned: bool,
+    pub legal_hold: bool,
+    pub superseded_by: Option<String>,
+    pub update: UpdateInfo,
+}
+
+/// Kind of document, given to `store_document_typed`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DocType {
+    Contract,
+    Certificate,
+    Invoice,
+    Report,
+    Other,
+}
+
+/// Result of `verify_document_detailed`; a missing hash is never conflated
+/// with a revoked or quarantined one
+#[allow(clippy::large_enum_variant)] // contracttype variants can't be boxed
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum VerificationOutcome {
+    NotFound,
+    Found(VerifiedDocument),
+}
+
+/// Compact view of a document for listings (no name string)
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DocumentSummary {
+    pub hash: String,
+    pub timestamp: u64,
+    pub status: DocumentStatus,
+    pub added_by: Address,
+}
+
+/// One line of an auditor's manifest
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AuditEntry {
+    pub hash: String,
+    pub expected_name: String,
+}
+
+/// Usage snapshot returned by `get_actor_stats`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ActorStats {
+    /// Documents stored by the actor (index slots, including later-deleted ones)
+    pub documents_stored: u32,
+    /// Ledger time of the actor's most recent store
+    pub last_store_at: Option<u64>,
+    /// Stores left in the current epoch; None when no daily limit is configured
+    pub remaining_daily_quota: Option<u32>,
+    pub whitelisted: bool,
+}
+
+/// Everything `get_permissions` knows about one address
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Permissions {
+    pub is_owner: bool,
+    pub is_whitelisted: bool,
+    /// Authorized to store (owner, whitelist or auth provider) and the
+    /// registry still accepts stores; the daily limit is reported separately
+    pub can_store: bool,
+    /// May revoke any document (owner only; controllers are per document)
+    pub can_revoke: bool,
+    /// Stores left in the current epoch; None when unlimited
+    pub remaining_quota: Option<u32>,
+    /// Its next store would not be charged a registration fee
+    pub fee_exempt: bool,
+}
+
+/// One page of a cursor-based document listing. `next_cursor` is the slot to
+/// resume from (None at the end); slots are never reused, so deletions
+/// between pages neither skip nor repeat entries.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DocumentPage {
+    pub items: Vec<DocumentSummary>,
+    pub next_cursor: Option<u32>,
+}
+
+/// A whitelisted address with its display label, if one was set
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct WhitelistEntry {
+    pub address: Address,
+    pub label: Option<String>,
+}
+
+/// Last change to a document record, returned by `get_update_info`. An
+/// untouched document reports its registration time and submitter with
+/// `update_count` 0.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct UpdateInfo {
+    pub last_updated: u64,
+    pub updated_by: Address,
+    pub update_count: u32,
+}
+
+/// A data subject's withdrawal of consent, visible through `verify_document`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ConsentRevocation {
+    pub subject: Address,
+    pub reason: String,
+    pub revoked_at: u64,
+    /// Owner's annotation (empty until `annotate_consent_revocation`)
+    pub owner_note: String,
+}
+
+/// A data subject's pending request to erase a document
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DeletionRequest {
+    pub hash: String,
+    pub subject: Address,
+    pub reason: String,
+    pub requested_at: u64,
+    /// Ledger time after which the request can no longer be approved
+    pub expires_at: u64,
+}
+
+/// Batch fee discount for batches of at least `min_docs` documents
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DiscountTier {
+    pub min_docs: u32,
+    /// Discount in basis points (10_000 => free)
+    pub discount_bps: u32,
+}
+
+/// Tokens an actor escrowed through `stake_for_access`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Stake {
+    pub token: Address,
+    pub amount: i128,
+    /// Ledger time of the latest deposit
+    pub staked_at: u64,
+}
+
+/// An address asking the owner to be whitelisted
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct WhitelistRequest {
+    pub applicant: Address,
+    pub note: String,
+    pub requested_at: u64,
+    /// Ledger time after which the request can no longer be approved
+    pub expires_at: u64,
+}
+
+/// One page of open whitelist requests (see `DocumentPage`)
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct WhitelistRequestPage {
+    pub items: Vec<WhitelistRequest>,
+    pub next_cursor: Option<u32>,
+}
+
+/// One page of open deletion requests (see `DocumentPage`)
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DeletionPage {
+    pub items: Vec<DeletionRequest>,
+    pub next_cursor: Option<u32>,
+}
+
+/// What a failing store hook does to the store that triggered it
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum HookMode {
+    /// Hook errors are swallowed; the store goes through
+    BestEffort,
+    /// A hook error aborts the store
+    Strict,
+}
+
+/// Contract whose `on_document_stored(hash, added_by, timestamp)` runs after
+/// every successful store
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct StoreHook {
+    pub contract: Address,
+    pub mode: HookMode,
+}
+
+/// Organization profile an actor publishes about itself. `contact_hash` is a
+/// digest of off-chain contact details, which stay private.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ActorProfile {
+    pub org_name: String,
+    pub contact_hash: BytesN<32>,
+    pub url: String,
+}
+
+/// One page of a cursor-based whitelist listing (see `DocumentPage`)
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct WhitelistPage {
+    pub items: Vec<WhitelistEntry>,
+    pub next_cursor: Option<u32>,
+}
+
+/// Last-activity timestamps returned by `get_activity` (0 => never)
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct ActivityInfo {
+    pub last_store_at: u64,
+    pub last_whitelist_change_at: u64,
+    pub last_ownership_change_at: u64,
+}
+
+/// Which `ActivityInfo` timestamp an operation advances
+enum Activity {
+    Store,
+    WhitelistChange,
+    OwnershipChange,
+}
+
+/// Role under which `assert_owner_or_whitelisted_actor` admitted an actor
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ActorRole {
+    Owner,
+    Whitelisted,
+    /// Admitted by the external auth provider
+    Provider,
+}
+
+/// Registry-wide counters returned by `get_stats`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RegistryStats {
+    pub document_count: u64,
+    /// Addresses on the whitelist (counted from when the counter was added)
+    pub whitelisted_count: u32,
+    pub revoked_count: u32,
+    pub sealed: bool,
+    pub decommissioned: bool,
+    pub schema_version: u32,
+}
+
+/// Contract-level facts returned by `get_contract_info`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ContractInfo {
+    pub interface_version: u32,
+    pub schema_version: u32,
+    pub event_schema_version: u32,
+    pub owner: Address,
+    pub pending_owner: Option<Address>,
+    pub sealed: bool,
+    pub decommissioned: bool,
+    pub document_count: u64,
+    pub whitelisted_count: u32,
+    pub registration_fee: FeeSetting,
+}
+
+/// Per-entry outcome of `audit_documents`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AuditResult {
+    Ok,
+    NotFound,
+    NameMismatch,
+    Revoked,
+    Quarantined,
+    Pending,
+    Vetoed,
+    Erased,
+}
+
+/// Owner-published commitment to the registry's logical state
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Checkpoint {
+    pub id: u32,
+    /// Merkle root over all registered hashes, computed off-chain
+    pub root: BytesN<32>,
+    pub doc_count: u64,
+    pub last_event_seq: u64,
+    pub note: String,
+    pub timestamp: u64,
+}
+
+/// Why and when an address was last removed from the whitelist
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RemovalRecord {
+    pub reason: String,
+    pub removed_at: u64,
+}
+
+/// Why and when a document was put under investigation by `quarantine_document`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct QuarantineRecord {
+    pub note: String,
+    pub quarantined_at: u64,
+}
+
+/// Why and when the owner vetoed a submission with `veto_document`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VetoRecord {
+    pub reason: String,
+    pub vetoed_at: u64,
+}
+
+/// Named set of documents verifiable as a unit; immutable once sealed
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Bundle {
+    pub bundle_id: String,
+    pub name: String,
+    pub creator: Address,
+    pub members: Vec<String>,
+    pub sealed: bool,
+    pub created_at: u64,
+    pub sealed_at: Option<u64>,
+}
+
+/// Ledger facts captured when a document is stored (see `get_receipt`)
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct StoreMeta {
+    pub ledger_sequence: u32,
+    pub event_seq: u64,
+}
+
+/// Everything needed to re-verify a registration later
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Receipt {
+    pub hash: String,
+    pub name: String,
+    pub timestamp: u64,
+    /// Ledger sequence at storage time (0 for documents stored before receipts)
+    pub ledger_sequence: u32,
+    pub contract: Address,
+    /// Sequence of the `stored` event (0 for documents stored before receipts)
+    pub event_seq: u64,
+    /// Client-asserted creation time (`store_document_with_created_at`)
+    pub created_at: Option<u64>,
+    /// Opaque ID for `read_document_by_id` (see `document_id`)
+    pub doc_id: BytesN<32>,
+}
+
+/// Owner-tunable settings, stored in instance storage
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Config {
+    /// Seconds after `timestamp` during which `added_by` may delete the document
+    pub self_delete_grace_secs: u64,
+    /// Remaining ledgers at or below which `document_ttl_status` reports `NearExpiry`
+    pub ttl_warning_ledgers: u32,
+    pub limits: BatchLimits,
+    /// With an auth provider set, ignore the local whitelist entirely
+    /// (otherwise either one admitting the actor is enough)
+    pub auth_provider_exclusive: bool,
+    /// Max documents a whitelisted actor may store per ledger-time day (0 => unlimited)
+    pub daily_store_limit: u32,
+    /// Fee charged per registration by non-owner actors (`FeeSetting::None` => free)
+    pub registration_fee: FeeSetting,
+    /// Added to the registration fee per byte of the document name
+    pub fee_per_byte: i128,
+    /// Registrations per actor exempt from fees (counted over the actor's index)
+    pub free_quota_per_actor: u32,
+    /// Seconds a whitelisted submission stays `Pending` and vetoable (0 => off)
+    pub veto_window_secs: u64,
+    /// Whitelisted actors need an `allow_type_for` grant to store a typed document
+    pub strict_type_permissions: bool,
+    /// Seconds a subject deletion request stays approvable
+    pub deletion_request_ttl_secs: u64,
+    /// Seconds an owner recovery must wait after initiation before finalizing
+    pub recovery_delay_secs: u64,
+    /// Reject registrations and renames reusing a live document's exact name
+    pub unique_names: bool,
+    /// Seconds a whitelist request stays approvable
+    pub whitelist_request_ttl_secs: u64,
+    /// Whitelisted actors need at least this stake to act (`FeeSetting::None` => none)
+    pub access_stake: FeeSetting,
+    /// Seconds after whitelist removal before a stake can be withdrawn
+    pub stake_cooldown_secs: u64,
+    /// How much data events carry (applies to events emitted after the change)
+    pub event_level: EventLevel,
+    /// Owner may import MD5/SHA-1 digests via `store_legacy_document`; once
+    /// switched off it can't be switched back on
+    pub legacy_hash_import: bool,
+    /// What `store_document` does with an already registered hash (applies
+    /// to stores after the change)
+    pub duplicate_policy: DuplicatePolicy,
+}
+
+/// Digest algorithm of a legacy import (hex lengths 32 and 40)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum HashAlgo {
+    /// Not a legacy import; rejected by `store_legacy_document`
+    None,
+    Md5,
+    Sha1,
+}
+
+/// Payload detail of emitted events; topics are the same at every level
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum EventLevel {
+    /// Topics only; the payload is `()`
+    Minimal,
+    /// Topics plus the key fields of each event
+    Standard,
+    /// Full records (e.g. the stored `Document`) where an event has one
+    Verbose,
+}
+
+/// Handling of a `store_document` call for a hash that is already registered
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DuplicatePolicy {
+    /// Panic with "Document already registered"
+    Reject,
+    /// Return the existing receipt and change nothing
+    Ignore,
+    /// Append a `DocumentVersion` (submitter or controller, `Active` documents
+    /// only); `read_document` and `verify_document` show the latest one
+    Version,
+}
+
+/// A later registration of an existing hash under `DuplicatePolicy::Version`.
+/// Version 0 is the original record.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DocumentVersion {
+    pub version: u32,
+    pub name: String,
+    pub added_by: Address,
+    pub timestamp: u64,
+    pub sequence: u32,
+}
+
+/// Registration fee, paid by the storing actor to the owner
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Fee {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// A `Fee` that may be unset. Used for `Config` fields, since
+/// `#[contracttype]` structs can't hold an `Option` of a contract type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum FeeSetting {
+    None,
+    Fixed(Fee),
+}
+
+impl FeeSetting {
+    /// The fee, if one is set
+    pub fn fee(&self) -> Option<Fee> {
+        match self {
+            FeeSetting::None => None,
+            FeeSetting::Fixed(fee) => Some(fee.clone()),
+        }
+    }
+}
+
+/// Max items accepted by each batch entrypoint. Larger batches panic with
+/// "batch too large"; empty batches are a successful no-op.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BatchLimits {
+    pub max_batch_store: u32,
+    pub max_batch_read: u32,
+    pub max_batch_whitelist: u32,
+    pub max_batch_bump: u32,
+}
+
+/// One entry of a `store_documents` batch
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DocumentInput {
+    pub name: String,
+    pub hash: String,
+}
+
+/// One historical registration for `init_with_documents`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ImportedDocument {
+    pub name: String,
+    pub hash: String,
+    /// Original registration time, kept as the document's `timestamp`
+    pub timestamp: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            self_delete_grace_secs: DEFAULT_SELF_DELETE_GRACE_SECS,
+            ttl_warning_ledgers: DEFAULT_TTL_WARNING_LEDGERS,
+            limits: BatchLimits {
+                max_batch_store: DEFAULT_MAX_BATCH_STORE,
+                max_batch_read: DEFAULT_MAX_BATCH_READ,
+                max_batch_whitelist: DEFAULT_MAX_BATCH_WHITELIST,
+                max_batch_bump: DEFAULT_MAX_BATCH_BUMP,
+            },
+            auth_provider_exclusive: false,
+            daily_store_limit: 0,
+            registration_fee: FeeSetting::None,
+            fee_per_byte: 0,
+            free_quota_per_actor: 0,
+            veto_window_secs: 0,
+            strict_type_permissions: false,
+            deletion_request_ttl_secs: DEFAULT_DELETION_REQUEST_TTL_SECS,
+            recovery_delay_secs: DEFAULT_RECOVERY_DELAY_SECS,
+            unique_names: false,
+            whitelist_request_ttl_secs: DEFAULT_WHITELIST_REQUEST_TTL_SECS,
+            access_stake: FeeSetting::None,
+            stake_cooldown_secs: DEFAULT_STAKE_COOLDOWN_SECS,
+            event_level: EventLevel::Standard,
+            legacy_hash_import: false,
+            duplicate_policy: DuplicatePolicy::Reject,
+        }
+    }
+}
+
+/// Lifecycle status of a stored document; only `Active` verifies
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DocumentStatus {
+    Active,
+    Quarantined,
+    /// Withdrawn by a controller or the owner; permanent
+    Revoked,
+    /// Whitelisted submission still inside its veto window (derived, not stored)
+    Pending,
+    /// Vetoed by the owner within the window; permanent
+    Vetoed,
+    /// Soft-deleted on an approved subject request (name wiped); permanent
+    Erased,
+}
+
+/// Liveness of a document entry relative to `Config::ttl_warning_ledgers`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum TtlStatus {
+    Live,
+    NearExpiry,
+    Missing,
+}
+
+/// An independent registry hosted inside this contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct NamespaceInfo {
+    pub admin: Address,
+    pub created_at: u64,
+}
+
+/// Usage numbers for a single namespace, see `namespace_stats`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct NamespaceStats {
+    pub admin: Address,
+    pub created_at: u64,
+    pub document_count: u32,
+    pub whitelist_count: u32,
+}
+
+/// Written once by `seal_registry`; the registry is frozen from then on
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SealInfo {
+    pub sealed_at: u64,
+    pub document_count: u64,
+}
+
+/// Characters a document name may contain
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum NameCharset {
+    /// Any UTF-8
+    Any,
+    /// ASCII 0x20..=0x7e
+    Printable,
+    /// ASCII letters, digits, space and `.`, `_`, `-`
+    Filename,
+}
+
+/// Owner-set checks on the name and hash of every new registration
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ValidationRules {
+    /// Name byte length bounds, inclusive (`max_name_len <= MAX_NAME_LEN`)
+    pub min_name_len: u32,
+    pub max_name_len: u32,
+    pub name_charset: NameCharset,
+    /// Name must contain a `.` with characters on both sides
+    pub require_extension: bool,
+    /// Accepted hash byte lengths; empty accepts any up to `MAX_HASH_LEN`
+    pub hash_lengths: Vec<u32>,
+    /// Hash must be lowercase or uppercase hex digits only
+    pub require_hex: bool,
+}
+
+/// Addresses that may jointly recover ownership (see `set_guardians`)
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GuardianSet {
+    pub guardians: Vec<Address>,
+    /// Supporting guardians needed before `finalize_recovery`
+    pub threshold: u32,
+}
+
+/// An owner recovery started by a guardian
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Recovery {
+    pub proposed_owner: Address,
+    pub initiated_at: u64,
+    /// Guardians backing the recovery, initiator first
+    pub supporters: Vec<Address>,
+}
+
+/// Outcome `store_document` would have right now (see `can_store`)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum StoreCheck {
+    Ok,
+    /// Neither owner, whitelisted nor admitted by the auth provider
+    NotAuthorized,
+    /// The hash is already registered (or taken by an alias)
+    Duplicate,
+    /// The registry is sealed or decommissioned
+    Paused,
+    /// The actor's daily store limit is used up
+    QuotaExceeded,
+    /// The hash fails the current validation rules
+    InvalidHash,
 }
 
 #[contractimpl]
@@ -46,6 +1187,29 @@ impl Contract {
         env.storage().instance().set(&DataKey::Owner, &owner);
     }
 
+    /// `init`, plus importing `documents` as backdated registrations by the
+    /// owner (at most `max_batch_store`; all-or-nothing)
+    pub fn init_with_documents(env: Env, owner: Address, documents: Vec<ImportedDocument>) {
+        Self::init(env.clone(), owner.clone());
+        Self::assert_batch_size(&documents, Self::get_limits(env.clone()).max_batch_store);
+        for doc in documents.iter() {
+            Self::import_document(&env, owner.clone(), doc.name, doc.hash, doc.timestamp);
+        }
+    }
+
+    /// `init`, plus whitelisting `initial_whitelist` in the same invocation
+    /// (at most `max_batch_whitelist` entries; repeats are skipped)
+    pub fn init_with_whitelist(env: Env, owner: Address, initial_whitelist: Vec<Address>) {
+        Self::init(env.clone(), owner);
+        let limit = Self::get_limits(env.clone()).max_batch_whitelist;
+        Self::assert_batch_size(&initial_whitelist, limit);
+        for address in initial_whitelist.iter() {
+            if !Self::is_whitelisted(env.clone(), address.clone()) {
+                Self::add_to_whitelist(&env, address);
+            }
+        }
+    }
+
     /// Internal: fetch owner, ensure they authorized this call
     fn assert_owner(env: &Env) -> Address {
         let owner: Address = env
@@ -57,45 +1221,213 @@ impl Contract {
         owner
     }
    /// Require that `actor` is the owner OR is whitelisted; otherwise panic.
-fn assert_owner_or_whitelisted_actor(env: &Env, actor: &Address) {
+   /// Works for account and contract actors alike (`require_auth` on a contract
+   /// address is satisfied when that contract is the direct caller).
+   /// Returns the role so callers can branch on it without re-reading storage.
+   /// With an auth provider set, it is asked about `action` (see `set_auth_provider`).
+fn assert_owner_or_whitelisted_actor(env: &Env, actor: &Address, action: Symbol) -> ActorRole {
     // Must have signed
     actor.require_auth();
 
-    // Load owner
-    let owner: Address = env
-        .storage()
-        .instance()
-        .get(&DataKey::Owner)
-        .expect("contract not initialized");
-
-    // Owner always allowed
-    if actor == &owner {
-        return;
-    }
-
-    // Otherwise must be whitelisted
-    let allowed = env
-        .storage()
-        .persistent()
-        .get::<_, bool>(&DataKey::Whitelist(actor.clone()))
-        .unwrap_or(false);
-
-    if !allowed {
-        panic!("not authorized: only owner or whitelisted address");
+    match Self::actor_role(env, actor, action) {
+        Some(ActorRole::Whitelisted) => {
+            Self::bump_whitelist_entry(
+                env,
+                actor,
+                WHITELIST_BUMP_THRESHOLD,
+                WHITELIST_BUMP_AMOUNT,
+            );
+            ActorRole::Whitelisted
+        }
+        Some(role) => role,
+        None => panic!("not authorized: only owner or whitelisted address"),
     }
 }
 
+    /// Internal: role `actor` would act under for `action`, without requiring
+    /// its signature (None if it may not act at all)
+    fn actor_role(env: &Env, actor: &Address, action: Symbol) -> Option<ActorRole> {
+        // Load owner
+        let owner: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Owner)
+            .expect("contract not initialized");
 
+        // Owner always allowed
+        if actor == &owner {
+            return Some(ActorRole::Owner);
+        }
+
+        let provider: Option<Address> = env.storage().instance().get(&DataKey::AuthProvider);
+        let exclusive = provider.is_some() && Self::get_config(env.clone()).auth_provider_exclusive;
+
+        // Otherwise must be whitelisted
+        if !exclusive {
+            let allowed = env
+                .storage()
+                .persistent()
+                .get::<_, bool>(&DataKey::Whitelist(actor.clone()))
+                .unwrap_or(false);
+            if allowed && Self::has_access_stake(env, actor) {
+                return Some(ActorRole::Whitelisted);
+            }
+        }
+
+        // ... or admitted by the provider
+        if let Some(provider) = provider {
+            if Self::provider_allows(env, &provider, actor, action) {
+                return Some(ActorRole::Provider);
+            }
+        }
+        None
+    }
+
+    /// Internal: every mutating entrypoint calls this first
+    fn assert_not_sealed(env: &Env) {
+        if env.storage().instance().has(&DataKey::Sealed) {
+            panic!("registry sealed");
+        }
+    }
+
+    /// Internal: block stores and whitelist changes after `decommission`
+    fn assert_not_decommissioned(env: &Env) {
+        if env.storage().instance().has(&DataKey::Successor) {
+            panic!("registry decommissioned");
+        }
+    }
+
+    /// Read-only: sequence number of the most recent event (0 if none yet)
+    pub fn last_event_seq(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get::<_, u64>(&DataKey::EventSeq)
+            .unwrap_or(0)
+    }
+
+
+
+    // ---------- CONFIG ----------
+
+    /// Owner-only: replace the contract configuration
+    pub fn set_config(env: Env, config: Config) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        if let Some(fee) = config.registration_fee.fee() {
+            Self::assert_valid_fee(&fee);
+        }
+        if config.fee_per_byte < 0 {
+            panic!("invalid fee");
+        }
+        if let Some(stake) = config.access_stake.fee() {
+            Self::assert_valid_fee(&stake);
+        }
+        if config.legacy_hash_import {
+            if env.storage().instance().has(&DataKey::LegacyImportClosed) {
+                panic!("legacy import closed");
+            }
+        } else if Self::get_config(env.clone()).legacy_hash_import {
+            env.storage().instance().set(&DataKey::LegacyImportClosed, &true);
+        }
+        env.storage().instance().set(&DataKey::Config, &config);
+        events::config_updated(&env, &owner, &config);
+    }
+
+    /// Read-only: batch size limits enforced by the batch entrypoints
+    pub fn get_limits(env: Env) -> BatchLimits {
+        Self::get_config(env).limits
+    }
+
+    /// Internal: reject batches above `limit`
+    fn assert_batch_size<T>(items: &Vec<T>, limit: u32) {
+        if items.len() > limit {
+            panic!("batch too large");
+        }
+    }
+
+    /// Read-only: current configuration (defaults until `set_config` is called)
+    pub fn get_config(env: Env) -> Config {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .unwrap_or_default()
+    }
 
     // ---------- WHITELIST ----------
 
     /// Owner-only: add address to whitelist (value stored as `true`)
     pub fn whitelist_address(env: Env, address: Address) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
         let _owner = Self::assert_owner(&env);
+        Self::add_to_whitelist(&env, address);
+    }
+
+    /// Internal: write the whitelist entry (value stored as `true`) and emit its event
+    fn add_to_whitelist(env: &Env, address: Address) {
+        if !Self::is_whitelisted(env.clone(), address.clone()) {
+            Self::bump_counter(env, &DataKey::WhitelistCount, 1);
+        }
         let allow = true;
         env.storage()
             .persistent()
-            .set(&DataKey::Whitelist(address), &allow);
+            .set(&DataKey::Whitelist(address.clone()), &allow);
+        Self::record_activity(env, Activity::WhitelistChange);
+        let slot_key = DataKey::WhitelistSlot(address.clone());
+        if !env.storage().persistent().has(&slot_key) {
+            let slot = Self::next_slot(env, &DataKey::WhitelistIndexCount);
+            env.storage()
+                .persistent()
+                .set(&DataKey::WhitelistIndex(slot), &address);
+            env.storage().persistent().set(&slot_key, &slot);
+        }
+        events::whitelist_added(env, &address);
+    }
+
+    /// Owner-only: whitelist several addresses in one call
+    pub fn whitelist_addresses(env: Env, addresses: Vec<Address>) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        Self::assert_batch_size(&addresses, Self::get_limits(env.clone()).max_batch_whitelist);
+        if addresses.is_empty() {
+            return;
+        }
+        let _owner = Self::assert_owner(&env);
+        for address in addresses.iter() {
+            Self::add_to_whitelist(&env, address);
+        }
+    }
+
+    /// Owner-only: make the whitelist exactly `addresses`, returning
+    /// `(added, removed)`. Only actual changes emit events. Entries missing
+    /// from the whitelist index (added before it existed) are left alone.
+    pub fn replace_whitelist(env: Env, addresses: Vec<Address>) -> (u32, u32) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        Self::assert_batch_size(&addresses, Self::get_limits(env.clone()).max_batch_whitelist);
+        let _owner = Self::assert_owner(&env);
+        let total: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::WhitelistIndexCount)
+            .unwrap_or(0);
+        let mut removed = 0;
+        for i in 0..total {
+            let slot: Option<Address> =
+                env.storage().persistent().get(&DataKey::WhitelistIndex(i));
+            if let Some(address) = slot.filter(|address| !addresses.contains(address)) {
+                Self::remove_whitelist_entry(&env, address, String::from_str(&env, ""));
+                removed += 1;
+            }
+        }
+        let mut added = 0;
+        for address in addresses.iter() {
+            if !Self::is_whitelisted(env.clone(), address.clone()) {
+                Self::add_to_whitelist(&env, address);
+                added += 1;
+            }
+        }
+        (added, removed)
     }
 
     /// Read-only: check if address is whitelisted (missing => false)
@@ -114,55 +1446,1012 @@ fn assert_owner_or_whitelisted_actor(env: &Env, actor: &Address) {
 
     /// Owner-only: remove address from whitelist (delete key)
     pub fn remove_from_whitelist(env: Env, address: Address) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        Self::remove_whitelist_entry(&env, address, String::from_str(&env, ""));
+    }
+
+    /// Owner-only: remove address from whitelist, recording why
+    pub fn remove_whitelisted_with_reason(env: Env, address: Address, reason: String) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
         let _owner = Self::assert_owner(&env);
-        env.storage().persistent().remove(&DataKey::Whitelist(address));
+        if reason.len() > MAX_REASON_LEN {
+            panic!("reason too long");
+        }
+        Self::remove_whitelist_entry(&env, address, reason);
+    }
+
+    /// Whitelisted actor: give up its own access. Treated like an owner removal
+    /// (epoch bump, `RemovalRecord`, event) with reason "renounced".
+    pub fn renounce_whitelist(env: Env, actor: Address) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        actor.require_auth();
+        if actor == Self::owner_address(env.clone()) {
+            panic!("owner cannot renounce");
+        }
+        if !Self::is_whitelisted(env.clone(), actor.clone()) {
+            panic!("not whitelisted");
+        }
+        Self::remove_whitelist_entry(&env, actor, String::from_str(&env, "renounced"));
+    }
+
+    /// Internal: delete the whitelist entry and, if the address was on the
+    /// whitelist, keep a `RemovalRecord`, bump its epoch and emit the removal
+    /// event (plain removals carry an empty reason)
+    fn remove_whitelist_entry(env: &Env, address: Address, reason: String) {
+        let whitelisted = Self::is_whitelisted(env.clone(), address.clone());
+        if whitelisted {
+            Self::bump_counter(env, &DataKey::WhitelistCount, -1);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Whitelist(address.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Profile(address.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::WhitelistLiveUntil(address.clone()));
+        Self::record_activity(env, Activity::WhitelistChange);
+        let slot_key = DataKey::WhitelistSlot(address.clone());
+        if let Some(slot) = env.storage().persistent().get::<_, u32>(&slot_key) {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::WhitelistIndex(slot));
+            env.storage().persistent().remove(&slot_key);
+        }
+        if whitelisted {
+            let record = RemovalRecord {
+                reason,
+                removed_at: env.ledger().timestamp(),
+            };
+            env.storage()
+                .persistent()
+                .set(&DataKey::LastRemoval(address.clone()), &record);
+            let epoch = Self::get_principal_epoch(env.clone(), address.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::PrincipalEpoch(address.clone()), &(epoch + 1));
+            events::whitelist_removed(env, &address, &record.reason, record.removed_at);
+        }
+    }
+
+    /// Read-only: most recent whitelist removal of `address`, if any
+    pub fn get_last_removal(env: Env, address: Address) -> Option<RemovalRecord> {
+        env.storage().persistent().get(&DataKey::LastRemoval(address))
+    }
+
+    /// Read-only: revocation epoch of `address`, bumped on every whitelist
+    /// removal. Rights derived from the address should record the epoch they
+    /// were granted in and lapse once it moves on.
+    pub fn get_principal_epoch(env: Env, address: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PrincipalEpoch(address))
+            .unwrap_or(0)
+    }
+
+    /// Owner-only: remove address from whitelist and quarantine the documents
+    /// it registered. Walks at most `QUARANTINE_BATCH_SIZE` entries of the
+    /// actor's index starting at `cursor`; returns the cursor to resume from,
+    /// or `None` once the whole index has been processed.
+    pub fn remove_and_quarantine(env: Env, address: Address, cursor: u32) -> Option<u32> {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        // continuation calls only walk the index
+        if cursor == 0 {
+            Self::remove_whitelist_entry(&env, address.clone(), String::from_str(&env, ""));
+        }
+
+        let total = Self::actor_document_count(env.clone(), address.clone());
+        let end = total.min(cursor.saturating_add(QUARANTINE_BATCH_SIZE));
+        for i in cursor..end {
+            // deleted documents leave an empty slot behind
+            let slot: Option<String> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ActorDoc(address.clone(), i));
+            // revoked documents keep their (stronger) status
+            let slot = slot.filter(|h| {
+                matches!(
+                    Self::status_of(&env, h),
+                    DocumentStatus::Active | DocumentStatus::Pending
+                )
+            });
+            if let Some(hash) = slot {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Status(hash.clone()), &DocumentStatus::Quarantined);
+                Self::touch_document(&env, &hash, &owner);
+                events::document_quarantined(&env, &hash, &String::from_str(&env, ""));
+            }
+        }
+
+        if end < total {
+            Some(end)
+        } else {
+            None
+        }
+    }
+
+    /// Owner-only: mark a single document as under investigation. Reversible
+    /// with `clear_quarantine`; revoked documents can't be quarantined.
+    pub fn quarantine_document(env: Env, hash: String, note: String) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        if note.len() > MAX_NOTE_LEN {
+            panic!("note too long");
+        }
+        Self::load_document(&env, &hash);
+        match Self::status_of(&env, &hash) {
+            DocumentStatus::Revoked => panic!("document revoked"),
+            DocumentStatus::Vetoed => panic!("document vetoed"),
+            DocumentStatus::Erased => panic!("document erased"),
+            DocumentStatus::Quarantined => panic!("document already quarantined"),
+            DocumentStatus::Active | DocumentStatus::Pending => {}
+        }
+        let record = QuarantineRecord {
+            note,
+            quarantined_at: env.ledger().timestamp(),
+        };
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Status(hash.clone()), &DocumentStatus::Quarantined);
+        storage.set(&DataKey::QuarantineNote(hash.clone()), &record);
+        Self::touch_document(&env, &hash, &owner);
+        Self::extend_document_ttl(&env, &hash, DOC_BUMP_AMOUNT);
+        events::document_quarantined(&env, &hash, &record.note);
+    }
+
+    /// Read-only: note and time of a document's manual quarantine, if any
+    pub fn get_quarantine(env: Env, hash: String) -> Option<QuarantineRecord> {
+        env.storage().persistent().get(&DataKey::QuarantineNote(hash))
+    }
+
+    /// Owner-only: lift the quarantine on a single document
+    pub fn clear_quarantine(env: Env, hash: String) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        let key = DataKey::Status(hash.clone());
+        let status: Option<DocumentStatus> = env.storage().persistent().get(&key);
+        if status != Some(DocumentStatus::Quarantined) {
+            panic!("document not quarantined");
+        }
+        env.storage().persistent().remove(&key);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::QuarantineNote(hash.clone()));
+        Self::touch_document(&env, &hash, &owner);
+        events::quarantine_cleared(&env, &hash);
     }
 
     // ---------- DOCUMENTS ----------
 
     /// Store a document (ONLY OWNER and whitelist).
-    pub fn store_document(env: Env, actor: Address, name: String, hash: String) {
+    ///
+    /// `actor` may also be a whitelisted contract: it passes its own
+    /// `env.current_contract_address()` and is authorized as the direct invoker.
+    pub fn store_document(env: Env, actor: Address, name: String, hash: String) -> Receipt {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
 
-          let key = DataKey::Document(hash.clone());
+        if Self::digest_taken(&env, &hash) {
+            let policy = Self::get_config(env.clone()).duplicate_policy;
+            let registered = env
+                .storage()
+                .persistent()
+                .has(&DataKey::Document(hash.clone()));
+            if policy == DuplicatePolicy::Reject || !registered {
+                panic!("Document already registered");
+            }
+            return Self::store_duplicate(&env, actor, name, hash, policy);
+        }
 
-        if env.storage().persistent().has(&key) {
+    // Enforce permission
+    let role = Self::assert_owner_or_whitelisted_actor(&env, &actor, symbol_short!("store"));
+        Self::consume_daily_quota(&env, &actor, role, 1);
+        let fee = Self::get_config(env.clone()).registration_fee.fee();
+        Self::charge_fee(&env, &actor, role, fee, &Vec::from_array(&env, [name.len()]));
+        let timestamp = env.ledger().timestamp();
+        Self::write_document(&env, actor, role, name, hash, timestamp)
+    }
+
+    /// Internal: `store_document` of a registered hash under `Ignore` or `Version`
+    fn store_duplicate(
+        env: &Env,
+        actor: Address,
+        name: String,
+        hash: String,
+        policy: DuplicatePolicy,
+    ) -> Receipt {
+        let role = Self::assert_owner_or_whitelisted_actor(env, &actor, symbol_short!("store"));
+        if policy == DuplicatePolicy::Ignore {
+            return Self::get_receipt(env.clone(), hash).expect("Document not found");
+        }
+        let original = Self::load_document(env, &hash);
+        if actor != original.added_by && !Self::is_controller(env, &actor, &hash) {
+            panic!("not authorized: only submitter or document controller");
+        }
+        if Self::status_of(env, &hash) != DocumentStatus::Active {
+            panic!("document not active");
+        }
+        Self::assert_valid_name(env, &name);
+        if role != ActorRole::Owner {
+            Self::assert_unreserved_name(env, &name);
+        }
+        Self::claim_name(env, &name, &hash);
+        Self::consume_daily_quota(env, &actor, role, 1);
+        let fee = Self::get_config(env.clone()).registration_fee.fee();
+        Self::charge_fee(env, &actor, role, fee, &Vec::from_array(env, [name.len()]));
+
+        let storage = env.storage().persistent();
+        let count_key = DataKey::DocVersionCount(hash.clone());
+        let version = storage.get::<_, u32>(&count_key).unwrap_or(0) + 1;
+        if version > MAX_DOC_VERSIONS {
+            panic!("too many versions");
+        }
+        let entry = DocumentVersion {
+            version,
+            name,
+            added_by: actor,
+            timestamp: env.ledger().timestamp(),
+            sequence: env.ledger().sequence(),
+        };
+        let version_key = DataKey::DocVersion(hash.clone(), version);
+        storage.set(&version_key, &entry);
+        storage.set(&count_key, &version);
+        storage.extend_ttl(&version_key, DOC_BUMP_AMOUNT, DOC_BUMP_AMOUNT);
+        storage.extend_ttl(&count_key, DOC_BUMP_AMOUNT, DOC_BUMP_AMOUNT);
+        Self::record_activity(env, Activity::Store);
+        let event_seq = events::document_versioned(env, &hash, &entry);
+        Receipt {
+            hash: hash.clone(),
+            name: entry.name,
+            timestamp: entry.timestamp,
+            ledger_sequence: entry.sequence,
+            contract: env.current_contract_address(),
+            event_seq,
+            created_at: Self::document_created_at(env.clone(), hash.clone()),
+            doc_id: Self::document_id(env.clone(), hash),
+        }
+    }
+
+    /// Internal: drop every `DocumentVersion` of a document and its count
+    fn remove_versions(env: &Env, hash: &String) {
+        let storage = env.storage().persistent();
+        let count_key = DataKey::DocVersionCount(hash.clone());
+        let count: u32 = storage.get(&count_key).unwrap_or(0);
+        for version in 1..=count {
+            storage.remove(&DataKey::DocVersion(hash.clone(), version));
+        }
+        storage.remove(&count_key);
+    }
+
+    /// Internal: newest `DocumentVersion` of a document, if it has any
+    fn latest_version(env: &Env, hash: &String) -> Option<DocumentVersion> {
+        let storage = env.storage().persistent();
+        let count: u32 = storage.get(&DataKey::DocVersionCount(hash.clone()))?;
+        storage.get(&DataKey::DocVersion(hash.clone(), count))
+    }
+
+    /// Read-only: every version of a document, oldest (the original record,
+    /// version 0) first. Empty if the hash isn't registered.
+    pub fn get_document_versions(env: Env, hash: String) -> Vec<DocumentVersion> {
+        let hash = Self::resolve_alias(&env, hash);
+        let mut out = Vec::new(&env);
+        let Some(doc) = Self::load_full(&env, &hash) else {
+            return out;
+        };
+        out.push_back(DocumentVersion {
+            version: 0,
+            name: doc.name,
+            added_by: doc.added_by,
+            timestamp: doc.timestamp,
+            sequence: doc.sequence,
+        });
+        let storage = env.storage().persistent();
+        let count: u32 = storage
+            .get(&DataKey::DocVersionCount(hash.clone()))
+            .unwrap_or(0);
+        for version in 1..=count {
+            if let Some(entry) = storage.get(&DataKey::DocVersion(hash.clone(), version)) {
+                out.push_back(entry);
+            }
+        }
+        out
+    }
+
+    /// Owner-only: import a historical registration keeping its original
+    /// timestamp. The actual ledger time is recorded as `registered_at`.
+    pub fn store_document_backdated(env: Env, name: String, hash: String, original_timestamp: u64) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        Self::import_document(&env, owner, name, hash, original_timestamp);
+    }
+
+    /// Internal: validate and write one backdated registration by the owner
+    fn import_document(
+        env: &Env,
+        owner: Address,
+        name: String,
+        hash: String,
+        original_timestamp: u64,
+    ) {
+        if Self::digest_taken(env, &hash) {
+            panic!("Document already registered");
+        }
+        let now = env.ledger().timestamp();
+        if original_timestamp > now {
+            panic!("timestamp in the future");
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::RegisteredAt(hash.clone()), &now);
+        Self::write_document(env, owner, ActorRole::Owner, name, hash, original_timestamp);
+    }
+
+    /// Read-only: ledger time at which a document was actually registered
+    pub fn document_registered_at(env: Env, hash: String) -> Option<u64> {
+        let doc = Self::load_core(&env, &hash)?;
+        Some(
+            env.storage()
+                .persistent()
+                .get(&DataKey::RegisteredAt(hash))
+                .unwrap_or(doc.timestamp),
+        )
+    }
+
+    /// Store several documents in one call (ONLY OWNER and whitelist).
+    /// All-or-nothing: a duplicate anywhere in the batch aborts the call.
+    pub fn store_documents(env: Env, actor: Address, docs: Vec<DocumentInput>) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        Self::assert_batch_size(&docs, Self::get_limits(env.clone()).max_batch_store);
+        if docs.is_empty() {
+            return;
+        }
+        let role = Self::assert_owner_or_whitelisted_actor(&env, &actor, symbol_short!("store"));
+        Self::consume_daily_quota(&env, &actor, role, docs.len());
+        let fee = Self::get_config(env.clone()).registration_fee.fee();
+        let mut name_lens = Vec::new(&env);
+        for input in docs.iter() {
+            name_lens.push_back(input.name.len());
+        }
+        Self::charge_fee(&env, &actor, role, fee, &name_lens);
+        for input in docs.iter() {
+            if Self::digest_taken(&env, &input.hash) {
+                panic!("Document already registered");
+            }
+            let timestamp = env.ledger().timestamp();
+            Self::write_document(&env, actor.clone(), role, input.name, input.hash, timestamp);
+        }
+    }
+
+    /// Internal: checks and charges shared by the single-document store variants
+    /// (writability, duplicate, permission, daily quota, `fee`)
+    fn prepare_store(
+        env: &Env,
+        actor: &Address,
+        name: &String,
+        hash: &String,
+        fee: Option<Fee>,
+    ) -> ActorRole {
+        Self::assert_not_decommissioned(env);
+        Self::assert_not_sealed(env);
+        if Self::digest_taken(env, hash) {
             panic!("Document already registered");
         }
+        let role = Self::assert_owner_or_whitelisted_actor(env, actor, symbol_short!("store"));
+        Self::consume_daily_quota(env, actor, role, 1);
+        Self::charge_fee(env, actor, role, fee, &Vec::from_array(env, [name.len()]));
+        role
+    }
 
-    // Enforce permission
-    Self::assert_owner_or_whitelisted_actor(&env, &actor);
-        let timestamp: u64 = env.ledger().timestamp();
+    /// Internal: persist a new document plus its counters, index slot and event,
+    /// returning its receipt. Callers have already checked permission and
+    /// duplicates; `role` is what that check returned for `actor`.
+    fn write_document(
+        env: &Env,
+        actor: Address,
+        role: ActorRole,
+        name: String,
+        hash: String,
+        timestamp: u64,
+    ) -> Receipt {
+        if env.storage().persistent().has(&DataKey::LegacyAlgo(hash.clone())) {
+            Self::assert_valid_name(env, &name);
+        } else {
+            Self::assert_valid_input(env, &name, &hash);
+        }
+        if role != ActorRole::Owner {
+            Self::assert_unreserved_name(env, &name);
+        }
+        Self::claim_name(env, &name, &hash);
         let doc = Document {
             name,
             hash: hash.clone(),
             timestamp,
             added_by: actor,
+            sequence: env.ledger().sequence(),
+            rename_count: 0,
+            tsa_proof: TsaAttachment::None,
+        };
+        let window = Self::get_config(env.clone()).veto_window_secs;
+        if window > 0 && role != ActorRole::Owner {
+            let veto_until = env.ledger().timestamp().saturating_add(window);
+            env.storage()
+                .persistent()
+                .set(&DataKey::VetoUntil(hash.clone()), &veto_until);
+        }
+        Self::put_document(env, &doc);
+        let side_keys = Self::registration_side_keys(&hash);
+        Self::extend_document_keys(env, &hash, DOC_BUMP_AMOUNT, &side_keys);
+
+        let count = Self::document_count(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::DocumentCount, &(count + 1));
+
+        // Per-actor index (used to walk an actor's documents)
+        let n = Self::actor_document_count(env.clone(), doc.added_by.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::ActorDoc(doc.added_by.clone(), n), &hash);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ActorDocCount(doc.added_by.clone()), &(n + 1));
+
+        Self::record_activity(env, Activity::Store);
+
+        // Global index (used by the cursor-based listing)
+        let slot = Self::next_slot(env, &DataKey::DocIndexCount);
+        env.storage().persistent().set(&DataKey::DocIndex(slot), &hash);
+        env.storage().persistent().set(&DataKey::DocSlot(hash.clone()), &slot);
+        env.storage().persistent().set(
+            &DataKey::LastStore(doc.added_by.clone()),
+            &env.ledger().timestamp(),
+        );
+
+        Self::count_actor_store(env, &doc.added_by);
+        let seq = events::document_stored(env, &doc);
+        let meta = StoreMeta {
+            ledger_sequence: doc.sequence,
+            event_seq: seq,
+        };
+        let meta_key = DataKey::StoreMeta(hash);
+        env.storage().persistent().set(&meta_key, &meta);
+        env.storage()
+            .persistent()
+            .extend_ttl(&meta_key, DOC_BUMP_AMOUNT, DOC_BUMP_AMOUNT);
+        Self::extend_chain(env, &doc);
+        let id = Self::document_id(env.clone(), doc.hash.clone());
+        env.storage().persistent().set(&DataKey::ById(id), &doc.hash);
+        Self::notify_store_hook(env, &doc);
+        Receipt {
+            hash: doc.hash.clone(),
+            name: doc.name,
+            timestamp: doc.timestamp,
+            ledger_sequence: meta.ledger_sequence,
+            contract: env.current_contract_address(),
+            event_seq: seq,
+            created_at: Self::document_created_at(env.clone(), doc.hash.clone()),
+            doc_id: Self::document_id(env.clone(), doc.hash),
+        }
+    }
+
+    /// Submitter-only: delete a document within `self_delete_grace_secs` of
+    /// its registration (inclusive). Removes the record and its index slot.
+    pub fn delete_own_document(env: Env, actor: Address, hash: String) {
+        Self::assert_not_sealed(&env);
+        actor.require_auth();
+
+        let doc = Self::load_document(&env, &hash);
+        if doc.added_by != actor {
+            panic!("not authorized: only the submitter");
+        }
+        if Self::is_pinned(env.clone(), hash.clone()) {
+            panic!("document pinned");
+        }
+        let grace = Self::get_config(env.clone()).self_delete_grace_secs;
+        if env.ledger().timestamp() > doc.timestamp.saturating_add(grace) {
+            panic!("grace period expired");
+        }
+        Self::remove_document(&env, &hash, &actor);
+    }
+
+    /// Internal: hard-delete an existing document stored by `added_by`: the
+    /// record, its side keys, alias and index slots. Emits `deleted`.
+    fn remove_document(env: &Env, hash: &String, added_by: &Address) {
+        Self::assert_not_on_legal_hold(env, hash);
+        let storage = env.storage().persistent();
+        if Self::status_of(env, hash) == DocumentStatus::Revoked {
+            Self::bump_counter(env, &DataKey::RevokedCount, -1);
+        }
+        if let Some(category) = storage.get::<_, Symbol>(&DataKey::Category(hash.clone())) {
+            Self::bump_category_count(env, &category, -1);
+        }
+        storage.remove(&DataKey::Document(hash.clone()));
+        if let Some(alias) = storage.get::<_, String>(&DataKey::DocAlias(hash.clone())) {
+            storage.remove(&DataKey::Alias(alias));
+        }
+        if let Some(slot) = storage.get::<_, u32>(&DataKey::DocSlot(hash.clone())) {
+            storage.remove(&DataKey::DocIndex(slot));
+        }
+        Self::remove_versions(env, hash);
+        for side_key in Self::document_side_keys(hash) {
+            storage.remove(&side_key);
+        }
+        storage.remove(&DataKey::ById(Self::document_id(env.clone(), hash.clone())));
+        let count = Self::document_count(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::DocumentCount, &(count - 1));
+
+        // Recent documents sit at the end of the actor index, so scan backwards
+        let mut i = Self::actor_document_count(env.clone(), added_by.clone());
+        while i > 0 {
+            i -= 1;
+            let slot_key = DataKey::ActorDoc(added_by.clone(), i);
+            if storage.get::<_, String>(&slot_key).as_ref() == Some(hash) {
+                storage.remove(&slot_key);
+                break;
+            }
+        }
+
+        events::document_deleted(env, hash);
+    }
+
+    /// Read-only: number of documents ever stored by `address` (index slots,
+    /// including ones later deleted)
+    pub fn actor_document_count(env: Env, address: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<_, u32>(&DataKey::ActorDocCount(address))
+            .unwrap_or(0)
+    }
+
+    /// Read-only: summaries of the documents stored by `address`, walking its
+    /// index slots `start..start + limit` (deleted slots are skipped)
+    pub fn list_documents_by_actor(
+        env: Env,
+        address: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<DocumentSummary> {
+        let total = Self::actor_document_count(env.clone(), address.clone());
+        let end = total.min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+        let mut out = Vec::new(&env);
+        for i in start..end {
+            let slot: Option<String> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ActorDoc(address.clone(), i));
+            if let Some(summary) = slot.and_then(|h| Self::read_document_summary(env.clone(), h)) {
+                out.push_back(summary);
+            }
+        }
+        out
+    }
+
+    /// Read-only: status of a stored document (None if it doesn't exist)
+    pub fn document_status(env: Env, hash: String) -> Option<DocumentStatus> {
+        if !env.storage().persistent().has(&DataKey::Document(hash.clone())) {
+            return None;
+        }
+        Some(Self::status_of(&env, &hash))
+    }
+
+    /// Internal: stored status of an existing document
+    fn status_of(env: &Env, hash: &String) -> DocumentStatus {
+        if let Some(status) = env.storage().persistent().get(&DataKey::Status(hash.clone())) {
+            return status;
+        }
+        let veto_until: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::VetoUntil(hash.clone()))
+            .unwrap_or(0);
+        if env.ledger().timestamp() < veto_until {
+            DocumentStatus::Pending
+        } else {
+            DocumentStatus::Active
+        }
+    }
+
+    /// Internal: `actor` must have signed and be the owner or one of the
+    /// document's controllers. Returns the (existing) document.
+    fn assert_owner_or_controller(env: &Env, actor: &Address, hash: &String) -> DocumentCore {
+        actor.require_auth();
+        let doc = Self::load_document(env, hash);
+        if actor == &Self::owner_address(env.clone()) || Self::is_controller(env, actor, hash) {
+            return doc;
+        }
+        panic!("not authorized: only owner or document controller");
+    }
+
+    /// Internal: whether `actor`, or a key it was rotated from, controls `hash`
+    fn is_controller(env: &Env, actor: &Address, hash: &String) -> bool {
+        // A rotated-out key loses its rights; its successor inherits them
+        if env.storage().persistent().has(&DataKey::RotatedTo(actor.clone())) {
+            return false;
+        }
+        let controllers = Self::get_document_controllers(env.clone(), hash.clone());
+        let mut key = Some(actor.clone());
+        let mut hops = 0;
+        while let Some(k) = key {
+            if controllers.contains(&k) {
+                return true;
+            }
+            if hops == MAX_ROTATION_HOPS {
+                break;
+            }
+            hops += 1;
+            key = Self::rotated_from(env.clone(), k);
+        }
+        false
+    }
+
+    /// Internal: load a stored document's core or panic
+    fn load_document(env: &Env, hash: &String) -> DocumentCore {
+        Self::load_core(env, hash).expect("document not found")
+    }
+
+    /// Internal: write a document as separate core and ext entries
+    fn put_document(env: &Env, doc: &Document) {
+        let core = CompactCore {
+            hash: doc.hash.clone(),
+            timestamp: doc.timestamp,
+            actor: Self::intern_actor(env, &doc.added_by),
+        };
+        let ext = DocumentExt {
+            name: doc.name.clone(),
         };
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Document(doc.hash.clone()), &core);
+        storage.set(&DataKey::DocumentExt(doc.hash.clone()), &ext);
+    }
+
+    /// Internal: whether a stored struct entry has a field named `field`.
+    /// Decoding a map into a struct with other fields traps rather than
+    /// returning `Err`, so layouts are told apart by their keys first.
+    fn has_field(env: &Env, raw: &Val, field: &str) -> bool {
+        Map::<Symbol, Val>::try_from_val(env, raw)
+            .is_ok_and(|map| map.contains_key(Symbol::new(env, field)))
+    }
+
+    /// Internal: raw main entry, decoded in whichever layout it was written
+    fn load_entry(env: &Env, hash: &String) -> Option<StoredEntry> {
+        let raw: Val = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Document(hash.clone()))?;
+        if Self::has_field(env, &raw, "actor") {
+            let compact = CompactCore::try_from_val(env, &raw).expect("corrupt document entry");
+            let core = DocumentCore {
+                hash: compact.hash,
+                timestamp: compact.timestamp,
+                added_by: Self::actor_by_id(env.clone(), compact.actor)
+                    .expect("unknown actor id"),
+            };
+            return Some(StoredEntry::Compact(core, compact.actor));
+        }
+        if Self::has_field(env, &raw, "name") {
+            let legacy = LegacyDocument::try_from_val(env, &raw).expect("corrupt document entry");
+            return Some(StoredEntry::Legacy(legacy));
+        }
+        let core = DocumentCore::try_from_val(env, &raw).expect("corrupt document entry");
+        Some(StoredEntry::Split(core))
+    }
+
+    /// Internal: hot fields only; never touches the ext entry
+    fn load_core(env: &Env, hash: &String) -> Option<DocumentCore> {
+        Some(match Self::load_entry(env, hash)? {
+            StoredEntry::Compact(core, _) | StoredEntry::Split(core) => core,
+            StoredEntry::Legacy(legacy) => DocumentCore {
+                hash: legacy.hash,
+                timestamp: legacy.timestamp,
+                added_by: legacy.added_by,
+            },
+        })
+    }
+
+    /// Internal: full document, assembling core and ext
+    fn load_full(env: &Env, hash: &String) -> Option<Document> {
+        let entry = Self::load_entry(env, hash)?;
+        let rename_count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RenameCount(hash.clone()))
+            .unwrap_or(0);
+        let tsa_proof: TsaAttachment = Self::document_tsa_proof(env.clone(), hash.clone()).into();
+        Some(match entry {
+            StoredEntry::Compact(core, _) | StoredEntry::Split(core) => {
+                let ext: DocumentExt = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::DocumentExt(hash.clone()))
+                    .expect("document ext missing");
+                Document {
+                    name: ext.name,
+                    hash: core.hash,
+                    timestamp: core.timestamp,
+                    added_by: core.added_by,
+                    sequence: Self::store_sequence(env, hash),
+                    rename_count,
+                    tsa_proof,
+                }
+            }
+            StoredEntry::Legacy(legacy) => Document {
+                name: legacy.name,
+                hash: legacy.hash,
+                timestamp: legacy.timestamp,
+                added_by: legacy.added_by,
+                sequence: 0,
+                rename_count,
+                tsa_proof,
+            },
+        })
+    }
+
+    /// Internal: ledger sequence recorded when `hash` was stored (0 if none)
+    fn store_sequence(env: &Env, hash: &String) -> u32 {
         env.storage()
             .persistent()
-            .set(&DataKey::Document(hash), &doc);
+            .get::<_, StoreMeta>(&DataKey::StoreMeta(hash.clone()))
+            .map_or(0, |meta| meta.ledger_sequence)
+    }
+
+    /// Internal: compact id for `actor`, assigning the next one on first use
+    fn intern_actor(env: &Env, actor: &Address) -> u32 {
+        let storage = env.storage().persistent();
+        let key = DataKey::ActorId(actor.clone());
+        if let Some(id) = storage.get(&key) {
+            return id;
+        }
+        let id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ActorIdCount)
+            .unwrap_or(0);
+        storage.set(&key, &id);
+        storage.set(&DataKey::ActorById(id), actor);
+        env.storage().instance().set(&DataKey::ActorIdCount, &(id + 1));
+        id
+    }
+
+    /// Read-only: compact id interned for `address`, if it has stored anything
+    pub fn actor_id_of(env: Env, address: Address) -> Option<u32> {
+        env.storage().persistent().get(&DataKey::ActorId(address))
+    }
+
+    /// Read-only: submitter address behind a compact actor id
+    pub fn actor_by_id(env: Env, id: u32) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::ActorById(id))
+    }
+
+    /// Read-only: number of documents stored so far
+    pub fn document_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get::<_, u64>(&DataKey::DocumentCount)
+            .unwrap_or(0)
     }
 
     /// Read a document by hash (helper; anyone can call).
+    /// Embargoed documents come back with an empty name until they unlock.
     pub fn read_document(env: Env, hash: String) -> Option<Document> {
-        env.storage().persistent().get(&DataKey::Document(hash))
+        let hash = Self::resolve_alias(&env, hash);
+        let mut doc = Self::load_full(&env, &hash)?;
+        if let Some(latest) = Self::latest_version(&env, &hash) {
+            doc.name = latest.name;
+            doc.added_by = latest.added_by;
+            doc.timestamp = latest.timestamp;
+            doc.sequence = latest.sequence;
+        }
+        if Self::is_embargoed(&env, &hash) {
+            doc.name = String::from_str(&env, "");
+        }
+        Some(doc)
+    }
+
+    /// Read several documents by hash; missing ones come back as `None`,
+    /// in the same order as `hashes`.
+    pub fn read_documents(env: Env, hashes: Vec<String>) -> Vec<Option<Document>> {
+        Self::assert_batch_size(&hashes, Self::get_limits(env.clone()).max_batch_read);
+        let mut out = Vec::new(&env);
+        for hash in hashes.iter() {
+            out.push_back(Self::read_document(env.clone(), hash));
+        }
+        out
+    }
+
+    /// Read the listing fields of a document (hash, timestamp, status, submitter).
+    pub fn read_document_summary(env: Env, hash: String) -> Option<DocumentSummary> {
+        let doc = Self::load_core(&env, &hash)?;
+        Some(DocumentSummary {
+            status: Self::status_of(&env, &hash),
+            hash: doc.hash,
+            timestamp: doc.timestamp,
+            added_by: doc.added_by,
+        })
     }
 
-    /// Verify a document by its hash.
+    /// Verify a document by its hash. Reads only the core entry; use
+    /// `read_document` for the name.
+    /// Quarantined documents are returned with `verified_document = false`.
     pub fn verify_document(env: Env, hash: String) -> Option<VerifiedDocument> {
-        let doc: Option<Document> = env.storage().persistent().get(&DataKey::Document(hash));
+        let hash = Self::resolve_alias(&env, hash);
+        let doc = Self::load_core(&env, &hash);
+        let status = Self::status_of(&env, &hash);
+        let restored_at: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Restored(hash.clone()));
+        let registered_at: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RegisteredAt(hash.clone()));
+        let successor = Self::get_successor(env.clone());
+        let live_until_ledger = Self::document_live_until(env.clone(), hash.clone()).unwrap_or(0);
+        let near_expiry =
+            Self::document_ttl_status(env.clone(), hash.clone()) == TtlStatus::NearExpiry;
+        let embargoed = Self::is_embargoed(&env, &hash);
+        let witness_count = Self::witness_count(env.clone(), hash.clone());
+        let doc_type = Self::doc_type_of(&env, &hash);
+        let sequence = Self::store_sequence(&env, &hash);
+        let redacted_at = Self::redacted_at(env.clone(), hash.clone());
+        let created_at = Self::document_created_at(env.clone(), hash.clone());
+        let legacy_hash =
+            Self::document_legacy_algo(env.clone(), hash.clone()).unwrap_or(HashAlgo::None);
+        let consent_revoked = Self::get_consent_revocation(env.clone(), hash.clone()).is_some();
+        let tsa_proof = Self::document_tsa_proof(env.clone(), hash.clone()).into();
+        let latest = Self::latest_version(&env, &hash);
         doc.map(|d| VerifiedDocument {
-            name: d.name,
+            tsa_proof,
+            legacy_hash,
+            consent_revoked,
             hash: d.hash,
-            timestamp: d.timestamp,
-            added_by: d.added_by,
-            verified_document: true,
+            registered_at: registered_at.unwrap_or(d.timestamp),
+            imported: registered_at.is_some(),
+            timestamp: latest.as_ref().map_or(d.timestamp, |v| v.timestamp),
+            added_by: latest.as_ref().map_or(d.added_by, |v| v.added_by.clone()),
+            verified_document: status == DocumentStatus::Active,
+            restored: restored_at.is_some(),
+            restored_at,
+            deprecated: successor.is_some(),
+            successor,
+            status,
+            live_until_ledger,
+            near_expiry,
+            embargoed,
+            witness_count,
+            doc_type,
+            sequence: latest.map_or(sequence, |v| v.sequence),
+            redacted: redacted_at.is_some(),
+            redacted_at,
+            created_at,
         })
     }
-    // transfer Ownership
-    pub fn transfer_ownership(env: Env, new_owner: Address) {
-    // Ensure the *current* owner authorized this call
-    let current_owner: Address = env
+
+    /// Negative attestation: report whether `hash` is unregistered right now.
+    /// An absent result is also emitted as an event so it is anchored in the
+    /// ledger history; a present hash returns `absent = false` silently.
+    pub fn attest_absence(env: Env, hash: String) -> AbsenceAttestation {
+        let absent = !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Document(hash.clone()));
+        let attestation = AbsenceAttestation {
+            hash,
+            ledger_sequence: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+            absent,
+        };
+        if absent {
+            events::absence_attested(&env, &attestation);
+        }
+        attestation
+    }
+
+    /// Anchor a verification in the ledger history: emits the document's
+    /// current status with this ledger's sequence and time, and returns the
+    /// new anchor count. Only the counter is stored.
+    pub fn anchor_verification(env: Env, hash: String) -> u32 {
+        Self::assert_not_sealed(&env);
+        let hash = Self::resolve_alias(&env, hash);
+        if !env.storage().persistent().has(&DataKey::Document(hash.clone())) {
+            panic!("Document not found");
+        }
+        let status = Self::status_of(&env, &hash);
+        let key = DataKey::VerificationAnchors(hash.clone());
+        let count = Self::verification_anchor_count(env.clone(), hash.clone()) + 1;
+        env.storage().persistent().set(&key, &count);
+        events::verification_anchored(&env, &hash, status);
+        count
+    }
+
+    /// Read-only: number of verifications anchored for a document
+    pub fn verification_anchor_count(env: Env, hash: String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VerificationAnchors(hash))
+            .unwrap_or(0)
+    }
+
+    /// Read-only: the document plus its status and every attribute attached
+    /// to it, in one call (None if missing)
+    pub fn get_document_full(env: Env, hash: String) -> Option<DocumentFull> {
+        let hash = Self::resolve_alias(&env, hash);
+        let document = Self::read_document(env.clone(), hash.clone())?;
+        Some(DocumentFull {
+            document,
+            verification: Self::verify_document(env.clone(), hash.clone())?,
+            controllers: Self::get_document_controllers(env.clone(), hash.clone()),
+            references: Self::get_document_references(env.clone(), hash.clone()),
+            category: Self::document_category(env.clone(), hash.clone()),
+            subject: Self::document_subject(env.clone(), hash.clone()),
+            pinned: Self::is_pinned(env.clone(), hash.clone()),
+            legal_hold: Self::is_on_legal_hold(env.clone(), hash.clone()),
+            superseded_by: Self::superseded_by(env.clone(), hash.clone()),
+            update: Self::get_update_info(env, hash)?,
+        })
+    }
+
+    /// Read-only: like `verify_document`, but returns an explicit outcome
+    pub fn verify_document_detailed(env: Env, hash: String) -> VerificationOutcome {
+        match Self::verify_document(env, hash) {
+            Some(verified) => VerificationOutcome::Found(verified),
+            None => VerificationOutcome::NotFound,
+        }
+    }
+
+    /// Owner-only: re-create a document whose entry was archived and evicted,
+    /// keeping its original timestamp and submitter. The record is flagged as
+    /// restored so verifiers can tell it apart from a native registration.
+    pub fn restore_evicted_document(
+        env: Env,
+        name: String,
+        hash: String,
+        original_timestamp: u64,
+        original_added_by: Address,
+    ) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+
+        let key = DataKey::Document(hash.clone());
+        if env.storage().persistent().has(&key) {
+            panic!("Document already registered");
+        }
+
+        // Counters and the actor index still reflect the original store
+        let doc = Document {
+            name,
+            hash: hash.clone(),
+            timestamp: original_timestamp,
+            added_by: original_added_by,
+            sequence: 0,
+            rename_count: 0,
+            tsa_proof: TsaAttachment::None,
+        };
+        Self::put_document(&env, &doc);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Restored(hash.clone()), &env.ledger().timestamp());
+        Self::extend_document_ttl(&env, &hash, DOC_BUMP_AMOUNT);
+
+        events::document_restored(&env, &hash, &doc.added_by);
+    }
+    // transfer Ownership
+    pub fn transfer_ownership(env: Env, new_owner: Address) {
+    Self::assert_not_sealed(&env);
+
+    // Ensure the *current* owner authorized this call
+    let current_owner: Address = env
         .storage()
         .instance()
         .get(&DataKey::Owner)
@@ -175,234 +2464,9887 @@ fn assert_owner_or_whitelisted_actor(env: &Env, actor: &Address) {
     }
 
     env.storage().instance().set(&DataKey::Owner, &new_owner);
+    // A direct transfer supersedes any pending proposal
+    env.storage().instance().remove(&DataKey::PendingOwner);
+    Self::record_activity(&env, Activity::OwnershipChange);
+    events::ownership_transferred(&env, &new_owner, &current_owner);
 }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use soroban_sdk::{Address, Env, String};
-    use soroban_sdk::testutils::{Address as _, Ledger}; // trait import
+    // ---------- TWO-STEP OWNERSHIP ----------
+
+    /// Owner-only: propose `candidate` as the next owner; takes effect once
+    /// the candidate calls `accept_ownership`
+    pub fn propose_ownership(env: Env, candidate: Address) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        if candidate == owner {
+            panic!("new owner must be different");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingOwner, &candidate);
+        events::ownership_proposed(&env, &candidate, &owner);
+    }
+
+    /// Pending owner: accept a proposed transfer
+    pub fn accept_ownership(env: Env) {
+        Self::assert_not_sealed(&env);
+        let candidate = Self::pending_owner(env.clone()).expect("no pending owner");
+        candidate.require_auth();
+        let previous = Self::owner_address(env.clone());
+        env.storage().instance().set(&DataKey::Owner, &candidate);
+        env.storage().instance().remove(&DataKey::PendingOwner);
+        Self::record_activity(&env, Activity::OwnershipChange);
+        events::ownership_accepted(&env, &candidate, &previous);
+    }
+
+    /// Owner-only: withdraw a pending proposal
+    pub fn cancel_ownership_transfer(env: Env) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        let candidate = Self::pending_owner(env.clone()).expect("no pending owner");
+        env.storage().instance().remove(&DataKey::PendingOwner);
+        events::ownership_cancelled(&env, &candidate, &owner);
+    }
+
+    /// Read-only: candidate of an in-flight transfer (None if none)
+    pub fn pending_owner(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingOwner)
+    }
+
+    // ---------- PINNING ----------
+    //
+    // Pinned documents must stay retrievable: they refuse deletion and are
+    // the only ones `bump_pinned` will extend.
+
+    /// Owner-only: pin an existing document
+    pub fn pin_document(env: Env, hash: String) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        if !env.storage().persistent().has(&DataKey::Document(hash.clone())) {
+            panic!("document not found");
+        }
+        env.storage().persistent().set(&DataKey::Pinned(hash.clone()), &true);
+        Self::touch_document(&env, &hash, &owner);
+        events::document_pinned(&env, &hash);
+    }
+
+    /// Owner-only: unpin a document
+    pub fn unpin_document(env: Env, hash: String) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        env.storage().persistent().remove(&DataKey::Pinned(hash.clone()));
+        if env.storage().persistent().has(&DataKey::Document(hash.clone())) {
+            Self::touch_document(&env, &hash, &owner);
+        }
+        events::document_unpinned(&env, &hash);
+    }
+
+    /// Read-only: whether a document is pinned
+    pub fn is_pinned(env: Env, hash: String) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, bool>(&DataKey::Pinned(hash))
+            .unwrap_or(false)
+    }
+
+    /// Owner or maintainer: extend the TTL of pinned documents (and their
+    /// side records) to `extend_to` ledgers. Every hash must be pinned.
+    pub fn bump_pinned(env: Env, caller: Address, hashes: Vec<String>, extend_to: u32) {
+        Self::assert_owner_or_maintainer(&env, &caller);
+        Self::assert_batch_size(&hashes, Self::get_limits(env.clone()).max_batch_bump);
+        for hash in hashes.iter() {
+            if !Self::is_pinned(env.clone(), hash.clone()) {
+                panic!("document not pinned");
+            }
+            Self::extend_document_ttl(&env, &hash, extend_to);
+        }
+    }
+
+    /// Internal: extend a document entry and every per-hash side record
+    fn extend_document_ttl(env: &Env, hash: &String, extend_to: u32) {
+        Self::extend_document_keys(env, hash, extend_to, &Self::document_side_keys(hash));
+    }
+
+    /// Internal: extend a document entry and the given side records
+    fn extend_document_keys(env: &Env, hash: &String, extend_to: u32, side_keys: &[DataKey]) {
+        let storage = env.storage().persistent();
+        let live_key = DataKey::LiveUntil(hash.clone());
+        let live_until = env.ledger().sequence().saturating_add(extend_to);
+        let known: u32 = storage.get(&live_key).unwrap_or(0);
+        storage.set(&live_key, &live_until.max(known));
+
+        storage.extend_ttl(&DataKey::Document(hash.clone()), extend_to, extend_to);
+        for key in side_keys {
+            if storage.has(key) {
+                storage.extend_ttl(key, extend_to, extend_to);
+            }
+        }
+        if let Some(alias) = storage.get::<_, String>(&DataKey::DocAlias(hash.clone())) {
+            storage.extend_ttl(&DataKey::Alias(alias), extend_to, extend_to);
+        }
+        // The actor id mapping must outlive every document that refers to it
+        if let Some(StoredEntry::Compact(core, id)) = Self::load_entry(env, hash) {
+            storage.extend_ttl(&DataKey::ActorById(id), extend_to, extend_to);
+            storage.extend_ttl(&DataKey::ActorId(core.added_by), extend_to, extend_to);
+        }
+    }
+
+    /// Internal: side records that may already exist when a document is first
+    /// written: set by `write_document` itself or by the store variant calling it
+    fn registration_side_keys(hash: &String) -> [DataKey; 13] {
+        [
+            DataKey::LiveUntil(hash.clone()),
+            DataKey::RegisteredAt(hash.clone()),
+            DataKey::Category(hash.clone()),
+            DataKey::Embargo(hash.clone()),
+            DataKey::DocumentExt(hash.clone()),
+            DataKey::VetoUntil(hash.clone()),
+            DataKey::DocAlias(hash.clone()),
+            DataKey::DocType(hash.clone()),
+            DataKey::CreatedAt(hash.clone()),
+            DataKey::Jurisdiction(hash.clone()),
+            DataKey::Language(hash.clone()),
+            DataKey::TsaProof(hash.clone()),
+            DataKey::LegacyAlgo(hash.clone()),
+        ]
+    }
+
+    /// Internal: every optional per-hash record that lives alongside a document
+    fn document_side_keys(hash: &String) -> [DataKey; 40] {
+        [
+            DataKey::Status(hash.clone()),
+            DataKey::Pinned(hash.clone()),
+            DataKey::Restored(hash.clone()),
+            DataKey::LiveUntil(hash.clone()),
+            DataKey::DocRefs(hash.clone()),
+            DataKey::Controllers(hash.clone()),
+            DataKey::RegisteredAt(hash.clone()),
+            DataKey::Category(hash.clone()),
+            DataKey::Embargo(hash.clone()),
+            DataKey::DocumentExt(hash.clone()),
+            DataKey::DocSlot(hash.clone()),
+            DataKey::QuarantineNote(hash.clone()),
+            DataKey::VetoUntil(hash.clone()),
+            DataKey::Veto(hash.clone()),
+            DataKey::OwnerNote(hash.clone()),
+            DataKey::DocAlias(hash.clone()),
+            DataKey::WitnessCount(hash.clone()),
+            DataKey::StoreMeta(hash.clone()),
+            DataKey::DocType(hash.clone()),
+            DataKey::Supersedes(hash.clone()),
+            DataKey::SupersededBy(hash.clone()),
+            DataKey::Updated(hash.clone()),
+            DataKey::Subject(hash.clone()),
+            DataKey::DeletionRequest(hash.clone()),
+            DataKey::DeletionSlot(hash.clone()),
+            DataKey::LegalHold(hash.clone()),
+            DataKey::Redacted(hash.clone()),
+            DataKey::PrevHead(hash.clone()),
+            DataKey::CreatedAt(hash.clone()),
+            DataKey::Jurisdiction(hash.clone()),
+            DataKey::Language(hash.clone()),
+            DataKey::JurisdictionSlot(hash.clone()),
+            DataKey::RenameCount(hash.clone()),
+            DataKey::TsaProof(hash.clone()),
+            DataKey::Views(hash.clone()),
+            DataKey::LastView(hash.clone()),
+            DataKey::LegacyAlgo(hash.clone()),
+            DataKey::ConsentRevocation(hash.clone()),
+            DataKey::DocVersionCount(hash.clone()),
+            DataKey::VerificationAnchors(hash.clone()),
+        ]
+    }
+
+    // ---------- LIVENESS ----------
+    //
+    // The host doesn't let contracts read TTLs, so the contract records the
+    // live-until ledger every time it extends a document itself. TTL bumps
+    // submitted directly to the network are not seen, so this is a lower bound.
+
+    /// Read-only: ledger sequence until which the document entry lives
+    pub fn document_live_until(env: Env, hash: String) -> Option<u32> {
+        if !env.storage().persistent().has(&DataKey::Document(hash.clone())) {
+            return None;
+        }
+        env.storage().persistent().get(&DataKey::LiveUntil(hash))
+    }
+
+    /// Read-only: `Live`, `NearExpiry` (within `ttl_warning_ledgers`) or `Missing`
+    pub fn document_ttl_status(env: Env, hash: String) -> TtlStatus {
+        let live_until = match Self::document_live_until(env.clone(), hash) {
+            Some(l) => l,
+            None => return TtlStatus::Missing,
+        };
+        let seq = env.ledger().sequence();
+        if live_until < seq {
+            return TtlStatus::Missing;
+        }
+        if live_until - seq <= Self::get_config(env).ttl_warning_ledgers {
+            TtlStatus::NearExpiry
+        } else {
+            TtlStatus::Live
+        }
+    }
+
+    // ---------- NAMESPACES ----------
+    //
+    // The flat functions above operate on the default registry; the `_ns`
+    // variants below operate on an isolated registry per namespace.
+
+    /// Internal: load namespace info or panic
+    fn namespace_info(env: &Env, ns: &Symbol) -> NamespaceInfo {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Namespace(ns.clone()))
+            .expect("namespace not found")
+    }
+
+    /// Internal: `admin` must have signed and be the namespace admin (or the owner)
+    fn assert_namespace_admin(env: &Env, ns: &Symbol, admin: &Address) {
+        admin.require_auth();
+        let info = Self::namespace_info(env, ns);
+        if admin == &info.admin || admin == &Self::owner_address(env.clone()) {
+            return;
+        }
+        panic!("not authorized: only namespace admin");
+    }
+
+    /// Owner-only: create a namespace managed by `ns_admin`
+    pub fn create_namespace(env: Env, ns: Symbol, ns_admin: Address) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        let key = DataKey::Namespace(ns.clone());
+        if env.storage().persistent().has(&key) {
+            panic!("namespace already exists");
+        }
+        let info = NamespaceInfo {
+            admin: ns_admin.clone(),
+            created_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &info);
+        let slot = Self::next_slot(&env, &DataKey::NamespaceIndexCount);
+        env.storage()
+            .persistent()
+            .set(&DataKey::NamespaceIndex(slot), &ns);
+        events::namespace_created(&env, &ns, &ns_admin);
+    }
+
+    /// Read-only: admin, creation time and current counters of a namespace
+    pub fn namespace_stats(env: Env, ns: Symbol) -> NamespaceStats {
+        let info = Self::namespace_info(&env, &ns);
+        let instance = env.storage().instance();
+        let document_count = instance.get(&DataKey::NsDocCount(ns.clone()));
+        let whitelist_count = instance.get(&DataKey::NsWhitelistCount(ns));
+        NamespaceStats {
+            admin: info.admin,
+            created_at: info.created_at,
+            document_count: document_count.unwrap_or(0),
+            whitelist_count: whitelist_count.unwrap_or(0),
+        }
+    }
+
+    /// Read-only: namespaces in creation order, `limit` capped at
+    /// MAX_PAGE_SIZE. Namespaces created before the index existed are absent.
+    pub fn list_namespaces(env: Env, start: u32, limit: u32) -> Vec<Symbol> {
+        let total: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NamespaceIndexCount)
+            .unwrap_or(0);
+        let (end, _) = Self::page_range(start, limit, total);
+        let mut out = Vec::new(&env);
+        for i in start..end {
+            if let Some(ns) = env.storage().persistent().get(&DataKey::NamespaceIndex(i)) {
+                out.push_back(ns);
+            }
+        }
+        out
+    }
+
+    /// Read-only: namespace admin and creation time
+    pub fn get_namespace(env: Env, ns: Symbol) -> Option<NamespaceInfo> {
+        env.storage().persistent().get(&DataKey::Namespace(ns))
+    }
+
+    /// Namespace admin: add address to the namespace whitelist
+    pub fn whitelist_address_ns(env: Env, ns: Symbol, admin: Address, address: Address) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        Self::assert_namespace_admin(&env, &ns, &admin);
+        let key = DataKey::NsWhitelist(ns.clone(), address.clone());
+        if !env.storage().persistent().has(&key) {
+            Self::bump_counter(&env, &DataKey::NsWhitelistCount(ns.clone()), 1);
+        }
+        env.storage().persistent().set(&key, &true);
+        Self::record_activity(&env, Activity::WhitelistChange);
+        events::namespace_whitelist_added(&env, &ns, &address);
+    }
+
+    /// Namespace admin: remove address from the namespace whitelist
+    pub fn remove_from_whitelist_ns(env: Env, ns: Symbol, admin: Address, address: Address) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        Self::assert_namespace_admin(&env, &ns, &admin);
+        let key = DataKey::NsWhitelist(ns.clone(), address.clone());
+        if env.storage().persistent().has(&key) {
+            Self::bump_counter(&env, &DataKey::NsWhitelistCount(ns.clone()), -1);
+        }
+        env.storage().persistent().remove(&key);
+        Self::record_activity(&env, Activity::WhitelistChange);
+        events::namespace_whitelist_removed(&env, &ns, &address);
+    }
+
+    /// Read-only: check if address is whitelisted in a namespace
+    pub fn is_whitelisted_ns(env: Env, ns: Symbol, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, bool>(&DataKey::NsWhitelist(ns, address))
+            .unwrap_or(false)
+    }
+
+    /// Store a document in a namespace (namespace admin or namespace whitelist).
+    pub fn store_document_ns(env: Env, ns: Symbol, actor: Address, name: String, hash: String) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        actor.require_auth();
+        let info = Self::namespace_info(&env, &ns);
+        if actor != info.admin && !Self::is_whitelisted_ns(env.clone(), ns.clone(), actor.clone()) {
+            panic!("not authorized: only namespace admin or whitelisted address");
+        }
+
+        let key = DataKey::NsDocument(ns.clone(), hash.clone());
+        if env.storage().persistent().has(&key) {
+            panic!("Document already registered");
+        }
+        Self::assert_valid_input(&env, &name, &hash);
+        let doc = NsStoredDocument {
+            name,
+            hash: hash.clone(),
+            timestamp: env.ledger().timestamp(),
+            added_by: actor.clone(),
+            sequence: env.ledger().sequence(),
+        };
+        env.storage().persistent().set(&key, &doc);
+        Self::index_ns_document(&env, &ns, &hash);
+        Self::bump_counter(&env, &DataKey::NsDocCount(ns.clone()), 1);
+        Self::record_activity(&env, Activity::Store);
+        events::namespace_stored(&env, &ns, &hash, &actor);
+    }
+
+    /// Internal: append a namespaced document to its namespace's index
+    fn index_ns_document(env: &Env, ns: &Symbol, hash: &String) {
+        let slot = Self::next_slot(env, &DataKey::NsDocIndexCount(ns.clone()));
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::NsDocIndex(ns.clone(), slot), hash);
+        storage.set(&DataKey::NsDocSlot(ns.clone(), hash.clone()), &slot);
+    }
+
+    /// Read-only: page of a namespace's documents in registration order.
+    /// Documents stored before the index existed are absent.
+    pub fn list_documents_ns(env: Env, ns: Symbol, cursor: u32, limit: u32) -> DocumentPage {
+        let total: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NsDocIndexCount(ns.clone()))
+            .unwrap_or(0);
+        let (end, next_cursor) = Self::page_range(cursor, limit, total);
+        let mut items = Vec::new(&env);
+        for i in cursor..end {
+            let slot: Option<String> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::NsDocIndex(ns.clone(), i));
+            let Some(doc) = slot.and_then(|h| Self::read_document_ns(env.clone(), ns.clone(), h))
+            else {
+                continue;
+            };
+            items.push_back(DocumentSummary {
+                hash: doc.hash,
+                timestamp: doc.timestamp,
+                status: DocumentStatus::Active,
+                added_by: doc.added_by,
+            });
+        }
+        DocumentPage { items, next_cursor }
+    }
+
+    /// Move a namespaced document to another namespace, keeping its record
+    /// (timestamp included) as stored. Needs the owner, or both namespace
+    /// admins with `caller` being one of them.
+    pub fn move_document(env: Env, caller: Address, ns_from: Symbol, ns_to: Symbol, hash: String) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        let from = Self::namespace_info(&env, &ns_from);
+        let to = Self::namespace_info(&env, &ns_to);
+        if caller == Self::owner_address(env.clone()) {
+            caller.require_auth();
+        } else if caller == from.admin || caller == to.admin {
+            from.admin.require_auth();
+            if to.admin != from.admin {
+                to.admin.require_auth();
+            }
+        } else {
+            panic!("not authorized: only namespace admins");
+        }
+        if ns_from == ns_to {
+            panic!("same namespace");
+        }
+
+        let storage = env.storage().persistent();
+        let src = DataKey::NsDocument(ns_from.clone(), hash.clone());
+        let raw: Val = storage.get(&src).expect("Document not found");
+        let dst = DataKey::NsDocument(ns_to.clone(), hash.clone());
+        if storage.has(&dst) {
+            panic!("Document already registered");
+        }
+        storage.remove(&src);
+        storage.set(&dst, &raw);
+        let slot_key = DataKey::NsDocSlot(ns_from.clone(), hash.clone());
+        if let Some(slot) = storage.get::<_, u32>(&slot_key) {
+            storage.remove(&DataKey::NsDocIndex(ns_from.clone(), slot));
+            storage.remove(&slot_key);
+        }
+        Self::index_ns_document(&env, &ns_to, &hash);
+        Self::bump_counter(&env, &DataKey::NsDocCount(ns_from.clone()), -1);
+        Self::bump_counter(&env, &DataKey::NsDocCount(ns_to.clone()), 1);
+        events::namespace_moved(&env, &hash, &ns_from, &ns_to);
+    }
+
+    /// Read a namespaced document by hash (anyone can call).
+    pub fn read_document_ns(env: Env, ns: Symbol, hash: String) -> Option<Document> {
+        let raw: Val = env.storage().persistent().get(&DataKey::NsDocument(ns, hash))?;
+        if Self::has_field(&env, &raw, "sequence") {
+            let doc = NsStoredDocument::try_from_val(&env, &raw).expect("corrupt document entry");
+            return Some(Document {
+                name: doc.name,
+                hash: doc.hash,
+                timestamp: doc.timestamp,
+                added_by: doc.added_by,
+                sequence: doc.sequence,
+                rename_count: 0,
+                tsa_proof: TsaAttachment::None,
+            });
+        }
+        let legacy = LegacyDocument::try_from_val(&env, &raw).expect("corrupt document entry");
+        Some(Document {
+            name: legacy.name,
+            hash: legacy.hash,
+            timestamp: legacy.timestamp,
+            added_by: legacy.added_by,
+            sequence: 0,
+            rename_count: 0,
+            tsa_proof: TsaAttachment::None,
+        })
+    }
+
+    /// Verify a namespaced document by its hash.
+    pub fn verify_document_ns(env: Env, ns: Symbol, hash: String) -> Option<VerifiedDocument> {
+        let successor = Self::get_successor(env.clone());
+        Self::read_document_ns(env, ns, hash).map(|d| VerifiedDocument {
+            hash: d.hash,
+            timestamp: d.timestamp,
+            added_by: d.added_by,
+            verified_document: true,
+            restored: false,
+            restored_at: None,
+            registered_at: d.timestamp,
+            imported: false,
+            deprecated: successor.is_some(),
+            successor,
+            status: DocumentStatus::Active,
+            live_until_ledger: 0,
+            near_expiry: false,
+            embargoed: false,
+            witness_count: 0,
+            doc_type: DocType::Other,
+            sequence: d.sequence,
+            redacted: false,
+            redacted_at: None,
+            created_at: None,
+            tsa_proof: TsaAttachment::None,
+            legacy_hash: HashAlgo::None,
+            consent_revoked: false,
+        })
+    }
+
+    // ---------- SEAL ----------
+
+    /// Owner-only: permanently freeze the registry. Irreversible; afterwards
+    /// only reads succeed (no stores, whitelist changes or ownership transfer).
+    pub fn seal_registry(env: Env) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        let info = SealInfo {
+            sealed_at: env.ledger().timestamp(),
+            document_count: Self::document_count(env.clone()),
+        };
+        env.storage().instance().set(&DataKey::Sealed, &info);
+        events::registry_sealed(&env, info.sealed_at, info.document_count);
+    }
+
+    /// Read-only: whether `seal_registry` has been called
+    pub fn is_sealed(env: Env) -> bool {
+        env.storage().instance().has(&DataKey::Sealed)
+    }
+
+    /// Read-only: seal timestamp and final document count, if sealed
+    pub fn seal_info(env: Env) -> Option<SealInfo> {
+        env.storage().instance().get(&DataKey::Sealed)
+    }
+
+    // ---------- REFERENCES ----------
+
+    /// Owner or controller: append an off-chain reference URI to a document
+    pub fn add_document_reference(env: Env, actor: Address, hash: String, uri: String) {
+        Self::assert_not_sealed(&env);
+        Self::assert_owner_or_controller(&env, &actor, &hash);
+        if uri.len() > MAX_URI_LEN {
+            panic!("uri too long");
+        }
+        let mut refs = Self::get_document_references(env.clone(), hash.clone());
+        if refs.len() >= MAX_DOC_REFS {
+            panic!("too many references");
+        }
+        refs.push_back(uri.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::DocRefs(hash.clone()), &refs);
+        Self::touch_document(&env, &hash, &actor);
+        events::reference_added(&env, &hash, &uri);
+    }
+
+    /// Owner or controller: remove the reference at `index`; later entries
+    /// shift down by one, keeping their relative order
+    pub fn remove_document_reference(env: Env, actor: Address, hash: String, index: u32) {
+        Self::assert_not_sealed(&env);
+        Self::assert_owner_or_controller(&env, &actor, &hash);
+        let mut refs = Self::get_document_references(env.clone(), hash.clone());
+        if index >= refs.len() {
+            panic!("reference index out of range");
+        }
+        refs.remove(index);
+        let key = DataKey::DocRefs(hash.clone());
+        if refs.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &refs);
+        }
+        Self::touch_document(&env, &hash, &actor);
+        events::reference_removed(&env, &hash, index);
+    }
+
+    /// Read-only: reference URIs attached to a document (empty if none)
+    pub fn get_document_references(env: Env, hash: String) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DocRefs(hash))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // ---------- CONTROLLERS ----------
+    //
+    // A document starts out controlled by its submitter. Controllers (and the
+    // owner) may amend references, revoke, and manage the controller set.
+
+    /// Read-only: controllers of a document (empty if the document doesn't exist)
+    pub fn get_document_controllers(env: Env, hash: String) -> Vec<Address> {
+        if let Some(controllers) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Controllers(hash.clone()))
+        {
+            return controllers;
+        }
+        // The original submitter, not the author of the latest version
+        match Self::load_full(&env, &Self::resolve_alias(&env, hash)) {
+            Some(doc) => Vec::from_array(&env, [doc.added_by]),
+            None => Vec::new(&env),
+        }
+    }
+
+    /// Owner or controller: add another controller to a document
+    pub fn add_document_controller(
+        env: Env,
+        actor: Address,
+        hash: String,
+        new_controller: Address,
+    ) {
+        Self::assert_not_sealed(&env);
+        Self::assert_owner_or_controller(&env, &actor, &hash);
+        let mut controllers = Self::get_document_controllers(env.clone(), hash.clone());
+        if controllers.contains(&new_controller) {
+            panic!("already a controller");
+        }
+        if controllers.len() >= MAX_CONTROLLERS {
+            panic!("too many controllers");
+        }
+        controllers.push_back(new_controller.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Controllers(hash.clone()), &controllers);
+        Self::touch_document(&env, &hash, &actor);
+        events::controller_added(&env, &hash, &new_controller);
+    }
+
+    /// Owner or controller: remove a controller; the last one cannot be removed
+    pub fn remove_document_controller(
+        env: Env,
+        actor: Address,
+        hash: String,
+        controller: Address,
+    ) {
+        Self::assert_not_sealed(&env);
+        Self::assert_owner_or_controller(&env, &actor, &hash);
+        let mut controllers = Self::get_document_controllers(env.clone(), hash.clone());
+        let index = controllers.first_index_of(&controller).expect("not a controller");
+        if controllers.len() == 1 {
+            panic!("document must keep a controller");
+        }
+        controllers.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Controllers(hash.clone()), &controllers);
+        Self::touch_document(&env, &hash, &actor);
+        events::controller_removed(&env, &hash, &controller);
+    }
+
+    /// Owner or controller: permanently revoke a document. It stays readable
+    /// but no longer verifies.
+    pub fn revoke_document(env: Env, actor: Address, hash: String) {
+        Self::assert_not_sealed(&env);
+        Self::assert_owner_or_controller(&env, &actor, &hash);
+        match Self::status_of(&env, &hash) {
+            DocumentStatus::Revoked => panic!("document already revoked"),
+            DocumentStatus::Erased => panic!("document erased"),
+            _ => {}
+        }
+        Self::mark_revoked(&env, &hash, &actor);
+    }
+
+    /// Internal: record the revocation of an existing, revocable document
+    fn mark_revoked(env: &Env, hash: &String, actor: &Address) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Status(hash.clone()), &DocumentStatus::Revoked);
+        Self::bump_counter(env, &DataKey::RevokedCount, 1);
+        Self::touch_document(env, hash, actor);
+        events::document_revoked(env, hash, actor);
+    }
+
+    /// Owner-only: revoke what `actor` registered with `after <= timestamp <
+    /// before`, walking its index slots `cursor..cursor + max` (at most
+    /// `MAX_PAGE_SIZE`). Revoked, erased and deleted entries are skipped.
+    /// Returns the number revoked and the cursor to continue from, if any.
+    pub fn revoke_documents_by_actor(
+        env: Env,
+        actor: Address,
+        after: u64,
+        before: u64,
+        cursor: u32,
+        max: u32,
+    ) -> (u32, Option<u32>) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        let total = Self::actor_document_count(env.clone(), actor.clone());
+        let (end, next) = Self::page_range(cursor, max, total);
+        let mut revoked = 0;
+        for i in cursor..end {
+            let slot: Option<String> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ActorDoc(actor.clone(), i));
+            let Some(doc) = slot.and_then(|h| Self::load_core(&env, &h)) else {
+                continue;
+            };
+            if doc.timestamp < after || doc.timestamp >= before {
+                continue;
+            }
+            match Self::status_of(&env, &doc.hash) {
+                DocumentStatus::Revoked | DocumentStatus::Erased => continue,
+                _ => {}
+            }
+            Self::mark_revoked(&env, &doc.hash, &owner);
+            revoked += 1;
+        }
+        (revoked, next)
+    }
+
+    // ---------- AUDIT ----------
+
+    /// Read-only: check a manifest of (hash, expected_name) pairs in one call.
+    /// Results keep the input order; a revoked or quarantined document reports
+    /// that status even if its name matches. Capped at `max_batch_read` entries.
+    pub fn audit_documents(env: Env, entries: Vec<AuditEntry>) -> Vec<AuditResult> {
+        Self::assert_batch_size(&entries, Self::get_limits(env.clone()).max_batch_read);
+        let mut results = Vec::new(&env);
+        for entry in entries.iter() {
+            let result = match Self::read_document(env.clone(), entry.hash.clone()) {
+                None => AuditResult::NotFound,
+                Some(doc) => match Self::status_of(&env, &entry.hash) {
+                    DocumentStatus::Revoked => AuditResult::Revoked,
+                    DocumentStatus::Quarantined => AuditResult::Quarantined,
+                    DocumentStatus::Pending => AuditResult::Pending,
+                    DocumentStatus::Vetoed => AuditResult::Vetoed,
+                    DocumentStatus::Erased => AuditResult::Erased,
+                    DocumentStatus::Active if doc.name != entry.expected_name => {
+                        AuditResult::NameMismatch
+                    }
+                    DocumentStatus::Active => AuditResult::Ok,
+                },
+            };
+            results.push_back(result);
+        }
+        results
+    }
+
+    // ---------- CHECKPOINTS ----------
+
+    /// Owner-only: publish a checkpoint of the registry state. `doc_count`
+    /// must equal the on-chain document counter. Returns the new checkpoint id.
+    pub fn publish_checkpoint(env: Env, root: BytesN<32>, doc_count: u64, note: String) -> u32 {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        if doc_count != Self::document_count(env.clone()) {
+            panic!("document count mismatch");
+        }
+        if note.len() > MAX_NOTE_LEN {
+            panic!("note too long");
+        }
+
+        let id = Self::checkpoint_count(&env) + 1;
+        let checkpoint = Checkpoint {
+            id,
+            root: root.clone(),
+            doc_count,
+            // the checkpoint's own event is the next one
+            last_event_seq: Self::last_event_seq(env.clone()) + 1,
+            note,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Checkpoint(id), &checkpoint);
+        env.storage().instance().set(&DataKey::CheckpointCount, &id);
+
+        events::checkpoint_published(&env, id, &root, doc_count);
+        id
+    }
+
+    /// Read-only: checkpoint by id
+    pub fn get_checkpoint(env: Env, id: u32) -> Option<Checkpoint> {
+        env.storage().persistent().get(&DataKey::Checkpoint(id))
+    }
+
+    /// Read-only: most recently published checkpoint
+    pub fn latest_checkpoint(env: Env) -> Option<Checkpoint> {
+        let id = Self::checkpoint_count(&env);
+        if id == 0 {
+            return None;
+        }
+        Self::get_checkpoint(env, id)
+    }
+
+    /// Internal: id of the latest checkpoint (0 => none)
+    fn checkpoint_count(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CheckpointCount)
+            .unwrap_or(0)
+    }
+
+    // ---------- DAILY LIMITS ----------
+    //
+    // Whitelisted actors are limited to `daily_store_limit` stores per epoch
+    // (ledger-time day). The owner is exempt.
+
+    /// Internal: count `n` stores against the actor's current epoch bucket
+    fn consume_daily_quota(env: &Env, actor: &Address, role: ActorRole, n: u32) {
+        let limit = Self::get_config(env.clone()).daily_store_limit;
+        if limit == 0 || role == ActorRole::Owner {
+            return;
+        }
+        if Self::exceeds_daily_quota(env, actor, role, n) {
+            panic!("daily limit reached");
+        }
+        let epoch = Self::current_epoch(env.clone());
+        let used = Self::epoch_store_count(env.clone(), actor.clone(), epoch);
+        env.storage()
+            .persistent()
+            .set(&DataKey::EpochCount(actor.clone(), epoch), &(used + n));
+    }
+
+    /// Internal: whether `n` more stores would push the actor past its limit
+    fn exceeds_daily_quota(env: &Env, actor: &Address, role: ActorRole, n: u32) -> bool {
+        let limit = Self::get_config(env.clone()).daily_store_limit;
+        if limit == 0 || role == ActorRole::Owner {
+            return false;
+        }
+        let epoch = Self::current_epoch(env.clone());
+        let used = Self::epoch_store_count(env.clone(), actor.clone(), epoch);
+        used.saturating_add(n) > limit
+    }
+
+    /// Read-only: the current daily epoch
+    pub fn current_epoch(env: Env) -> u64 {
+        env.ledger().timestamp() / EPOCH_SECS
+    }
+
+    /// Read-only: documents `actor` stored during `epoch`
+    pub fn epoch_store_count(env: Env, actor: Address, epoch: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EpochCount(actor, epoch))
+            .unwrap_or(0)
+    }
+
+    /// Owner or maintainer: delete past epoch counters to reclaim rent.
+    /// The current epoch can't be pruned (it would reset the limit).
+    pub fn prune_epoch_counters(env: Env, caller: Address, actor: Address, epochs: Vec<u64>) {
+        Self::assert_owner_or_maintainer(&env, &caller);
+        Self::assert_batch_size(&epochs, Self::get_limits(env.clone()).max_batch_bump);
+        let current = Self::current_epoch(env.clone());
+        for epoch in epochs.iter() {
+            if epoch >= current {
+                panic!("cannot prune current epoch");
+            }
+            env.storage()
+                .persistent()
+                .remove(&DataKey::EpochCount(actor.clone(), epoch));
+        }
+    }
+
+    // ---------- ACTOR STATS ----------
+
+    /// Read-only: usage snapshot for `address`. Unknown addresses get zeros/None.
+    pub fn get_actor_stats(env: Env, address: Address) -> ActorStats {
+        let limit = Self::get_config(env.clone()).daily_store_limit;
+        let remaining_daily_quota = if limit == 0 {
+            None
+        } else {
+            let epoch = Self::current_epoch(env.clone());
+            let used = Self::epoch_store_count(env.clone(), address.clone(), epoch);
+            Some(limit.saturating_sub(used))
+        };
+        ActorStats {
+            documents_stored: Self::actor_document_count(env.clone(), address.clone()),
+            last_store_at: env
+                .storage()
+                .persistent()
+                .get(&DataKey::LastStore(address.clone())),
+            remaining_daily_quota,
+            whitelisted: Self::is_whitelisted(env, address),
+        }
+    }
+
+    /// Read-only: aggregate permission snapshot for `address`
+    pub fn get_permissions(env: Env, address: Address) -> Permissions {
+        let is_owner = address == Self::owner_address(env.clone());
+        let writable = !env.storage().instance().has(&DataKey::Sealed)
+            && !env.storage().instance().has(&DataKey::Successor);
+        let role = Self::actor_role(&env, &address, symbol_short!("store"));
+        let config = Self::get_config(env.clone());
+        let remaining_quota = match config.daily_store_limit {
+            0 => None,
+            _ if is_owner => None,
+            limit => {
+                let epoch = Self::current_epoch(env.clone());
+                let used = Self::epoch_store_count(env.clone(), address.clone(), epoch);
+                Some(limit.saturating_sub(used))
+            }
+        };
+        let charged = matches!(
+            config.registration_fee.fee(),
+            Some(fee) if fee.amount > 0 || config.fee_per_byte > 0
+        );
+        let used = Self::actor_document_count(env.clone(), address.clone());
+        Permissions {
+            is_owner,
+            is_whitelisted: Self::is_whitelisted(env.clone(), address),
+            can_store: writable && role.is_some(),
+            can_revoke: is_owner && !env.storage().instance().has(&DataKey::Sealed),
+            remaining_quota,
+            fee_exempt: is_owner || !charged || used < config.free_quota_per_actor,
+        }
+    }
+
+    // ---------- DECOMMISSION ----------
+
+    /// Owner-only: retire this registry in favour of `successor`. Stores and
+    /// whitelist changes are blocked; reads keep answering from local data and
+    /// `verify_document` flags results as deprecated. Irreversible.
+    pub fn decommission(env: Env, successor: Address) {
+        Self::assert_not_sealed(&env);
+        Self::assert_not_decommissioned(&env);
+        let _owner = Self::assert_owner(&env);
+        env.storage().instance().set(&DataKey::Successor, &successor);
+        events::registry_decommissioned(&env, &successor);
+    }
+
+    /// Read-only: successor registry, if decommissioned
+    pub fn get_successor(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Successor)
+    }
+
+    // ---------- FEES ----------
+    //
+    // `Config::registration_fee` applies to every store by a non-owner actor;
+    // categorized stores use the category override instead, when one is set.
+    // `Config::fee_per_byte` adds a charge per name byte, in the same token.
+
+    /// Internal: reject negative fee amounts
+    fn assert_valid_fee(fee: &Fee) {
+        if fee.amount < 0 {
+            panic!("invalid fee");
+        }
+    }
+
+    /// Internal: transfer the registration fees for documents with the given
+    /// name lengths from `actor` to the owner. Owner stores and zero fees cost
+    /// nothing; the first stores within the actor's free quota are skipped.
+    /// Must run before the documents are written.
+    fn charge_fee(
+        env: &Env,
+        actor: &Address,
+        role: ActorRole,
+        fee: Option<Fee>,
+        name_lens: &Vec<u32>,
+    ) {
+        let config = Self::get_config(env.clone());
+        let fee = match fee {
+            Some(fee) if fee.amount > 0 || config.fee_per_byte > 0 => fee,
+            _ => return,
+        };
+        if role == ActorRole::Owner {
+            return;
+        }
+        let used = Self::actor_document_count(env.clone(), actor.clone());
+        let free = config.free_quota_per_actor.saturating_sub(used).min(name_lens.len());
+        if free > 0 {
+            events::free_quota_used(env, actor, free);
+        }
+        let mut total: i128 = 0;
+        for len in name_lens.iter().skip(free as usize) {
+            let amount = Self::fee_amount(&fee, config.fee_per_byte, len);
+            total = total.checked_add(amount).expect("fee overflow");
+        }
+        let total = Self::apply_batch_discount(env, total, name_lens.len());
+        if total > 0 {
+            let owner = Self::owner_address(env.clone());
+            token::Client::new(env, &fee.token).transfer(actor, &owner, &total);
+        }
+    }
+
+    /// Internal: flat fee plus the per-byte charge for a name of `name_len` bytes
+    fn fee_amount(fee: &Fee, fee_per_byte: i128, name_len: u32) -> i128 {
+        fee_per_byte
+            .checked_mul(name_len as i128)
+            .and_then(|bytes| bytes.checked_add(fee.amount))
+            .expect("fee overflow")
+    }
+
+    /// Read-only: batch discount tiers, ascending by `min_docs`
+    pub fn get_discount_tiers(env: Env) -> Vec<DiscountTier> {
+        env.storage()
+            .instance()
+            .get(&DataKey::DiscountTiers)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Owner-only: replace the batch discount tiers (empty => no discounts).
+    /// Tiers must start at 2 documents and ascend strictly in both fields.
+    pub fn set_discount_tiers(env: Env, tiers: Vec<DiscountTier>) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        if tiers.len() > MAX_DISCOUNT_TIERS {
+            panic!("invalid discount tiers");
+        }
+        let mut prev = DiscountTier { min_docs: 1, discount_bps: 0 };
+        for tier in tiers.iter() {
+            if tier.min_docs <= prev.min_docs
+                || tier.discount_bps <= prev.discount_bps
+                || tier.discount_bps > BPS_DENOMINATOR
+            {
+                panic!("invalid discount tiers");
+            }
+            prev = tier;
+        }
+        env.storage().instance().set(&DataKey::DiscountTiers, &tiers);
+    }
+
+    /// Internal: `total` less the discount of the highest tier `count`
+    /// reaches; the whole batch gets that one tier, rounded down
+    fn apply_batch_discount(env: &Env, total: i128, count: u32) -> i128 {
+        let bps = Self::get_discount_tiers(env.clone())
+            .iter()
+            .filter(|tier| count >= tier.min_docs)
+            .last()
+            .map_or(0, |tier| tier.discount_bps);
+        if bps == 0 {
+            return total;
+        }
+        total
+            .checked_mul((BPS_DENOMINATOR - bps) as i128)
+            .expect("fee overflow")
+            / BPS_DENOMINATOR as i128
+    }
+
+    /// Read-only: flat registration fees `store_documents` would charge a
+    /// non-owner outside its free quota for `count` documents, after the
+    /// batch discount. `fee_per_byte` charges are not included.
+    pub fn quote_batch_fee(env: Env, count: u32) -> i128 {
+        let amount = match Self::get_config(env.clone()).registration_fee.fee() {
+            Some(fee) => fee.amount,
+            None => return 0,
+        };
+        let total = amount.checked_mul(count as i128).expect("fee overflow");
+        Self::apply_batch_discount(&env, total, count)
+    }
+
+    /// Read-only: what `store_document` would charge a non-owner outside its
+    /// free quota for this input (0 when no fee is configured)
+    pub fn quote_store_fee(env: Env, name: String, hash: String) -> i128 {
+        Self::assert_valid_input(&env, &name, &hash);
+        let config = Self::get_config(env.clone());
+        match config.registration_fee.fee() {
+            Some(fee) => Self::fee_amount(&fee, config.fee_per_byte, name.len()),
+            None => 0,
+        }
+    }
+
+    /// Owner-only: override the registration fee for `category` (0 => free)
+    pub fn set_category_fee(env: Env, category: Symbol, token: Address, amount: i128) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        let fee = Fee { token, amount };
+        Self::assert_valid_fee(&fee);
+        env.storage()
+            .persistent()
+            .set(&DataKey::CategoryFee(category.clone()), &fee);
+        events::category_fee_set(&env, &category, &fee);
+    }
+
+    /// Read-only: fee charged for `category` (its override, else the global fee)
+    pub fn get_category_fee(env: Env, category: Symbol) -> Option<Fee> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CategoryFee(category))
+            .or(Self::get_config(env).registration_fee.fee())
+    }
+
+    /// Store a document under `category` (ONLY OWNER and whitelist), charging
+    /// the category fee.
+    pub fn store_document_categorized(
+        env: Env,
+        actor: Address,
+        name: String,
+        hash: String,
+        category: Symbol,
+    ) {
+        let fee = Self::get_category_fee(env.clone(), category.clone());
+        let role = Self::prepare_store(&env, &actor, &name, &hash, fee);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Category(hash.clone()), &category);
+        Self::bump_category_count(&env, &category, 1);
+        let timestamp = env.ledger().timestamp();
+        Self::write_document(&env, actor, role, name, hash, timestamp);
+    }
+
+    /// Read-only: category a document was registered under, if any
+    pub fn document_category(env: Env, hash: String) -> Option<Symbol> {
+        env.storage().persistent().get(&DataKey::Category(hash))
+    }
+
+    // ---------- EMBARGO ----------
+    //
+    // An embargoed document's hash is public, but its name is redacted by the
+    // read paths until `unlock_at` (ledger time) or an early `lift_embargo`.
+
+    /// Internal: whether the name of `hash` is still withheld
+    fn is_embargoed(env: &Env, hash: &String) -> bool {
+        match Self::embargo_until(env.clone(), hash.clone()) {
+            Some(unlock_at) => env.ledger().timestamp() < unlock_at,
+            None => false,
+        }
+    }
+
+    /// Store a document whose name stays redacted until `unlock_at`
+    /// (ONLY OWNER and whitelist).
+    pub fn store_embargoed_document(
+        env: Env,
+        actor: Address,
+        name: String,
+        hash: String,
+        unlock_at: u64,
+    ) {
+        let timestamp = env.ledger().timestamp();
+        if unlock_at <= timestamp {
+            panic!("unlock time must be in the future");
+        }
+        let fee = Self::get_config(env.clone()).registration_fee.fee();
+        let role = Self::prepare_store(&env, &actor, &name, &hash, fee);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Embargo(hash.clone()), &unlock_at);
+        Self::write_document(&env, actor, role, name, hash, timestamp);
+    }
+
+    /// Owner-only: end an embargo before its unlock time
+    pub fn lift_embargo(env: Env, hash: String) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        let key = DataKey::Embargo(hash.clone());
+        if !env.storage().persistent().has(&key) {
+            panic!("document not embargoed");
+        }
+        env.storage().persistent().remove(&key);
+        Self::touch_document(&env, &hash, &owner);
+        events::embargo_lifted(&env, &hash);
+    }
+
+    /// Read-only: unlock time of a document's embargo, if one was set and not lifted
+    pub fn embargo_until(env: Env, hash: String) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::Embargo(hash))
+    }
+
+    // ---------- STORAGE MIGRATION ----------
+    //
+    // Older entries under `DataKey::Document` are either a full `Document`
+    // (before the core/ext split) or an address-bearing `DocumentCore` (before
+    // actor interning). Every read path decodes all layouts, so migrating is
+    // optional; it only makes old entries cheaper to read and store.
+
+    /// Owner-only: rewrite older entries in the compact layout and bump them.
+    /// Missing or already-migrated hashes are skipped. Returns how many moved.
+    pub fn migrate_documents(env: Env, hashes: Vec<String>) -> u32 {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        Self::assert_batch_size(&hashes, Self::get_limits(env.clone()).max_batch_bump);
+        let mut migrated = 0;
+        for hash in hashes.iter() {
+            let doc = match Self::load_entry(&env, &hash) {
+                Some(StoredEntry::Legacy(_)) | Some(StoredEntry::Split(_)) => {
+                    Self::load_full(&env, &hash).unwrap()
+                }
+                _ => continue,
+            };
+            Self::put_document(&env, &doc);
+            Self::extend_document_ttl(&env, &hash, DOC_BUMP_AMOUNT);
+            migrated += 1;
+        }
+        migrated
+    }
+
+    // ---------- CURSOR PAGINATION ----------
+    //
+    // Each listing walks an append-only slot index: deleted entries leave an
+    // empty slot behind, so a cursor stays valid across deletions. A page
+    // walks at most `limit` slots and may hold fewer items than that.
+
+    /// Internal: assign the next slot of the index counted by `count_key`
+    fn next_slot(env: &Env, count_key: &DataKey) -> u32 {
+        let slot: u32 = env.storage().instance().get(count_key).unwrap_or(0);
+        env.storage().instance().set(count_key, &(slot + 1));
+        slot
+    }
+
+    /// Internal: slots `cursor..end` to walk for a page and the cursor after it
+    fn page_range(cursor: u32, limit: u32, total: u32) -> (u32, Option<u32>) {
+        let end = total.min(cursor.saturating_add(limit.min(MAX_PAGE_SIZE)));
+        let next = if end < total { Some(end) } else { None };
+        (end, next)
+    }
+
+    /// Read-only: page of all documents in registration order
+    pub fn list_documents_page(env: Env, cursor: u32, limit: u32) -> DocumentPage {
+        let total: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::DocIndexCount)
+            .unwrap_or(0);
+        let (end, next_cursor) = Self::page_range(cursor, limit, total);
+        let mut items = Vec::new(&env);
+        for i in cursor..end {
+            let slot: Option<String> = env.storage().persistent().get(&DataKey::DocIndex(i));
+            if let Some(summary) = slot.and_then(|h| Self::read_document_summary(env.clone(), h)) {
+                items.push_back(summary);
+            }
+        }
+        DocumentPage { items, next_cursor }
+    }
+
+    /// Read-only: page of the documents stored by `address`
+    pub fn list_documents_by_actor_page(
+        env: Env,
+        address: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> DocumentPage {
+        let total = Self::actor_document_count(env.clone(), address.clone());
+        let (end, next_cursor) = Self::page_range(cursor, limit, total);
+        let items = if cursor < end {
+            Self::list_documents_by_actor(env, address, cursor, end - cursor)
+        } else {
+            Vec::new(&env)
+        };
+        DocumentPage { items, next_cursor }
+    }
+
+    /// Read-only: page of currently whitelisted addresses, in the order they
+    /// were added. Addresses whitelisted before the index existed are absent.
+    pub fn list_whitelist_page(env: Env, cursor: u32, limit: u32) -> WhitelistPage {
+        let total: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::WhitelistIndexCount)
+            .unwrap_or(0);
+        let (end, next_cursor) = Self::page_range(cursor, limit, total);
+        let mut items = Vec::new(&env);
+        for i in cursor..end {
+            let slot: Option<Address> =
+                env.storage().persistent().get(&DataKey::WhitelistIndex(i));
+            if let Some(address) = slot {
+                let label = Self::get_actor_label(env.clone(), address.clone());
+                items.push_back(WhitelistEntry { address, label });
+            }
+        }
+        WhitelistPage { items, next_cursor }
+    }
+
+    // ---------- RECENT DOCUMENTS ----------
+
+    /// Read-only: global store sequence of a document (its slot in the global
+    /// index); later registrations always get a higher sequence
+    pub fn document_sequence(env: Env, hash: String) -> Option<u32> {
+        env.storage().persistent().get(&DataKey::DocSlot(hash))
+    }
+
+    /// Read-only: up to `limit` most recent documents, newest first. Walks back
+    /// at most `MAX_PAGE_SIZE` index slots, skipping deleted ones.
+    pub fn list_recent_documents(env: Env, limit: u32) -> Vec<DocumentSummary> {
+        let mut i: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::DocIndexCount)
+            .unwrap_or(0);
+        let floor = i.saturating_sub(MAX_PAGE_SIZE);
+        let mut out = Vec::new(&env);
+        while i > floor && out.len() < limit {
+            i -= 1;
+            let slot: Option<String> = env.storage().persistent().get(&DataKey::DocIndex(i));
+            if let Some(summary) = slot.and_then(|h| Self::read_document_summary(env.clone(), h)) {
+                out.push_back(summary);
+            }
+        }
+        out
+    }
+
+    /// Read-only: the most recently registered document still stored
+    pub fn latest_document(env: Env) -> Option<DocumentSummary> {
+        Self::list_recent_documents(env, 1).first()
+    }
+
+    // ---------- ACTIVITY ----------
+
+    /// Internal: stamp the `ActivityInfo` field for `kind` with the ledger time
+    fn record_activity(env: &Env, kind: Activity) {
+        let mut info = Self::get_activity(env.clone());
+        let now = env.ledger().timestamp();
+        match kind {
+            Activity::Store => info.last_store_at = now,
+            Activity::WhitelistChange => info.last_whitelist_change_at = now,
+            Activity::OwnershipChange => info.last_ownership_change_at = now,
+        }
+        env.storage().instance().set(&DataKey::Activity, &info);
+    }
+
+    /// Read-only: when the registry last saw a store, whitelist change and
+    /// ownership change (0 => never)
+    pub fn get_activity(env: Env) -> ActivityInfo {
+        env.storage()
+            .instance()
+            .get(&DataKey::Activity)
+            .unwrap_or_default()
+    }
+
+    // ---------- STATS ----------
+
+    /// Internal: add `delta` to an instance counter (floored at zero)
+    fn bump_counter(env: &Env, key: &DataKey, delta: i32) {
+        let value: u32 = env.storage().instance().get(key).unwrap_or(0);
+        let value = value.saturating_add_signed(delta);
+        env.storage().instance().set(key, &value);
+    }
+
+    /// Read-only: number of addresses currently whitelisted
+    pub fn whitelisted_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::WhitelistCount)
+            .unwrap_or(0)
+    }
+
+    /// Read-only: number of stored documents that have been revoked
+    pub fn revoked_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RevokedCount)
+            .unwrap_or(0)
+    }
+
+    /// Read-only: registry-wide counters and flags in one call
+    pub fn get_stats(env: Env) -> RegistryStats {
+        RegistryStats {
+            document_count: Self::document_count(env.clone()),
+            whitelisted_count: Self::whitelisted_count(env.clone()),
+            revoked_count: Self::revoked_count(env.clone()),
+            sealed: Self::is_sealed(env.clone()),
+            decommissioned: Self::get_successor(env).is_some(),
+            schema_version: SCHEMA_VERSION,
+        }
+    }
+
+    /// Read-only: versions, ownership, flags and headline counters in one call
+    pub fn get_contract_info(env: Env) -> ContractInfo {
+        ContractInfo {
+            interface_version: INTERFACE_VERSION,
+            schema_version: SCHEMA_VERSION,
+            event_schema_version: events::EVENT_SCHEMA_VERSION,
+            owner: Self::owner_address(env.clone()),
+            pending_owner: Self::pending_owner(env.clone()),
+            sealed: Self::is_sealed(env.clone()),
+            decommissioned: Self::get_successor(env.clone()).is_some(),
+            document_count: Self::document_count(env.clone()),
+            whitelisted_count: Self::whitelisted_count(env.clone()),
+            registration_fee: Self::get_config(env).registration_fee,
+        }
+    }
+
+    /// Read-only: version of the public entrypoint surface
+    pub fn interface_version(_env: Env) -> u32 {
+        INTERFACE_VERSION
+    }
+
+    /// Read-only: features this deployment offers, sorted. Built-in features
+    /// are always listed; the rest only while their config enables them.
+    /// Names are stable: new features are added, existing ones never renamed.
+    pub fn supported_features(env: Env) -> Vec<Symbol> {
+        let config = Self::get_config(env.clone());
+        let provider = env.storage().instance().has(&DataKey::AuthProvider);
+        let legacy = config.legacy_hash_import
+            && !env.storage().instance().has(&DataKey::LegacyImportClosed);
+        // Keep in ASCII order
+        let features = [
+            ("access_stake", config.access_stake.fee().is_some()),
+            ("auth_provider", provider),
+            ("bundles", true),
+            ("categories", true),
+            ("chain", true),
+            ("daily_limit", config.daily_store_limit > 0),
+            ("fees", config.registration_fee.fee().is_some()),
+            ("idempotency", true),
+            ("legacy_hash", legacy),
+            ("namespaces", true),
+            ("recovery", true),
+            ("retention", true),
+            ("revocation", true),
+            ("tsa", true),
+            ("unique_names", config.unique_names),
+            ("versions", config.duplicate_policy == DuplicatePolicy::Version),
+            ("veto", config.veto_window_secs > 0),
+            ("views", true),
+        ];
+        let mut out = Vec::new(&env);
+        for (name, enabled) in features {
+            if enabled {
+                out.push_back(Symbol::new(&env, name));
+            }
+        }
+        out
+    }
+
+    // ---------- VETO WINDOW ----------
+    //
+    // With `veto_window_secs` set, documents stored by whitelisted actors are
+    // `Pending` for that long and become verified on their own unless vetoed.
+
+    /// Owner-only: veto a whitelisted submission while its window is open
+    pub fn veto_document(env: Env, hash: String, reason: String) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        if reason.len() > MAX_REASON_LEN {
+            panic!("reason too long");
+        }
+        let veto_until: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::VetoUntil(hash.clone()))
+            .expect("document not subject to veto");
+        if env.ledger().timestamp() >= veto_until {
+            panic!("veto window closed");
+        }
+        if Self::status_of(&env, &hash) == DocumentStatus::Revoked {
+            panic!("document revoked");
+        }
+        let record = VetoRecord {
+            reason,
+            vetoed_at: env.ledger().timestamp(),
+        };
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Status(hash.clone()), &DocumentStatus::Vetoed);
+        storage.set(&DataKey::Veto(hash.clone()), &record);
+        Self::touch_document(&env, &hash, &owner);
+        Self::extend_document_ttl(&env, &hash, DOC_BUMP_AMOUNT);
+        events::document_vetoed(&env, &hash, &record.reason);
+    }
+
+    /// Read-only: end of a document's veto window, if it had one
+    pub fn veto_window_end(env: Env, hash: String) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::VetoUntil(hash))
+    }
+
+    /// Read-only: the owner's veto of a document, if any
+    pub fn get_veto(env: Env, hash: String) -> Option<VetoRecord> {
+        env.storage().persistent().get(&DataKey::Veto(hash))
+    }
+
+    // ---------- KEY ROTATION ----------
+
+    /// Owner-only, co-signed by `new`: move a whitelist entry to a new key.
+    /// Documents keep `added_by = old`; control over them passes to `new`.
+    pub fn rotate_whitelisted_address(env: Env, old: Address, new: Address) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        new.require_auth();
+        if !Self::is_whitelisted(env.clone(), old.clone()) {
+            panic!("address not whitelisted");
+        }
+        if Self::is_whitelisted(env.clone(), new.clone()) {
+            panic!("new address already whitelisted");
+        }
+        Self::add_to_whitelist(&env, new.clone());
+        Self::remove_whitelist_entry(&env, old.clone(), String::from_str(&env, "rotated"));
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::RotatedFrom(new.clone()), &old);
+        storage.set(&DataKey::RotatedTo(old.clone()), &new);
+        events::whitelist_rotated(&env, &new, &old);
+    }
+
+    /// Read-only: the key `address` replaced through rotation, if any
+    pub fn rotated_from(env: Env, address: Address) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::RotatedFrom(address))
+    }
+
+    // ---------- OWNER NOTES ----------
+    //
+    // Contract storage is world-readable off-chain; notes are only hidden from
+    // the contract's own read API, so they must not hold secrets.
+
+    /// Owner-only: attach (or overwrite) an internal note on a document
+    pub fn set_owner_note(env: Env, hash: String, note: String) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        if note.len() > MAX_NOTE_LEN {
+            panic!("note too long");
+        }
+        Self::load_document(&env, &hash);
+        env.storage()
+            .persistent()
+            .set(&DataKey::OwnerNote(hash.clone()), &note);
+        Self::extend_document_ttl(&env, &hash, DOC_BUMP_AMOUNT);
+    }
+
+    /// Owner-only: remove a document's internal note
+    pub fn clear_owner_note(env: Env, hash: String) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        env.storage().persistent().remove(&DataKey::OwnerNote(hash));
+    }
+
+    /// Internal note on a document; `caller` must sign, and only the owner
+    /// gets the note back (everyone else gets None)
+    pub fn get_owner_note(env: Env, caller: Address, hash: String) -> Option<String> {
+        caller.require_auth();
+        if caller != Self::owner_address(env.clone()) {
+            return None;
+        }
+        env.storage().persistent().get(&DataKey::OwnerNote(hash))
+    }
+
+    // ---------- DIGEST ALIASES ----------
+
+    /// Internal: primary hash for `hash` if it is a registered alias
+    fn resolve_alias(env: &Env, hash: String) -> String {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Alias(hash.clone()))
+            .unwrap_or(hash)
+    }
+
+    /// Internal: whether `digest` is already taken by a document or an alias
+    fn digest_taken(env: &Env, digest: &String) -> bool {
+        let storage = env.storage().persistent();
+        storage.has(&DataKey::Document(digest.clone()))
+            || storage.has(&DataKey::Alias(digest.clone()))
+    }
+
+    /// Store one document under its SHA-256 digest, also discoverable by its
+    /// Keccak-256 digest through `read_document` / `verify_document`
+    /// (ONLY OWNER and whitelist).
+    pub fn store_document_multihash(
+        env: Env,
+        actor: Address,
+        name: String,
+        sha256_hex: String,
+        keccak_hex: String,
+    ) {
+        if sha256_hex == keccak_hex || Self::digest_taken(&env, &keccak_hex) {
+            panic!("digest already registered");
+        }
+        let fee = Self::get_config(env.clone()).registration_fee.fee();
+        let role = Self::prepare_store(&env, &actor, &name, &sha256_hex, fee);
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Alias(keccak_hex.clone()), &sha256_hex);
+        storage.set(&DataKey::DocAlias(sha256_hex.clone()), &keccak_hex);
+        let timestamp = env.ledger().timestamp();
+        Self::write_document(&env, actor, role, name, sha256_hex, timestamp);
+    }
+
+    // ---------- BUNDLES ----------
+
+    /// Internal: `actor` must have signed and be the bundle creator or the
+    /// owner; returns the bundle, which must not be sealed yet
+    fn open_bundle_for(env: &Env, actor: &Address, bundle_id: &String) -> Bundle {
+        actor.require_auth();
+        let bundle = Self::get_bundle(env.clone(), bundle_id.clone());
+        if actor != &bundle.creator && actor != &Self::owner_address(env.clone()) {
+            panic!("not authorized: only bundle creator or owner");
+        }
+        if bundle.sealed {
+            panic!("bundle sealed");
+        }
+        bundle
+    }
+
+    /// Internal: persist a bundle and bump its entry
+    fn put_bundle(env: &Env, bundle: &Bundle) {
+        let key = DataKey::Bundle(bundle.bundle_id.clone());
+        env.storage().persistent().set(&key, bundle);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, DOC_BUMP_AMOUNT, DOC_BUMP_AMOUNT);
+    }
+
+    /// Create an empty bundle (ONLY OWNER and whitelist)
+    pub fn create_bundle(env: Env, actor: Address, bundle_id: String, name: String) {
+        Self::assert_not_sealed(&env);
+        Self::assert_owner_or_whitelisted_actor(&env, &actor, symbol_short!("bundle"));
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Bundle(bundle_id.clone()))
+        {
+            panic!("bundle already exists");
+        }
+        let bundle = Bundle {
+            bundle_id: bundle_id.clone(),
+            name,
+            creator: actor.clone(),
+            members: Vec::new(&env),
+            sealed: false,
+            created_at: env.ledger().timestamp(),
+            sealed_at: None,
+        };
+        Self::put_bundle(&env, &bundle);
+        events::bundle_created(&env, &bundle_id, &actor);
+    }
+
+    /// Bundle creator or owner: add an existing document to an unsealed bundle
+    pub fn add_to_bundle(env: Env, actor: Address, bundle_id: String, hash: String) {
+        Self::assert_not_sealed(&env);
+        let mut bundle = Self::open_bundle_for(&env, &actor, &bundle_id);
+        Self::load_document(&env, &hash);
+        if bundle.members.contains(&hash) {
+            panic!("document already in bundle");
+        }
+        if bundle.members.len() >= MAX_BUNDLE_SIZE {
+            panic!("bundle full");
+        }
+        bundle.members.push_back(hash.clone());
+        Self::put_bundle(&env, &bundle);
+        events::bundle_added(&env, &bundle_id, &hash);
+    }
+
+    /// Bundle creator or owner: freeze a bundle's membership. Irreversible.
+    pub fn seal_bundle(env: Env, actor: Address, bundle_id: String) {
+        Self::assert_not_sealed(&env);
+        let mut bundle = Self::open_bundle_for(&env, &actor, &bundle_id);
+        bundle.sealed = true;
+        bundle.sealed_at = Some(env.ledger().timestamp());
+        Self::put_bundle(&env, &bundle);
+        events::bundle_sealed(&env, &bundle_id, bundle.members.len());
+    }
+
+    /// Read-only: a bundle with its member hashes
+    pub fn get_bundle(env: Env, bundle_id: String) -> Bundle {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Bundle(bundle_id))
+            .expect("bundle not found")
+    }
+
+    // ---------- WITNESSES ----------
+
+    /// Any address: record that `witness` saw the document exist (once each)
+    pub fn witness_document(env: Env, witness: Address, hash: String) {
+        Self::assert_not_sealed(&env);
+        witness.require_auth();
+        Self::load_document(&env, &hash);
+        let key = DataKey::Witness(hash.clone(), witness.clone());
+        if env.storage().persistent().has(&key) {
+            panic!("already witnessed");
+        }
+        let now = env.ledger().timestamp();
+        let storage = env.storage().persistent();
+        storage.set(&key, &now);
+        storage.extend_ttl(&key, DOC_BUMP_AMOUNT, DOC_BUMP_AMOUNT);
+        let count = Self::witness_count(env.clone(), hash.clone());
+        storage.set(&DataKey::WitnessCount(hash.clone()), &(count + 1));
+        Self::extend_document_ttl(&env, &hash, DOC_BUMP_AMOUNT);
+        events::document_witnessed(&env, &hash, &witness);
+    }
+
+    /// Read-only: number of addresses that witnessed a document
+    pub fn witness_count(env: Env, hash: String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::WitnessCount(hash))
+            .unwrap_or(0)
+    }
+
+    /// Read-only: whether `address` witnessed a document
+    pub fn has_witnessed(env: Env, address: Address, hash: String) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Witness(hash, address))
+    }
+
+    // ---------- RECEIPTS ----------
+
+    /// Read-only: proof-of-existence receipt for a document
+    pub fn get_receipt(env: Env, hash: String) -> Option<Receipt> {
+        let doc = Self::read_document(env.clone(), hash)?;
+        let meta: Option<StoreMeta> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::StoreMeta(doc.hash.clone()));
+        let (ledger_sequence, event_seq) = match meta {
+            Some(m) => (m.ledger_sequence, m.event_seq),
+            None => (0, 0),
+        };
+        Some(Receipt {
+            created_at: Self::document_created_at(env.clone(), doc.hash.clone()),
+            doc_id: Self::document_id(env.clone(), doc.hash.clone()),
+            hash: doc.hash,
+            name: doc.name,
+            timestamp: doc.timestamp,
+            ledger_sequence,
+            contract: env.current_contract_address(),
+            event_seq,
+        })
+    }
+
+    // ---------- ACTOR LABELS ----------
+    //
+    // Labels are keyed by address and survive whitelist removal, so a
+    // re-whitelisted address keeps its label.
+
+    /// Owner-only: set the display label of `address`; an empty label clears it
+    pub fn set_actor_label(env: Env, address: Address, label: String) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        if label.len() > MAX_LABEL_LEN {
+            panic!("label too long");
+        }
+        let key = DataKey::ActorLabel(address.clone());
+        if label.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &label);
+        }
+        events::actor_labeled(&env, &address, &label);
+    }
+
+    /// Read-only: display label of `address`, if any
+    pub fn get_actor_label(env: Env, address: Address) -> Option<String> {
+        env.storage().persistent().get(&DataKey::ActorLabel(address))
+    }
+
+    /// Read-only: whitelist entry of `address` (None if not whitelisted)
+    pub fn get_whitelist_entry(env: Env, address: Address) -> Option<WhitelistEntry> {
+        if !Self::is_whitelisted(env.clone(), address.clone()) {
+            return None;
+        }
+        let label = Self::get_actor_label(env, address.clone());
+        Some(WhitelistEntry { address, label })
+    }
+
+    // ---------- ACTOR PROFILES ----------
+    //
+    // Unlike labels, a profile is the actor's own claim and is removed when
+    // the actor leaves the whitelist.
+
+    /// Internal: check bounds and persist `profile` for `address`
+    fn put_profile(env: &Env, address: &Address, profile: &ActorProfile) {
+        if profile.org_name.is_empty() {
+            panic!("organization name required");
+        }
+        if profile.org_name.len() > MAX_ORG_NAME_LEN {
+            panic!("organization name too long");
+        }
+        if profile.url.len() > MAX_URI_LEN {
+            panic!("url too long");
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Profile(address.clone()), profile);
+        events::profile_set(env, address, &profile.org_name);
+    }
+
+    /// Whitelisted actor: publish or replace its own profile
+    pub fn set_actor_profile(env: Env, actor: Address, profile: ActorProfile) {
+        Self::assert_not_sealed(&env);
+        actor.require_auth();
+        if !Self::is_whitelisted(env.clone(), actor.clone()) {
+            panic!("not whitelisted");
+        }
+        Self::put_profile(&env, &actor, &profile);
+    }
+
+    /// Owner-only: set the profile of a whitelisted address on its behalf
+    pub fn set_actor_profile_admin(env: Env, address: Address, profile: ActorProfile) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        if !Self::is_whitelisted(env.clone(), address.clone()) {
+            panic!("not whitelisted");
+        }
+        Self::put_profile(&env, &address, &profile);
+    }
+
+    /// Read-only: published profile of `address`, if any
+    pub fn get_actor_profile(env: Env, address: Address) -> Option<ActorProfile> {
+        env.storage().persistent().get(&DataKey::Profile(address))
+    }
+
+    // ---------- DOCUMENT TYPES ----------
+
+    /// Store a typed document (ONLY OWNER and whitelist)
+    pub fn store_document_typed(
+        env: Env,
+        actor: Address,
+        name: String,
+        hash: String,
+        doc_type: DocType,
+    ) {
+        let config = Self::get_config(env.clone());
+        let fee = config.registration_fee.fee();
+        let role = Self::prepare_store(&env, &actor, &name, &hash, fee);
+        if config.strict_type_permissions
+            && role != ActorRole::Owner
+            && !Self::is_type_allowed(env.clone(), actor.clone(), doc_type)
+        {
+            panic!("not authorized for document type");
+        }
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::DocType(hash.clone()), &doc_type);
+        let count_key = DataKey::TypeDocCount(doc_type);
+        let n: u32 = storage.get(&count_key).unwrap_or(0);
+        storage.set(&DataKey::TypeDoc(doc_type, n), &hash);
+        storage.set(&count_key, &(n + 1));
+        let timestamp = env.ledger().timestamp();
+        Self::write_document(&env, actor, role, name, hash, timestamp);
+    }
+
+    /// Internal: type of an existing document (`Other` when untyped)
+    fn doc_type_of(env: &Env, hash: &String) -> DocType {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DocType(hash.clone()))
+            .unwrap_or(DocType::Other)
+    }
+
+    /// Read-only: type of a document (None if it doesn't exist)
+    pub fn document_type(env: Env, hash: String) -> Option<DocType> {
+        let hash = Self::resolve_alias(&env, hash);
+        Self::load_core(&env, &hash)?;
+        Some(Self::doc_type_of(&env, &hash))
+    }
+
+    /// Read-only: documents registered with `doc_type`, in registration order.
+    /// Only covers `store_document_typed`; untyped documents are never listed.
+    pub fn list_by_type(
+        env: Env,
+        doc_type: DocType,
+        start: u32,
+        limit: u32,
+    ) -> Vec<DocumentSummary> {
+        let total: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TypeDocCount(doc_type))
+            .unwrap_or(0);
+        let end = total.min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+        let mut out = Vec::new(&env);
+        for i in start..end {
+            let slot: Option<String> =
+                env.storage().persistent().get(&DataKey::TypeDoc(doc_type, i));
+            // Skip slots whose document was deleted or re-registered differently
+            let slot = slot.filter(|h| Self::doc_type_of(&env, h) == doc_type);
+            if let Some(summary) = slot.and_then(|h| Self::read_document_summary(env.clone(), h)) {
+                out.push_back(summary);
+            }
+        }
+        out
+    }
+
+    /// Owner-only: let `address` store documents of `doc_type` under
+    /// `strict_type_permissions`
+    pub fn allow_type_for(env: Env, address: Address, doc_type: DocType) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        env.storage()
+            .persistent()
+            .set(&DataKey::TypeGrant(address.clone(), doc_type), &true);
+        events::type_grant_changed(&env, &address, doc_type, true);
+    }
+
+    /// Owner-only: withdraw a type grant
+    pub fn disallow_type_for(env: Env, address: Address, doc_type: DocType) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::TypeGrant(address.clone(), doc_type));
+        events::type_grant_changed(&env, &address, doc_type, false);
+    }
+
+    /// Read-only: whether `address` holds a grant for `doc_type`
+    pub fn is_type_allowed(env: Env, address: Address, doc_type: DocType) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TypeGrant(address, doc_type))
+            .unwrap_or(false)
+    }
+
+    // ---------- SUPERSEDE ----------
+    //
+    // Each document supersedes at most one other and is superseded by at most
+    // one, so links form chains. Links are caller-supplied and may close a
+    // cycle; `get_document_chain` detects that rather than looping.
+
+    /// Owner or controller of `old_hash`: record that `new_hash` replaces it
+    pub fn supersede_document(env: Env, actor: Address, old_hash: String, new_hash: String) {
+        Self::assert_not_sealed(&env);
+        Self::assert_owner_or_controller(&env, &actor, &old_hash);
+        Self::load_document(&env, &new_hash);
+        if old_hash == new_hash {
+            panic!("document cannot supersede itself");
+        }
+        let storage = env.storage().persistent();
+        if storage.has(&DataKey::SupersededBy(old_hash.clone())) {
+            panic!("document already superseded");
+        }
+        if storage.has(&DataKey::Supersedes(new_hash.clone())) {
+            panic!("document already supersedes another");
+        }
+        storage.set(&DataKey::SupersededBy(old_hash.clone()), &new_hash);
+        storage.set(&DataKey::Supersedes(new_hash.clone()), &old_hash);
+        Self::touch_document(&env, &old_hash, &actor);
+        Self::touch_document(&env, &new_hash, &actor);
+        events::document_superseded(&env, &old_hash, &new_hash);
+    }
+
+    /// Read-only: document that superseded `hash`, if any
+    pub fn superseded_by(env: Env, hash: String) -> Option<String> {
+        env.storage().persistent().get(&DataKey::SupersededBy(hash))
+    }
+
+    /// Read-only: document that `hash` supersedes, if any
+    pub fn supersedes(env: Env, hash: String) -> Option<String> {
+        env.storage().persistent().get(&DataKey::Supersedes(hash))
+    }
+
+    /// Read-only: the lineage of `hash`, oldest first, following at most
+    /// `max_hops` links in total (capped at `MAX_CHAIN_HOPS`). Panics if the
+    /// chain is longer or loops; stops early at a deleted document.
+    pub fn get_document_chain(env: Env, hash: String, max_hops: u32) -> Vec<DocumentSummary> {
+        let max_hops = max_hops.min(MAX_CHAIN_HOPS);
+        let start = Self::read_document_summary(env.clone(), hash.clone())
+            .expect("document not found");
+        let mut seen = Vec::from_array(&env, [hash.clone()]);
+        let mut older = Vec::new(&env);
+        let mut hops = 0;
+        let mut cursor = Self::supersedes(env.clone(), hash.clone());
+        while let Some(h) = cursor {
+            let Some(summary) = Self::read_document_summary(env.clone(), h.clone()) else {
+                break;
+            };
+            Self::step_chain(&mut seen, &h, &mut hops, max_hops);
+            older.push_front(summary);
+            cursor = Self::supersedes(env.clone(), h);
+        }
+        let mut chain = older;
+        chain.push_back(start);
+        let mut cursor = Self::superseded_by(env.clone(), hash);
+        while let Some(h) = cursor {
+            let Some(summary) = Self::read_document_summary(env.clone(), h.clone()) else {
+                break;
+            };
+            Self::step_chain(&mut seen, &h, &mut hops, max_hops);
+            chain.push_back(summary);
+            cursor = Self::superseded_by(env.clone(), h);
+        }
+        chain
+    }
+
+    /// Internal: count one hop of a chain walk, panicking on a revisit or
+    /// when the hop budget is spent
+    fn step_chain(seen: &mut Vec<String>, hash: &String, hops: &mut u32, max_hops: u32) {
+        if seen.contains(hash) {
+            panic!("supersede cycle detected");
+        }
+        if *hops == max_hops {
+            panic!("supersede chain exceeds max hops");
+        }
+        *hops += 1;
+        seen.push_back(hash.clone());
+    }
+
+    // ---------- UPDATE TRACKING ----------
+    //
+    // Every public mutation of a document record (status, pin, references,
+    // controllers, embargo, supersede links) calls `touch_document`. Owner
+    // notes are internal and leave no trace here.
+
+    /// Internal: record that `actor` just changed an existing document
+    fn touch_document(env: &Env, hash: &String, actor: &Address) {
+        let key = DataKey::Updated(hash.clone());
+        let count = env
+            .storage()
+            .persistent()
+            .get::<_, UpdateInfo>(&key)
+            .map_or(0, |info| info.update_count);
+        let info = UpdateInfo {
+            last_updated: env.ledger().timestamp(),
+            updated_by: actor.clone(),
+            update_count: count + 1,
+        };
+        env.storage().persistent().set(&key, &info);
+    }
+
+    /// Read-only: when and by whom a document last changed (None if missing)
+    pub fn get_update_info(env: Env, hash: String) -> Option<UpdateInfo> {
+        let doc = Self::load_core(&env, &hash)?;
+        Some(
+            env.storage()
+                .persistent()
+                .get(&DataKey::Updated(hash))
+                .unwrap_or(UpdateInfo {
+                    last_updated: doc.timestamp,
+                    updated_by: doc.added_by,
+                    update_count: 0,
+                }),
+        )
+    }
+
+    // ---------- SUBJECT DELETION REQUESTS ----------
+    //
+    // The subject of a document may ask for erasure; only the owner decides.
+    // Approval soft-deletes: the record stays (so the hash can't be
+    // re-registered) but its name is wiped and it no longer verifies.
+
+    /// Owner or controller: record the address a document is about
+    pub fn set_document_subject(env: Env, actor: Address, hash: String, subject: Address) {
+        Self::assert_not_sealed(&env);
+        Self::assert_owner_or_controller(&env, &actor, &hash);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Subject(hash.clone()), &subject);
+        Self::touch_document(&env, &hash, &actor);
+    }
+
+    /// Read-only: subject of a document, if one was recorded
+    pub fn document_subject(env: Env, hash: String) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Subject(hash))
+    }
+
+    /// Subject of the document: ask the owner to erase it. Replaces an
+    /// expired request; an open one must be answered first.
+    pub fn request_deletion(env: Env, subject: Address, hash: String, reason: String) {
+        Self::assert_not_sealed(&env);
+        subject.require_auth();
+        if Self::document_subject(env.clone(), hash.clone()) != Some(subject.clone()) {
+            panic!("not authorized: only the document subject");
+        }
+        if reason.len() > MAX_REASON_LEN {
+            panic!("reason too long");
+        }
+        if Self::status_of(&env, &hash) == DocumentStatus::Erased {
+            panic!("document erased");
+        }
+        let now = env.ledger().timestamp();
+        if let Some(open) = Self::stored_deletion_request(&env, &hash) {
+            if now <= open.expires_at {
+                panic!("deletion already requested");
+            }
+            Self::close_deletion_request(&env, &hash);
+        }
+        let ttl = Self::get_config(env.clone()).deletion_request_ttl_secs;
+        let request = DeletionRequest {
+            hash: hash.clone(),
+            subject,
+            reason,
+            requested_at: now,
+            expires_at: now.saturating_add(ttl),
+        };
+        let slot = Self::next_slot(&env, &DataKey::DeletionQueueCount);
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::DeletionRequest(hash.clone()), &request);
+        storage.set(&DataKey::DeletionQueue(slot), &hash);
+        storage.set(&DataKey::DeletionSlot(hash.clone()), &slot);
+        events::deletion_requested(&env, &hash, &request.subject);
+    }
+
+    /// Internal: stored request for `hash`, expired or not
+    fn stored_deletion_request(env: &Env, hash: &String) -> Option<DeletionRequest> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DeletionRequest(hash.clone()))
+    }
+
+    /// Internal: drop a request and its queue slot
+    fn close_deletion_request(env: &Env, hash: &String) {
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::DeletionRequest(hash.clone()));
+        let slot_key = DataKey::DeletionSlot(hash.clone());
+        if let Some(slot) = storage.get::<_, u32>(&slot_key) {
+            storage.remove(&DataKey::DeletionQueue(slot));
+            storage.remove(&slot_key);
+        }
+    }
+
+    /// Internal: the open request for `hash`, or panic if none or expired
+    fn open_deletion_request(env: &Env, hash: &String) -> DeletionRequest {
+        let request = Self::stored_deletion_request(env, hash).expect("no deletion request");
+        if env.ledger().timestamp() > request.expires_at {
+            panic!("deletion request expired");
+        }
+        request
+    }
+
+    /// Read-only: open (unexpired) deletion request for a document
+    pub fn get_deletion_request(env: Env, hash: String) -> Option<DeletionRequest> {
+        Self::stored_deletion_request(&env, &hash)
+            .filter(|request| env.ledger().timestamp() <= request.expires_at)
+    }
+
+    /// Read-only: page of open deletion requests, oldest first
+    pub fn list_deletion_requests(env: Env, cursor: u32, limit: u32) -> DeletionPage {
+        let total: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::DeletionQueueCount)
+            .unwrap_or(0);
+        let (end, next_cursor) = Self::page_range(cursor, limit, total);
+        let mut items = Vec::new(&env);
+        for i in cursor..end {
+            let slot: Option<String> = env.storage().persistent().get(&DataKey::DeletionQueue(i));
+            if let Some(request) = slot.and_then(|h| Self::get_deletion_request(env.clone(), h)) {
+                items.push_back(request);
+            }
+        }
+        DeletionPage { items, next_cursor }
+    }
+
+    /// Owner-only: grant an open request, soft-deleting the document
+    pub fn approve_deletion(env: Env, hash: String) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        Self::assert_not_on_legal_hold(&env, &hash);
+        Self::open_deletion_request(&env, &hash);
+        Self::close_deletion_request(&env, &hash);
+        if Self::status_of(&env, &hash) == DocumentStatus::Revoked {
+            Self::bump_counter(&env, &DataKey::RevokedCount, -1);
+        }
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Status(hash.clone()), &DocumentStatus::Erased);
+        let ext = DocumentExt {
+            name: String::from_str(&env, ""),
+        };
+        storage.set(&DataKey::DocumentExt(hash.clone()), &ext);
+        Self::remove_versions(&env, &hash);
+        Self::touch_document(&env, &hash, &owner);
+        events::deletion_approved(&env, &hash);
+    }
+
+    /// Owner-only: refuse an open request, giving a reason
+    pub fn deny_deletion(env: Env, hash: String, reason: String) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        if reason.len() > MAX_REASON_LEN {
+            panic!("reason too long");
+        }
+        Self::open_deletion_request(&env, &hash);
+        Self::close_deletion_request(&env, &hash);
+        events::deletion_denied(&env, &hash, &reason);
+    }
+
+    // ---------- RETENTION ----------
+    //
+    // A document becomes purgeable once its type's retention period has passed
+    // since `timestamp`. Types without a period are kept forever, and pinned or
+    // held documents are never purged.
+
+    /// Owner-only: set how long documents of `doc_type` are kept (0 => forever)
+    pub fn set_retention_period(env: Env, doc_type: DocType, seconds: u64) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        let key = DataKey::Retention(doc_type);
+        if seconds == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &seconds);
+        }
+    }
+
+    /// Read-only: retention period of `doc_type` in seconds, if one is set
+    pub fn get_retention_period(env: Env, doc_type: DocType) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::Retention(doc_type))
+    }
+
+    /// Read-only: whether `purge_eligible` would delete `hash` right now
+    pub fn is_purge_eligible(env: Env, hash: String) -> bool {
+        let Some(doc) = Self::load_core(&env, &hash) else {
+            return false;
+        };
+        let doc_type = Self::doc_type_of(&env, &hash);
+        let Some(period) = Self::get_retention_period(env.clone(), doc_type) else {
+            return false;
+        };
+        env.ledger().timestamp() >= doc.timestamp.saturating_add(period)
+            && !Self::is_pinned(env.clone(), hash.clone())
+            && !Self::is_on_legal_hold(env, hash)
+    }
+
+    /// Owner-only: delete every listed document whose retention has run out.
+    /// Ineligible or missing hashes are skipped. Returns how many were purged.
+    pub fn purge_eligible(env: Env, hashes: Vec<String>) -> u32 {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        Self::assert_batch_size(&hashes, Self::get_limits(env.clone()).max_batch_bump);
+        let mut purged = 0;
+        for hash in hashes.iter() {
+            if !Self::is_purge_eligible(env.clone(), hash.clone()) {
+                continue;
+            }
+            let doc = Self::load_document(&env, &hash);
+            Self::remove_document(&env, &hash, &doc.added_by);
+            purged += 1;
+        }
+        purged
+    }
+
+    // ---------- LEGAL HOLD ----------
+    //
+    // A held document cannot be destroyed by any path: self-delete, approved
+    // erasure or purge. Revocation and other status changes stay allowed.
+
+    /// Owner-only: place or release a legal hold on a document
+    pub fn set_legal_hold(env: Env, hash: String, held: bool) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        Self::load_document(&env, &hash);
+        let key = DataKey::LegalHold(hash.clone());
+        if held {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Self::touch_document(&env, &hash, &owner);
+        events::legal_hold_set(&env, &hash, held);
+    }
+
+    /// Read-only: whether a document is under legal hold
+    pub fn is_on_legal_hold(env: Env, hash: String) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LegalHold(hash))
+            .unwrap_or(false)
+    }
+
+    /// Internal: block destructive operations on held documents
+    fn assert_not_on_legal_hold(env: &Env, hash: &String) {
+        if Self::is_on_legal_hold(env.clone(), hash.clone()) {
+            panic!("document on legal hold");
+        }
+    }
+
+    // ---------- REDACTION ----------
+
+    /// Owner-only: irreversibly replace the name with `REDACTION_MARKER` and
+    /// clear the subject, references and owner note. Hash, timestamp and
+    /// submitter are kept, so the document still verifies.
+    pub fn redact_document(env: Env, hash: String) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        Self::load_document(&env, &hash);
+        Self::assert_not_on_legal_hold(&env, &hash);
+        let storage = env.storage().persistent();
+        if storage.has(&DataKey::Redacted(hash.clone())) {
+            panic!("document already redacted");
+        }
+        let ext = DocumentExt {
+            name: String::from_str(&env, REDACTION_MARKER),
+        };
+        storage.set(&DataKey::DocumentExt(hash.clone()), &ext);
+        storage.remove(&DataKey::Subject(hash.clone()));
+        storage.remove(&DataKey::DocRefs(hash.clone()));
+        storage.remove(&DataKey::OwnerNote(hash.clone()));
+        let versions: u32 = storage
+            .get(&DataKey::DocVersionCount(hash.clone()))
+            .unwrap_or(0);
+        for version in 1..=versions {
+            let key = DataKey::DocVersion(hash.clone(), version);
+            if let Some(mut entry) = storage.get::<_, DocumentVersion>(&key) {
+                entry.name = ext.name.clone();
+                storage.set(&key, &entry);
+            }
+        }
+        storage.set(&DataKey::Redacted(hash.clone()), &env.ledger().timestamp());
+        Self::touch_document(&env, &hash, &owner);
+        Self::extend_document_ttl(&env, &hash, DOC_BUMP_AMOUNT);
+        events::document_redacted(&env, &hash);
+    }
+
+    /// Read-only: when a document was redacted, if it was
+    pub fn redacted_at(env: Env, hash: String) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::Redacted(hash))
+    }
+
+    // ---------- STORE HOOK ----------
+    //
+    // The host rejects contract re-entry, so a hook calling back into this
+    // registry fails like any other hook error (see `HookMode`).
+
+    /// Owner-only: notify `contract` after every store, handling its failures
+    /// according to `mode`
+    pub fn set_store_hook(env: Env, contract: Address, mode: HookMode) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        let hook = StoreHook { contract, mode };
+        env.storage().instance().set(&DataKey::StoreHook, &hook);
+        events::store_hook_set(&env, &hook.contract, mode);
+    }
+
+    /// Owner-only: stop notifying the store hook
+    pub fn clear_store_hook(env: Env) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        let hook = Self::get_store_hook(env.clone()).expect("no store hook");
+        env.storage().instance().remove(&DataKey::StoreHook);
+        events::store_hook_cleared(&env, &hook.contract);
+    }
+
+    /// Read-only: the registered store hook, if any
+    pub fn get_store_hook(env: Env) -> Option<StoreHook> {
+        env.storage().instance().get(&DataKey::StoreHook)
+    }
+
+    /// Internal: call the hook for a freshly written document
+    fn notify_store_hook(env: &Env, doc: &Document) {
+        let Some(hook) = Self::get_store_hook(env.clone()) else {
+            return;
+        };
+        let func = Symbol::new(env, "on_document_stored");
+        let args: Vec<Val> = (doc.hash.clone(), doc.added_by.clone(), doc.timestamp).into_val(env);
+        match hook.mode {
+            HookMode::Strict => env.invoke_contract::<()>(&hook.contract, &func, args),
+            HookMode::BestEffort => {
+                let _ = env.try_invoke_contract::<(), InvokeError>(&hook.contract, &func, args);
+            }
+        }
+    }
+
+    // ---------- AUTH PROVIDER ----------
+    //
+    // A shared ACL contract exposing `is_authorized(actor, action) -> bool`.
+    // Actions are `store` (every store path) and `bundle` (`create_bundle`).
+    // A provider call that fails or returns anything but a bool counts as a
+    // denial.
+
+    /// Owner-only: consult `contract` when admitting non-owner actors
+    pub fn set_auth_provider(env: Env, contract: Address) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        env.storage().instance().set(&DataKey::AuthProvider, &contract);
+        events::auth_provider_set(&env, &contract);
+    }
+
+    /// Owner-only: go back to the local whitelist alone
+    pub fn clear_auth_provider(env: Env) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        let provider = Self::get_auth_provider(env.clone()).expect("no auth provider");
+        env.storage().instance().remove(&DataKey::AuthProvider);
+        events::auth_provider_cleared(&env, &provider);
+    }
+
+    /// Read-only: the configured auth provider, if any
+    pub fn get_auth_provider(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::AuthProvider)
+    }
+
+    /// Internal: ask `provider` whether `actor` may perform `action`
+    fn provider_allows(env: &Env, provider: &Address, actor: &Address, action: Symbol) -> bool {
+        let func = Symbol::new(env, "is_authorized");
+        let args: Vec<Val> = (actor.clone(), action).into_val(env);
+        matches!(
+            env.try_invoke_contract::<bool, InvokeError>(provider, &func, args),
+            Ok(Ok(true))
+        )
+    }
+
+    // ---------- EXPORT ----------
+    //
+    // Read-only chunks over the global and whitelist indexes for off-chain
+    // backups. Each call walks at most `MAX_PAGE_SIZE` slots; callers advance
+    // `start` by the limit they asked for, so deleted slots just shorten a chunk.
+
+    /// Read-only: documents in index slots `start..start + limit`, as
+    /// `read_document` returns them
+    pub fn export_documents(env: Env, start: u32, limit: u32) -> Vec<Document> {
+        let total: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::DocIndexCount)
+            .unwrap_or(0);
+        let (end, _) = Self::page_range(start, limit, total);
+        let mut out = Vec::new(&env);
+        for i in start..end {
+            let slot: Option<String> = env.storage().persistent().get(&DataKey::DocIndex(i));
+            if let Some(doc) = slot.and_then(|h| Self::read_document(env.clone(), h)) {
+                out.push_back(doc);
+            }
+        }
+        out
+    }
+
+    /// Read-only: whitelist entries in index slots `start..start + limit`
+    pub fn export_whitelist(env: Env, start: u32, limit: u32) -> Vec<(Address, WhitelistEntry)> {
+        let page = Self::list_whitelist_page(env.clone(), start, limit);
+        let mut out = Vec::new(&env);
+        for entry in page.items.iter() {
+            out.push_back((entry.address.clone(), entry));
+        }
+        out
+    }
+
+    // ---------- DOCUMENT DIGEST ----------
+    //
+    // Canonical encoding (version 1), concatenated without separators:
+    //   1. the ASCII tag `AITT-DOC-V1` (11 bytes, no length prefix)
+    //   2. hash, name: XDR `ScVal::String` (u32 type 14, u32 byte length,
+    //      UTF-8 bytes, zero-padded to a multiple of 4)
+    //   3. timestamp: XDR `ScVal::U64` (u32 type 5, u64), big-endian
+    //   4. added_by, then this contract: XDR `ScVal::Address` (u32 type 18,
+    //      then the `ScAddress` union: 0 + u32 key type 0 + 32-byte ed25519
+    //      key for accounts, 1 + 32-byte id for contracts)
+    // The digest is sha256 of that encoding. Only registration fields are
+    // covered: status changes (revoke, quarantine) keep the digest, anything
+    // that rewrites the name (redaction, erasure) changes it.
+
+    /// Internal: canonical encoding of a registration (see above)
+    fn canonical_encoding(
+        env: &Env,
+        hash: &String,
+        name: &String,
+        timestamp: u64,
+        added_by: &Address,
+        contract: &Address,
+    ) -> Bytes {
+        let mut buf = Bytes::from_slice(env, DIGEST_DOMAIN);
+        buf.append(&hash.clone().to_xdr(env));
+        buf.append(&name.clone().to_xdr(env));
+        buf.append(&timestamp.to_xdr(env));
+        buf.append(&added_by.clone().to_xdr(env));
+        buf.append(&contract.clone().to_xdr(env));
+        buf
+    }
+
+    /// Read-only: sha256 commitment to a registration (None if missing or
+    /// still embargoed)
+    pub fn get_document_digest(env: Env, hash: String) -> Option<BytesN<32>> {
+        let hash = Self::resolve_alias(&env, hash);
+        if Self::is_embargoed(&env, &hash) {
+            return None;
+        }
+        let doc = Self::load_full(&env, &hash)?;
+        Some(Self::document_digest(&env, &doc))
+    }
+
+    /// Internal: sha256 of `doc`'s canonical encoding under this contract
+    fn document_digest(env: &Env, doc: &Document) -> BytesN<32> {
+        let contract = env.current_contract_address();
+        let buf = Self::canonical_encoding(
+            env,
+            &doc.hash,
+            &doc.name,
+            doc.timestamp,
+            &doc.added_by,
+            &contract,
+        );
+        env.crypto().sha256(&buf).into()
+    }
+
+    // ---------- PREFLIGHT ----------
+    //
+    // Mirrors the checks of `store_document`, in the same order, without
+    // requiring the actor's signature or writing anything. Fees are not
+    // simulated: a store can still fail on the token transfer.
+
+    /// Read-only: whether `actor` storing `hash` would currently succeed
+    pub fn can_store(env: Env, actor: Address, hash: String) -> StoreCheck {
+        if env.storage().instance().has(&DataKey::Successor)
+            || env.storage().instance().has(&DataKey::Sealed)
+        {
+            return StoreCheck::Paused;
+        }
+        if Self::digest_taken(&env, &hash) {
+            return StoreCheck::Duplicate;
+        }
+        let Some(role) = Self::actor_role(&env, &actor, symbol_short!("store")) else {
+            return StoreCheck::NotAuthorized;
+        };
+        if Self::exceeds_daily_quota(&env, &actor, role, 1) {
+            return StoreCheck::QuotaExceeded;
+        }
+        if Self::hash_problem(&env, &hash).is_some() {
+            return StoreCheck::InvalidHash;
+        }
+        StoreCheck::Ok
+    }
+
+    // ---------- HASH CHAIN ----------
+    //
+    // Every registration extends a running head:
+    //   head' = sha256(head || get_document_digest(hash))
+    // starting from 32 zero bytes. The head a registration extended is kept
+    // per document, so any run of consecutive registrations can be rechecked.
+    // Redaction and erasure rewrite the name (and so the digest); segments
+    // spanning such documents stop verifying.
+
+    /// Internal: link `doc` into the chain
+    fn extend_chain(env: &Env, doc: &Document) {
+        let prev = Self::get_chain_head(env.clone());
+        let head = Self::chain_link(env, &prev, &Self::document_digest(env, doc));
+        env.storage()
+            .persistent()
+            .set(&DataKey::PrevHead(doc.hash.clone()), &prev);
+        env.storage().instance().set(&DataKey::ChainHead, &head);
+    }
+
+    /// Internal: sha256(prev || digest)
+    fn chain_link(env: &Env, prev: &BytesN<32>, digest: &BytesN<32>) -> BytesN<32> {
+        let mut buf = Bytes::from_array(env, &prev.to_array());
+        buf.append(&Bytes::from_array(env, &digest.to_array()));
+        env.crypto().sha256(&buf).into()
+    }
+
+    /// Read-only: current chain head (zeros before the first registration)
+    pub fn get_chain_head(env: Env) -> BytesN<32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ChainHead)
+            .unwrap_or(BytesN::from_array(&env, &[0; 32]))
+    }
+
+    /// Read-only: head a document's registration extended, if it was chained
+    pub fn get_prev_head(env: Env, hash: String) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&DataKey::PrevHead(hash))
+    }
+
+    /// Read-only: whether `hashes` (at most `MAX_PAGE_SIZE`) are consecutive
+    /// registrations, oldest first, whose recorded links all recompute
+    pub fn verify_chain_segment(env: Env, hashes: Vec<String>) -> bool {
+        Self::assert_batch_size(&hashes, MAX_PAGE_SIZE);
+        let mut head: Option<BytesN<32>> = None;
+        for hash in hashes.iter() {
+            let Some(prev) = Self::get_prev_head(env.clone(), hash.clone()) else {
+                return false;
+            };
+            let Some(doc) = Self::load_full(&env, &hash) else {
+                return false;
+            };
+            if head.as_ref().is_some_and(|h| h != &prev) {
+                return false;
+            }
+            head = Some(Self::chain_link(&env, &prev, &Self::document_digest(&env, &doc)));
+        }
+        true
+    }
+
+    // ---------- STATUS LISTINGS ----------
+    //
+    // No per-status index: `Pending` is derived from the veto window, so an
+    // index could never stay in step with the clock. Instead each call filters
+    // the global index slots `start..start + limit` (at most `MAX_PAGE_SIZE`)
+    // by current status; callers advance `start` by the limit they asked for.
+
+    /// Read-only: hashes in the scanned slots whose current status is `status`
+    pub fn list_by_status(env: Env, status: DocumentStatus, start: u32, limit: u32) -> Vec<String> {
+        let total: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::DocIndexCount)
+            .unwrap_or(0);
+        let (end, _) = Self::page_range(start, limit, total);
+        let mut out = Vec::new(&env);
+        for i in start..end {
+            let slot: Option<String> = env.storage().persistent().get(&DataKey::DocIndex(i));
+            let Some(hash) = slot else { continue };
+            if Self::document_status(env.clone(), hash.clone()) == Some(status) {
+                out.push_back(hash);
+            }
+        }
+        out
+    }
+
+    /// Read-only: documents with `from_ts <= timestamp < to_ts`, scanning at
+    /// most `limit` global index slots from `cursor`. Backdated imports sit
+    /// where they were registered, so the whole index is scanned; follow the
+    /// returned cursor until it is None.
+    pub fn list_documents_in_range(
+        env: Env,
+        from_ts: u64,
+        to_ts: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> (Vec<DocumentSummary>, Option<u32>) {
+        if from_ts > to_ts {
+            panic!("reversed range");
+        }
+        if from_ts == to_ts {
+            panic!("empty range");
+        }
+        let total: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::DocIndexCount)
+            .unwrap_or(0);
+        let (end, next_cursor) = Self::page_range(cursor, limit, total);
+        let mut out = Vec::new(&env);
+        for i in cursor..end {
+            let slot: Option<String> = env.storage().persistent().get(&DataKey::DocIndex(i));
+            let Some(summary) = slot.and_then(|h| Self::read_document_summary(env.clone(), h))
+            else {
+                continue;
+            };
+            if summary.timestamp >= from_ts && summary.timestamp < to_ts {
+                out.push_back(summary);
+            }
+        }
+        (out, next_cursor)
+    }
+
+    // ---------- CREATION TIME ----------
+    //
+    // `timestamp` is always ledger-derived. A submitter may additionally
+    // assert when the document itself was created; it is stored as given
+    // (not in the future) and never used for ordering or windows.
+
+    /// Store a document (ONLY OWNER and whitelist) recording `created_at`
+    /// alongside the ledger timestamp
+    pub fn store_document_with_created_at(
+        env: Env,
+        actor: Address,
+        name: String,
+        hash: String,
+        created_at: Option<u64>,
+    ) -> Receipt {
+        let now = env.ledger().timestamp();
+        if created_at.is_some_and(|t| t > now) {
+            panic!("created_at in the future");
+        }
+        let fee = Self::get_config(env.clone()).registration_fee.fee();
+        let role = Self::prepare_store(&env, &actor, &name, &hash, fee);
+        if let Some(created_at) = created_at {
+            env.storage()
+                .persistent()
+                .set(&DataKey::CreatedAt(hash.clone()), &created_at);
+        }
+        Self::write_document(&env, actor, role, name, hash, now)
+    }
+
+    /// Read-only: client-asserted creation time, if one was given
+    pub fn document_created_at(env: Env, hash: String) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::CreatedAt(hash))
+    }
+
+    // ---------- JURISDICTION & LANGUAGE ----------
+    //
+    // Jurisdictions must be on the owner-managed allowed set; languages are
+    // free-form. Untagged documents read back as `UNSPECIFIED_TAG`. Re-tagging
+    // frees the old index slot; listings still filter on the current tag so
+    // slots of deleted documents are skipped.
+
+    /// Owner-only: allow documents to be tagged with `jurisdiction`
+    pub fn add_allowed_jurisdiction(env: Env, jurisdiction: Symbol) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllowedJurisdiction(jurisdiction.clone()), &true);
+        events::jurisdiction_allowed(&env, &jurisdiction, true);
+    }
+
+    /// Owner-only: stop accepting `jurisdiction` for new tags (existing tags stay)
+    pub fn remove_allowed_jurisdiction(env: Env, jurisdiction: Symbol) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AllowedJurisdiction(jurisdiction.clone()));
+        events::jurisdiction_allowed(&env, &jurisdiction, false);
+    }
+
+    /// Read-only: whether `jurisdiction` is on the allowed set
+    pub fn is_jurisdiction_allowed(env: Env, jurisdiction: Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::AllowedJurisdiction(jurisdiction))
+    }
+
+    /// Internal: validate and write both tags (None clears one), indexing a
+    /// newly set jurisdiction
+    fn set_locale(
+        env: &Env,
+        hash: &String,
+        jurisdiction: Option<Symbol>,
+        language: Option<Symbol>,
+    ) {
+        let storage = env.storage().persistent();
+        let juris_key = DataKey::Jurisdiction(hash.clone());
+        let slot_key = DataKey::JurisdictionSlot(hash.clone());
+        if let Some(j) = &jurisdiction {
+            if !Self::is_jurisdiction_allowed(env.clone(), j.clone()) {
+                panic!("jurisdiction not allowed");
+            }
+        }
+        let current: Option<Symbol> = storage.get(&juris_key);
+        if current != jurisdiction {
+            // Free the old index slot so a re-tag never lists a document twice
+            if let (Some(old), Some(slot)) = (current, storage.get::<_, u32>(&slot_key)) {
+                storage.remove(&DataKey::JurisdictionDoc(old, slot));
+            }
+            match jurisdiction {
+                Some(j) => {
+                    let count_key = DataKey::JurisdictionDocCount(j.clone());
+                    let n: u32 = storage.get(&count_key).unwrap_or(0);
+                    storage.set(&DataKey::JurisdictionDoc(j.clone(), n), hash);
+                    storage.set(&count_key, &(n + 1));
+                    storage.set(&slot_key, &n);
+                    storage.set(&juris_key, &j);
+                }
+                None => {
+                    storage.remove(&slot_key);
+                    storage.remove(&juris_key);
+                }
+            }
+        }
+        let lang_key = DataKey::Language(hash.clone());
+        match language {
+            Some(l) => storage.set(&lang_key, &l),
+            None => storage.remove(&lang_key),
+        }
+    }
+
+    /// Store a document (ONLY OWNER and whitelist) tagged with a jurisdiction
+    /// and/or language
+    pub fn store_document_localized(
+        env: Env,
+        actor: Address,
+        name: String,
+        hash: String,
+        jurisdiction: Option<Symbol>,
+        language: Option<Symbol>,
+    ) {
+        let fee = Self::get_config(env.clone()).registration_fee.fee();
+        let role = Self::prepare_store(&env, &actor, &name, &hash, fee);
+        Self::set_locale(&env, &hash, jurisdiction, language);
+        let timestamp = env.ledger().timestamp();
+        Self::write_document(&env, actor, role, name, hash, timestamp);
+    }
+
+    /// Owner or controller: replace both tags of a document (None clears one)
+    pub fn set_document_locale(
+        env: Env,
+        actor: Address,
+        hash: String,
+        jurisdiction: Option<Symbol>,
+        language: Option<Symbol>,
+    ) {
+        Self::assert_not_sealed(&env);
+        Self::assert_owner_or_controller(&env, &actor, &hash);
+        Self::set_locale(&env, &hash, jurisdiction, language);
+        Self::touch_document(&env, &hash, &actor);
+    }
+
+    /// Read-only: jurisdiction tag (`UNSPECIFIED_TAG` when untagged)
+    pub fn document_jurisdiction(env: Env, hash: String) -> Symbol {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Jurisdiction(hash))
+            .unwrap_or(Symbol::new(&env, UNSPECIFIED_TAG))
+    }
+
+    /// Read-only: language tag (`UNSPECIFIED_TAG` when untagged)
+    pub fn document_language(env: Env, hash: String) -> Symbol {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Language(hash))
+            .unwrap_or(Symbol::new(&env, UNSPECIFIED_TAG))
+    }
+
+    /// Read-only: documents currently tagged `jurisdiction`, from that
+    /// jurisdiction's index slots `start..start + limit` (at most `MAX_PAGE_SIZE`)
+    pub fn list_by_jurisdiction(
+        env: Env,
+        jurisdiction: Symbol,
+        start: u32,
+        limit: u32,
+    ) -> Vec<DocumentSummary> {
+        let total: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::JurisdictionDocCount(jurisdiction.clone()))
+            .unwrap_or(0);
+        let (end, _) = Self::page_range(start, limit, total);
+        let mut out = Vec::new(&env);
+        for i in start..end {
+            let slot: Option<String> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::JurisdictionDoc(jurisdiction.clone(), i));
+            // Skip slots whose document was deleted or re-tagged since
+            let slot = slot.filter(|h| {
+                Self::document_jurisdiction(env.clone(), h.clone()) == jurisdiction
+            });
+            if let Some(summary) = slot.and_then(|h| Self::read_document_summary(env.clone(), h)) {
+                out.push_back(summary);
+            }
+        }
+        out
+    }
+
+    // ---------- GUARDIAN RECOVERY ----------
+    //
+    // A guardian proposes a new owner and others back it. Once `threshold`
+    // guardians support it and `recovery_delay_secs` has passed since
+    // initiation, anyone may finalize. The owner can veto until then;
+    // replacing the guardian set also drops an in-flight recovery.
+
+    /// Owner-only: replace the guardian set (at most `MAX_GUARDIANS`,
+    /// no repeats, `1 <= threshold <= len`; an empty list disables recovery)
+    pub fn set_guardians(env: Env, guardians: Vec<Address>, threshold: u32) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        Self::assert_batch_size(&guardians, MAX_GUARDIANS);
+        if guardians.is_empty() {
+            env.storage().instance().remove(&DataKey::Guardians);
+        } else {
+            if threshold == 0 || threshold > guardians.len() {
+                panic!("invalid threshold");
+            }
+            for (i, guardian) in guardians.iter().enumerate() {
+                if guardians.first_index_of(&guardian) != Some(i as u32) {
+                    panic!("duplicate guardian");
+                }
+            }
+            let set = GuardianSet { guardians: guardians.clone(), threshold };
+            env.storage().instance().set(&DataKey::Guardians, &set);
+        }
+        env.storage().instance().remove(&DataKey::Recovery);
+        events::guardians_set(&env, guardians.len(), threshold);
+    }
+
+    /// Read-only: current guardian set, if any
+    pub fn get_guardians(env: Env) -> Option<GuardianSet> {
+        env.storage().instance().get(&DataKey::Guardians)
+    }
+
+    /// Read-only: in-flight recovery, if any
+    pub fn get_recovery(env: Env) -> Option<Recovery> {
+        env.storage().instance().get(&DataKey::Recovery)
+    }
+
+    /// Internal: require `guardian`'s signature and membership
+    fn assert_guardian(env: &Env, guardian: &Address) {
+        guardian.require_auth();
+        let set = Self::get_guardians(env.clone()).expect("no guardians");
+        if !set.guardians.contains(guardian) {
+            panic!("not a guardian");
+        }
+    }
+
+    /// Guardian: start recovering ownership to `proposed_owner`
+    pub fn initiate_recovery(env: Env, guardian: Address, proposed_owner: Address) {
+        Self::assert_not_sealed(&env);
+        Self::assert_guardian(&env, &guardian);
+        if env.storage().instance().has(&DataKey::Recovery) {
+            panic!("recovery already pending");
+        }
+        if proposed_owner == Self::owner_address(env.clone()) {
+            panic!("new owner must be different");
+        }
+        let recovery = Recovery {
+            proposed_owner: proposed_owner.clone(),
+            initiated_at: env.ledger().timestamp(),
+            supporters: Vec::from_array(&env, [guardian.clone()]),
+        };
+        env.storage().instance().set(&DataKey::Recovery, &recovery);
+        events::recovery_initiated(&env, &proposed_owner, &guardian);
+    }
+
+    /// Guardian: back the in-flight recovery
+    pub fn support_recovery(env: Env, guardian: Address) {
+        Self::assert_not_sealed(&env);
+        Self::assert_guardian(&env, &guardian);
+        let mut recovery = Self::get_recovery(env.clone()).expect("no recovery pending");
+        if recovery.supporters.contains(&guardian) {
+            panic!("already supported");
+        }
+        recovery.supporters.push_back(guardian.clone());
+        env.storage().instance().set(&DataKey::Recovery, &recovery);
+        events::recovery_supported(&env, &recovery.proposed_owner, &guardian);
+    }
+
+    /// Anyone: hand ownership to the proposed owner once the threshold is met
+    /// and the delay has elapsed
+    pub fn finalize_recovery(env: Env) {
+        Self::assert_not_sealed(&env);
+        let recovery = Self::get_recovery(env.clone()).expect("no recovery pending");
+        let set = Self::get_guardians(env.clone()).expect("no guardians");
+        if recovery.supporters.len() < set.threshold {
+            panic!("recovery threshold not met");
+        }
+        let delay = Self::get_config(env.clone()).recovery_delay_secs;
+        if env.ledger().timestamp() < recovery.initiated_at.saturating_add(delay) {
+            panic!("recovery delay not elapsed");
+        }
+        let previous = Self::owner_address(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::Owner, &recovery.proposed_owner);
+        env.storage().instance().remove(&DataKey::PendingOwner);
+        env.storage().instance().remove(&DataKey::Recovery);
+        Self::record_activity(&env, Activity::OwnershipChange);
+        events::recovery_finalized(&env, &recovery.proposed_owner, &previous);
+    }
+
+    /// Owner-only: cancel the in-flight recovery
+    pub fn veto_recovery(env: Env) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        let recovery = Self::get_recovery(env.clone()).expect("no recovery pending");
+        env.storage().instance().remove(&DataKey::Recovery);
+        events::recovery_vetoed(&env, &recovery.proposed_owner, &owner);
+    }
+
+    // ---------- VALIDATION RULES ----------
+    //
+    // Checked by `write_document` (so by every store variant and import) and
+    // by namespaced stores. Rules only gate new registrations: restores of
+    // evicted documents and stored records are never re-validated.
+
+    /// Read-only: rules in force (defaults accept any hash up to `MAX_HASH_LEN`
+    /// and any name up to `MAX_NAME_LEN` bytes)
+    pub fn get_validation_rules(env: Env) -> ValidationRules {
+        env.storage()
+            .instance()
+            .get(&DataKey::ValidationRules)
+            .unwrap_or(ValidationRules {
+                min_name_len: 0,
+                max_name_len: MAX_NAME_LEN,
+                name_charset: NameCharset::Any,
+                require_extension: false,
+                hash_lengths: Vec::new(&env),
+                require_hex: false,
+            })
+    }
+
+    /// Owner-only: replace the validation rules
+    pub fn set_validation_rules(env: Env, rules: ValidationRules) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        let lengths_ok = rules.hash_lengths.len() <= MAX_HASH_LENGTHS
+            && rules.hash_lengths.iter().all(|n| n > 0 && n <= MAX_HASH_LEN);
+        let names_ok =
+            rules.min_name_len <= rules.max_name_len && rules.max_name_len <= MAX_NAME_LEN;
+        if !names_ok || !lengths_ok {
+            panic!("invalid validation rules");
+        }
+        env.storage().instance().set(&DataKey::ValidationRules, &rules);
+    }
+
+    /// Internal: panic unless `name` and `hash` pass the current rules
+    fn assert_valid_input(env: &Env, name: &String, hash: &String) {
+        Self::assert_valid_hash(env, hash);
+        Self::assert_valid_name(env, name);
+    }
+
+    /// Internal: panic unless `hash` passes the current rules
+    fn assert_valid_hash(env: &Env, hash: &String) {
+        if let Some(problem) = Self::hash_problem(env, hash) {
+            panic!("{}", problem);
+        }
+    }
+
+    /// Internal: why `hash` fails the current rules, if it does
+    fn hash_problem(env: &Env, hash: &String) -> Option<&'static str> {
+        let rules = Self::get_validation_rules(env.clone());
+        let len = hash.len();
+        let allowed = if rules.hash_lengths.is_empty() {
+            len > 0 && len <= MAX_HASH_LEN
+        } else {
+            rules.hash_lengths.contains(len)
+        };
+        if !allowed {
+            return Some("hash length not allowed");
+        }
+        if rules.require_hex {
+            let mut buf = [0u8; MAX_HASH_LEN as usize];
+            hash.copy_into_slice(&mut buf[..len as usize]);
+            if !buf[..len as usize].iter().all(u8::is_ascii_hexdigit) {
+                return Some("hash not hex");
+            }
+        }
+        None
+    }
+
+    /// Internal: panic unless `name` passes the current rules
+    fn assert_valid_name(env: &Env, name: &String) {
+        let rules = Self::get_validation_rules(env.clone());
+        let len = name.len();
+        if len < rules.min_name_len || len > rules.max_name_len {
+            panic!("name length out of range");
+        }
+        let mut buf = [0u8; MAX_NAME_LEN as usize];
+        let bytes = &mut buf[..len as usize];
+        name.copy_into_slice(bytes);
+        let charset_ok = match rules.name_charset {
+            NameCharset::Any => true,
+            NameCharset::Printable => bytes.iter().all(|b| (0x20..=0x7e).contains(b)),
+            NameCharset::Filename => bytes
+                .iter()
+                .all(|b| b.is_ascii_alphanumeric() || b" ._-".contains(b)),
+        };
+        if !charset_ok {
+            panic!("name has disallowed characters");
+        }
+        if rules.require_extension {
+            let dot = bytes.iter().rposition(|b| *b == b'.');
+            if !matches!(dot, Some(i) if i > 0 && i + 1 < bytes.len()) {
+                panic!("name missing extension");
+            }
+        }
+    }
+
+    // ---------- UNIQUE NAMES ----------
+    //
+    // Every registration and rename records its name in `NameIndex` unless a
+    // live document already holds it, whether or not `unique_names` is on, so
+    // turning the flag on later covers names stored before. An entry is stale
+    // once its document is gone or renamed (redaction and erasure included);
+    // stale entries are simply taken over. Enabling the flag never touches
+    // existing duplicates. Names registered before the index existed are not
+    // tracked.
+
+    /// Internal: whether a live document other than `hash` holds `name`
+    fn name_taken(env: &Env, name: &String, hash: &String) -> bool {
+        let holder: Option<String> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NameIndex(name.clone()));
+        match holder {
+            Some(h) if &h != hash => {
+                Self::load_full(env, &h).is_some_and(|doc| &doc.name == name)
+            }
+            _ => false,
+        }
+    }
+
+    /// Internal: record `hash` as the holder of `name`, panicking when names
+    /// must be unique and a live document already holds it
+    fn claim_name(env: &Env, name: &String, hash: &String) {
+        if Self::name_taken(env, name, hash) {
+            if Self::get_config(env.clone()).unique_names {
+                panic!("name taken");
+            }
+            return;
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::NameIndex(name.clone()), hash);
+    }
+
+    /// Read-only: hash of the live document indexed under `name`, if any
+    pub fn document_by_name(env: Env, name: String) -> Option<String> {
+        let hash: String = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NameIndex(name.clone()))?;
+        let doc = Self::load_full(&env, &hash)?;
+        (doc.name == name).then_some(hash)
+    }
+
+    /// Owner or controller: replace a document's name. The new name must pass
+    /// the validation rules and, with `unique_names`, be free. Each document
+    /// may be renamed at most `MAX_RENAMES` times; keeping the name is a no-op.
+    pub fn update_document_name(env: Env, actor: Address, hash: String, name: String) {
+        Self::assert_not_sealed(&env);
+        Self::assert_owner_or_controller(&env, &actor, &hash);
+        if Self::status_of(&env, &hash) == DocumentStatus::Erased {
+            panic!("document erased");
+        }
+        if env.storage().persistent().has(&DataKey::Redacted(hash.clone())) {
+            panic!("document redacted");
+        }
+        Self::assert_valid_name(&env, &name);
+        if actor != Self::owner_address(env.clone()) {
+            Self::assert_unreserved_name(&env, &name);
+        }
+        let mut doc = Self::load_full(&env, &hash).expect("document not found");
+        // Keeping the current name is always allowed, even if it's a duplicate
+        if doc.name == name {
+            return;
+        }
+        if doc.rename_count >= MAX_RENAMES {
+            panic!("rename limit reached");
+        }
+        Self::claim_name(&env, &name, &hash);
+        let old_name = core::mem::replace(&mut doc.name, name);
+        Self::put_document(&env, &doc);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RenameCount(hash.clone()), &(doc.rename_count + 1));
+        Self::touch_document(&env, &hash, &actor);
+        Self::extend_document_ttl(&env, &hash, DOC_BUMP_AMOUNT);
+        events::document_renamed(&env, &hash, &old_name, &doc.name);
+    }
+
+    // ---------- DOCUMENT IDS ----------
+    //
+    //   doc_id = sha256(contract || hash)
+    // where `contract` is the XDR `ScVal::Address` of this contract (40 bytes:
+    // u32 type 18, u32 1, 32-byte contract id) and `hash` the raw UTF-8 bytes
+    // of the hash string, without length prefix. IDs are only indexed for
+    // documents stored since they were introduced.
+
+    /// Internal: `doc_id` of `hash` under `contract` (see above)
+    fn derive_document_id(env: &Env, contract: &Address, hash: &String) -> BytesN<32> {
+        let mut buf = contract.clone().to_xdr(env);
+        // Strip the 8-byte ScVal::String header and trailing padding
+        let encoded = hash.clone().to_xdr(env);
+        buf.append(&encoded.slice(8..8 + hash.len()));
+        env.crypto().sha256(&buf).into()
+    }
+
+    /// Read-only: the ID `hash` has (or would have) in this registry
+    pub fn document_id(env: Env, hash: String) -> BytesN<32> {
+        Self::derive_document_id(&env, &env.current_contract_address(), &hash)
+    }
+
+    /// Internal: hash registered under `doc_id`
+    fn hash_by_id(env: &Env, doc_id: BytesN<32>) -> Option<String> {
+        env.storage().persistent().get(&DataKey::ById(doc_id))
+    }
+
+    /// Read-only: `read_document` by document ID
+    pub fn read_document_by_id(env: Env, doc_id: BytesN<32>) -> Option<Document> {
+        let hash = Self::hash_by_id(&env, doc_id)?;
+        Self::read_document(env, hash)
+    }
+
+    /// Read-only: `verify_document` by document ID
+    pub fn verify_document_by_id(env: Env, doc_id: BytesN<32>) -> Option<VerifiedDocument> {
+        let hash = Self::hash_by_id(&env, doc_id)?;
+        Self::verify_document(env, hash)
+    }
+
+    // ---------- RESERVED PREFIXES ----------
+    //
+    // Names starting with a reserved prefix (compared byte for byte) can only
+    // be registered or given through a rename by the owner.
+
+    /// Read-only: reserved name prefixes
+    pub fn reserved_name_prefixes(env: Env) -> Vec<String> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReservedPrefixes)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Owner-only: reserve `prefix` (1..=`MAX_PREFIX_LEN` bytes, at most
+    /// `MAX_RESERVED_PREFIXES` in total)
+    pub fn reserve_name_prefix(env: Env, prefix: String) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        if prefix.is_empty() || prefix.len() > MAX_PREFIX_LEN {
+            panic!("invalid prefix");
+        }
+        let mut prefixes = Self::reserved_name_prefixes(env.clone());
+        if prefixes.contains(&prefix) {
+            return;
+        }
+        if prefixes.len() >= MAX_RESERVED_PREFIXES {
+            panic!("too many reserved prefixes");
+        }
+        prefixes.push_back(prefix);
+        env.storage()
+            .instance()
+            .set(&DataKey::ReservedPrefixes, &prefixes);
+    }
+
+    /// Owner-only: release a reserved prefix
+    pub fn release_name_prefix(env: Env, prefix: String) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        let mut prefixes = Self::reserved_name_prefixes(env.clone());
+        let Some(i) = prefixes.first_index_of(&prefix) else {
+            panic!("prefix not reserved");
+        };
+        prefixes.remove(i);
+        env.storage()
+            .instance()
+            .set(&DataKey::ReservedPrefixes, &prefixes);
+    }
+
+    /// Internal: panic if `name` starts with a reserved prefix. Runs after
+    /// `assert_valid_input`, so the name fits `MAX_NAME_LEN`.
+    fn assert_unreserved_name(env: &Env, name: &String) {
+        let prefixes = Self::reserved_name_prefixes(env.clone());
+        if prefixes.is_empty() {
+            return;
+        }
+        let mut buf = [0u8; MAX_NAME_LEN as usize];
+        let name_bytes = &mut buf[..name.len() as usize];
+        name.copy_into_slice(name_bytes);
+        for prefix in prefixes.iter() {
+            let len = prefix.len() as usize;
+            if len > name_bytes.len() {
+                continue;
+            }
+            let mut raw = [0u8; MAX_PREFIX_LEN as usize];
+            prefix.copy_into_slice(&mut raw[..len]);
+            if name_bytes[..len] == raw[..len] {
+                panic!("reserved prefix");
+            }
+        }
+    }
+
+    // ---------- CATEGORY COUNTS ----------
+    //
+    // Counted on categorized stores, deletions and `recategorize_document`.
+    // Categorized documents stored before the counters existed aren't counted.
+
+    /// Internal: add `delta` to a category's counter, registering new categories
+    fn bump_category_count(env: &Env, category: &Symbol, delta: i64) {
+        let mut known = Self::known_categories(env);
+        if !known.contains(category) {
+            if known.len() >= MAX_CATEGORIES {
+                panic!("too many categories");
+            }
+            known.push_back(category.clone());
+            env.storage().instance().set(&DataKey::Categories, &known);
+        }
+        let key = DataKey::CategoryCount(category.clone());
+        let count = Self::count_by_category(env.clone(), category.clone());
+        let count = count.saturating_add_signed(delta);
+        env.storage().persistent().set(&key, &count);
+    }
+
+    /// Internal: categories in first-use order
+    fn known_categories(env: &Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Categories)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Read-only: stored documents currently filed under `category`
+    pub fn count_by_category(env: Env, category: Symbol) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CategoryCount(category))
+            .unwrap_or(0)
+    }
+
+    /// Read-only: count for every category ever used (at most `MAX_CATEGORIES`)
+    pub fn category_breakdown(env: Env) -> Vec<(Symbol, u64)> {
+        let mut out = Vec::new(&env);
+        for category in Self::known_categories(&env).iter() {
+            let count = Self::count_by_category(env.clone(), category.clone());
+            out.push_back((category, count));
+        }
+        out
+    }
+
+    /// Owner-only: file a document under `new_category`, moving its count
+    pub fn recategorize_document(env: Env, hash: String, new_category: Symbol) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        Self::load_document(&env, &hash);
+        let key = DataKey::Category(hash.clone());
+        let old: Option<Symbol> = env.storage().persistent().get(&key);
+        if old.as_ref() == Some(&new_category) {
+            return;
+        }
+        if let Some(old) = old {
+            Self::bump_category_count(&env, &old, -1);
+        }
+        Self::bump_category_count(&env, &new_category, 1);
+        env.storage().persistent().set(&key, &new_category);
+        Self::touch_document(&env, &hash, &owner);
+    }
+
+    // ---------- TSA PROOFS ----------
+    //
+    // Clients that also hold an RFC 3161 token from a traditional TSA can anchor
+    // its digest here. The token itself stays off-chain; a proof is set once.
+
+    /// Internal: record a TSA proof, refusing to overwrite one
+    fn set_tsa_proof(env: &Env, hash: &String, token_hash: BytesN<32>, tsa_time: u64) {
+        if tsa_time > env.ledger().timestamp() {
+            panic!("tsa_time in the future");
+        }
+        let key = DataKey::TsaProof(hash.clone());
+        if env.storage().persistent().has(&key) {
+            panic!("tsa proof already attached");
+        }
+        let proof = TsaProof { token_hash, tsa_time };
+        env.storage().persistent().set(&key, &proof);
+    }
+
+    /// Store a document (ONLY OWNER and whitelist) together with a TSA proof
+    pub fn store_document_with_tsa_proof(
+        env: Env,
+        actor: Address,
+        name: String,
+        hash: String,
+        tsa_token_hash: BytesN<32>,
+        tsa_time: u64,
+    ) -> Receipt {
+        let fee = Self::get_config(env.clone()).registration_fee.fee();
+        let role = Self::prepare_store(&env, &actor, &name, &hash, fee);
+        Self::set_tsa_proof(&env, &hash, tsa_token_hash, tsa_time);
+        let now = env.ledger().timestamp();
+        Self::write_document(&env, actor, role, name, hash, now)
+    }
+
+    /// Owner or controller: anchor a TSA proof on an existing document (once)
+    pub fn attach_tsa_proof(
+        env: Env,
+        actor: Address,
+        hash: String,
+        tsa_token_hash: BytesN<32>,
+        tsa_time: u64,
+    ) {
+        Self::assert_not_sealed(&env);
+        Self::assert_owner_or_controller(&env, &actor, &hash);
+        Self::set_tsa_proof(&env, &hash, tsa_token_hash, tsa_time);
+        Self::touch_document(&env, &hash, &actor);
+    }
+
+    /// Read-only: the anchored TSA proof, if any
+    pub fn document_tsa_proof(env: Env, hash: String) -> Option<TsaProof> {
+        env.storage().persistent().get(&DataKey::TsaProof(hash))
+    }
+
+    // ---------- VIEW COUNTS ----------
+    //
+    // Rough usage data from explicit, unauthenticated pings. Reads never count.
+    // At most one view per document per ledger is counted, which blunts spam
+    // without tracking callers.
+
+    /// Anyone: count a view of `hash`; false if one was already counted this ledger
+    pub fn record_view(env: Env, hash: String) -> bool {
+        Self::assert_not_sealed(&env);
+        Self::load_document(&env, &hash);
+        let sequence = env.ledger().sequence();
+        let storage = env.storage().persistent();
+        let last_key = DataKey::LastView(hash.clone());
+        if storage.get::<_, u32>(&last_key) == Some(sequence) {
+            return false;
+        }
+        let views = Self::get_view_count(env.clone(), hash.clone());
+        storage.set(&DataKey::Views(hash.clone()), &views.saturating_add(1));
+        storage.set(&last_key, &sequence);
+        true
+    }
+
+    /// Read-only: views counted for `hash`
+    pub fn get_view_count(env: Env, hash: String) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Views(hash))
+            .unwrap_or(0)
+    }
+
+    // ---------- WHITELIST REQUESTS ----------
+    //
+    // Partners apply themselves; the owner approves (whitelisting them) or
+    // denies. Requests lapse after `Config::whitelist_request_ttl_secs`.
+
+    /// Applicant: ask to be whitelisted. Replaces an expired request; an open
+    /// one must be answered first.
+    pub fn request_whitelist(env: Env, applicant: Address, note: String) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        applicant.require_auth();
+        if Self::is_whitelisted(env.clone(), applicant.clone()) {
+            panic!("already whitelisted");
+        }
+        if note.len() > MAX_NOTE_LEN {
+            panic!("note too long");
+        }
+        let now = env.ledger().timestamp();
+        if let Some(open) = Self::stored_whitelist_request(&env, &applicant) {
+            if now <= open.expires_at {
+                panic!("whitelist already requested");
+            }
+            Self::close_whitelist_request(&env, &applicant);
+        }
+        let ttl = Self::get_config(env.clone()).whitelist_request_ttl_secs;
+        let request = WhitelistRequest {
+            applicant: applicant.clone(),
+            note,
+            requested_at: now,
+            expires_at: now.saturating_add(ttl),
+        };
+        let slot = Self::next_slot(&env, &DataKey::WhitelistRequestQueueCount);
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::WhitelistRequest(applicant.clone()), &request);
+        storage.set(&DataKey::WhitelistRequestQueue(slot), &applicant);
+        storage.set(&DataKey::WhitelistRequestSlot(applicant.clone()), &slot);
+        events::whitelist_requested(&env, &applicant, &request.note);
+    }
+
+    /// Internal: stored request of `applicant`, expired or not
+    fn stored_whitelist_request(env: &Env, applicant: &Address) -> Option<WhitelistRequest> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::WhitelistRequest(applicant.clone()))
+    }
+
+    /// Internal: drop a request and its queue slot
+    fn close_whitelist_request(env: &Env, applicant: &Address) {
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::WhitelistRequest(applicant.clone()));
+        let slot_key = DataKey::WhitelistRequestSlot(applicant.clone());
+        if let Some(slot) = storage.get::<_, u32>(&slot_key) {
+            storage.remove(&DataKey::WhitelistRequestQueue(slot));
+            storage.remove(&slot_key);
+        }
+    }
+
+    /// Internal: the open request of `applicant`, or panic if none or expired
+    fn open_whitelist_request(env: &Env, applicant: &Address) -> WhitelistRequest {
+        let request =
+            Self::stored_whitelist_request(env, applicant).expect("no whitelist request");
+        if env.ledger().timestamp() > request.expires_at {
+            panic!("whitelist request expired");
+        }
+        request
+    }
+
+    /// Read-only: open (unexpired) whitelist request of an address
+    pub fn get_whitelist_request(env: Env, applicant: Address) -> Option<WhitelistRequest> {
+        Self::stored_whitelist_request(&env, &applicant)
+            .filter(|request| env.ledger().timestamp() <= request.expires_at)
+    }
+
+    /// Read-only: page of open whitelist requests, oldest first
+    pub fn list_whitelist_requests(env: Env, cursor: u32, limit: u32) -> WhitelistRequestPage {
+        let total: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::WhitelistRequestQueueCount)
+            .unwrap_or(0);
+        let (end, next_cursor) = Self::page_range(cursor, limit, total);
+        let mut items = Vec::new(&env);
+        for i in cursor..end {
+            let slot: Option<Address> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::WhitelistRequestQueue(i));
+            if let Some(request) = slot.and_then(|a| Self::get_whitelist_request(env.clone(), a)) {
+                items.push_back(request);
+            }
+        }
+        WhitelistRequestPage { items, next_cursor }
+    }
+
+    /// Owner-only: grant an open request, whitelisting the applicant
+    pub fn approve_whitelist_request(env: Env, applicant: Address) {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        Self::open_whitelist_request(&env, &applicant);
+        Self::close_whitelist_request(&env, &applicant);
+        Self::add_to_whitelist(&env, applicant);
+    }
+
+    /// Owner-only: refuse an open request, giving a reason
+    pub fn deny_whitelist_request(env: Env, applicant: Address, reason: String) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        if reason.len() > MAX_REASON_LEN {
+            panic!("reason too long");
+        }
+        Self::open_whitelist_request(&env, &applicant);
+        Self::close_whitelist_request(&env, &applicant);
+        events::whitelist_request_denied(&env, &applicant, &reason);
+    }
+
+    // ---------- ACCESS STAKES ----------
+    //
+    // With `Config::access_stake` set, whitelisted actors must keep at least
+    // that deposit escrowed in the contract. Stakes come back only once the
+    // actor is off the whitelist and `stake_cooldown_secs` have passed; the
+    // owner can slash them in the meantime. Slashed tokens go to the owner,
+    // like registration fees.
+
+    /// Internal: whether `actor` meets the configured stake (true when none is)
+    fn has_access_stake(env: &Env, actor: &Address) -> bool {
+        let required = match Self::get_config(env.clone()).access_stake.fee() {
+            Some(required) if required.amount > 0 => required,
+            _ => return true,
+        };
+        Self::get_stake(env.clone(), actor.clone())
+            .is_some_and(|stake| stake.token == required.token && stake.amount >= required.amount)
+    }
+
+    /// Actor: escrow `amount` of `token`, adding to any existing stake
+    pub fn stake_for_access(env: Env, actor: Address, token: Address, amount: i128) {
+        Self::assert_not_sealed(&env);
+        actor.require_auth();
+        if amount <= 0 {
+            panic!("invalid amount");
+        }
+        let mut stake = match Self::get_stake(env.clone(), actor.clone()) {
+            Some(existing) if existing.token != token => panic!("stake token mismatch"),
+            Some(existing) => existing,
+            None => Stake { token: token.clone(), amount: 0, staked_at: 0 },
+        };
+        token::Client::new(&env, &token).transfer(&actor, &env.current_contract_address(), &amount);
+        stake.amount += amount;
+        stake.staked_at = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .set(&DataKey::Stake(actor.clone()), &stake);
+        events::staked(&env, &actor, &stake);
+    }
+
+    /// Actor: withdraw the whole stake once off the whitelist and past the
+    /// cooldown (counted from the later of removal and the last deposit)
+    pub fn unstake(env: Env, actor: Address) -> i128 {
+        actor.require_auth();
+        let stake = Self::get_stake(env.clone(), actor.clone()).expect("no stake");
+        if Self::is_whitelisted(env.clone(), actor.clone()) {
+            panic!("still whitelisted");
+        }
+        let removed_at = Self::get_last_removal(env.clone(), actor.clone())
+            .map_or(0, |record| record.removed_at);
+        let cooldown = Self::get_config(env.clone()).stake_cooldown_secs;
+        let since = removed_at.max(stake.staked_at);
+        if env.ledger().timestamp() < since.saturating_add(cooldown) {
+            panic!("stake cooldown not elapsed");
+        }
+        env.storage().persistent().remove(&DataKey::Stake(actor.clone()));
+        token::Client::new(&env, &stake.token).transfer(
+            &env.current_contract_address(),
+            &actor,
+            &stake.amount,
+        );
+        events::unstaked(&env, &actor, stake.amount);
+        stake.amount
+    }
+
+    /// Owner-only: confiscate part of an actor's stake
+    pub fn slash(env: Env, actor: Address, amount: i128, reason: String) {
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        if reason.len() > MAX_REASON_LEN {
+            panic!("reason too long");
+        }
+        let mut stake = Self::get_stake(env.clone(), actor.clone()).expect("no stake");
+        if amount <= 0 || amount > stake.amount {
+            panic!("invalid amount");
+        }
+        stake.amount -= amount;
+        let key = DataKey::Stake(actor.clone());
+        if stake.amount == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &stake);
+        }
+        token::Client::new(&env, &stake.token).transfer(
+            &env.current_contract_address(),
+            &owner,
+            &amount,
+        );
+        events::slashed(&env, &actor, amount, &reason);
+    }
+
+    /// Read-only: an actor's current stake, if any
+    pub fn get_stake(env: Env, actor: Address) -> Option<Stake> {
+        env.storage().persistent().get(&DataKey::Stake(actor))
+    }
+
+    // ---------- FOOTPRINT ----------
+    //
+    // Approximate bytes a registration writes to its `Document` and
+    // `DocumentExt` entries: XDR strings are length-prefixed and padded to 4
+    // bytes, and the hash appears in both keys as well as the core.
+
+    /// Internal: XDR size of a string of `len` bytes
+    fn xdr_string_size(len: u32) -> u32 {
+        4 + len.div_ceil(4) * 4
+    }
+
+    /// Internal: estimated footprint of a document entry
+    fn entry_size(name: &String, hash: &String) -> u32 {
+        ENTRY_OVERHEAD_BYTES
+            + Self::xdr_string_size(name.len())
+            + 3 * Self::xdr_string_size(hash.len())
+    }
+
+    /// Read-only: estimated bytes `store_document` would write for this input.
+    /// Panics like `store_document` when the input fails validation.
+    pub fn estimate_entry_size(env: Env, name: String, hash: String) -> u32 {
+        Self::assert_valid_input(&env, &name, &hash);
+        Self::entry_size(&name, &hash)
+    }
+
+    // ---------- IDEMPOTENT STORES ----------
+    //
+    // A retried submission carrying the same key returns the document the key
+    // first registered, whatever hash it now carries. Keys are scoped to the
+    // actor and kept in temporary storage for `IDEM_TTL_LEDGERS`.
+
+    /// Store a document (ONLY OWNER and whitelist) unless `idempotency_key`
+    /// already registered one for `actor`, in which case that one is returned
+    pub fn store_document_idem(
+        env: Env,
+        actor: Address,
+        name: String,
+        hash: String,
+        idempotency_key: Option<BytesN<32>>,
+    ) -> Document {
+        let key = match idempotency_key {
+            Some(key) => DataKey::Idem(actor.clone(), key),
+            None => {
+                Self::store_document(env.clone(), actor, name, hash.clone());
+                return Self::read_document(env, hash).expect("document not found");
+            }
+        };
+        if let Some(original) = env.storage().temporary().get::<_, String>(&key) {
+            actor.require_auth();
+            return Self::read_document(env, original).expect("document not found");
+        }
+        Self::store_document(env.clone(), actor, name, hash.clone());
+        env.storage().temporary().set(&key, &hash);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, IDEM_TTL_LEDGERS, IDEM_TTL_LEDGERS);
+        Self::read_document(env, hash).expect("document not found")
+    }
+
+    // ---------- ACTOR STATISTICS ----------
+    //
+    // Registrations per actor in 30-day epochs of ledger time. Each epoch
+    // keeps one counter and one index slot per active actor, so its storage
+    // grows with distinct actors, not documents.
+
+    /// Internal: count one registration by `actor` in the current epoch
+    fn count_actor_store(env: &Env, actor: &Address) {
+        let epoch = Self::stats_epoch(env.clone());
+        let count = Self::actor_count_in_epoch(env.clone(), actor.clone(), epoch);
+        let storage = env.storage().persistent();
+        if count == 0 {
+            let slots_key = DataKey::EpochActorCount(epoch);
+            let slot: u32 = storage.get(&slots_key).unwrap_or(0);
+            storage.set(&DataKey::EpochActor(epoch, slot), actor);
+            storage.set(&slots_key, &(slot + 1));
+        }
+        storage.set(&DataKey::ActorEpochCount(actor.clone(), epoch), &(count + 1));
+    }
+
+    /// Read-only: the current statistics epoch
+    pub fn stats_epoch(env: Env) -> u64 {
+        env.ledger().timestamp() / STATS_EPOCH_SECS
+    }
+
+    /// Read-only: documents `address` registered during statistics `epoch`
+    pub fn actor_count_in_epoch(env: Env, address: Address, epoch: u64) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ActorEpochCount(address, epoch))
+            .unwrap_or(0)
+    }
+
+    /// Read-only: per-actor counts for `epoch`, in order of first activity
+    pub fn list_actor_counts(env: Env, epoch: u64, start: u32, limit: u32) -> Vec<(Address, u64)> {
+        let total: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EpochActorCount(epoch))
+            .unwrap_or(0);
+        let (end, _) = Self::page_range(start, limit, total);
+        let mut out = Vec::new(&env);
+        for i in start..end {
+            let actor: Address = env
+                .storage()
+                .persistent()
+                .get(&DataKey::EpochActor(epoch, i))
+                .expect("epoch index corrupt");
+            let count = Self::actor_count_in_epoch(env.clone(), actor.clone(), epoch);
+            out.push_back((actor, count));
+        }
+        out
+    }
+
+    // ---------- LEGACY HASHES ----------
+    //
+    // Migration path for historical MD5/SHA-1 digests. Only the owner can
+    // import them, only while `Config::legacy_hash_import` is on, and they
+    // skip the hash rules (not the name rules). Regular stores never accept
+    // them through this flag.
+
+    /// Owner-only: import a document under a legacy MD5 or SHA-1 hex digest
+    pub fn store_legacy_document(env: Env, name: String, hash: String, algo: HashAlgo) -> Receipt {
+        Self::assert_not_decommissioned(&env);
+        Self::assert_not_sealed(&env);
+        let owner = Self::assert_owner(&env);
+        if env.storage().instance().has(&DataKey::LegacyImportClosed) {
+            panic!("legacy import closed");
+        }
+        if !Self::get_config(env.clone()).legacy_hash_import {
+            panic!("legacy import disabled");
+        }
+        let expected = match algo {
+            HashAlgo::None => panic!("invalid algo"),
+            HashAlgo::Md5 => 32,
+            HashAlgo::Sha1 => 40,
+        };
+        let mut buf = [0u8; 40];
+        let len = hash.len();
+        if len != expected {
+            panic!("hash length not allowed");
+        }
+        hash.copy_into_slice(&mut buf[..len as usize]);
+        if !buf[..len as usize].iter().all(u8::is_ascii_hexdigit) {
+            panic!("hash not hex");
+        }
+        if Self::digest_taken(&env, &hash) {
+            panic!("Document already registered");
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::LegacyAlgo(hash.clone()), &algo);
+        let now = env.ledger().timestamp();
+        Self::write_document(&env, owner, ActorRole::Owner, name, hash, now)
+    }
+
+    /// Read-only: algorithm of a legacy import, if `hash` is one
+    pub fn document_legacy_algo(env: Env, hash: String) -> Option<HashAlgo> {
+        env.storage().persistent().get(&DataKey::LegacyAlgo(hash))
+    }
+
+    // ---------- WHITELIST LIVENESS ----------
+    //
+    // An archived whitelist entry reads as "not whitelisted", so entries are
+    // bumped whenever they authorize a call and can be bumped by anyone. The
+    // contract can't read TTLs, so the tracked ledger is an estimate: a bump
+    // counts whenever the tracked value was within its threshold.
+
+    /// Internal: extend the entry and its index slot if below `threshold`
+    fn bump_whitelist_entry(env: &Env, address: &Address, threshold: u32, extend_to: u32) {
+        let storage = env.storage().persistent();
+        storage.extend_ttl(&DataKey::Whitelist(address.clone()), threshold, extend_to);
+        let slot_key = DataKey::WhitelistSlot(address.clone());
+        if let Some(slot) = storage.get::<_, u32>(&slot_key) {
+            storage.extend_ttl(&slot_key, threshold, extend_to);
+            storage.extend_ttl(&DataKey::WhitelistIndex(slot), threshold, extend_to);
+        }
+        let live_key = DataKey::WhitelistLiveUntil(address.clone());
+        let seq = env.ledger().sequence();
+        let known: u32 = storage.get(&live_key).unwrap_or(0);
+        if known.saturating_sub(seq) <= threshold {
+            storage.set(&live_key, &seq.saturating_add(extend_to).max(known));
+            storage.extend_ttl(&live_key, extend_to, extend_to);
+        }
+    }
+
+    /// Anyone: extend a whitelist entry to `extend_to` ledgers if its TTL is
+    /// below `threshold`
+    pub fn extend_whitelist_ttl(env: Env, address: Address, threshold: u32, extend_to: u32) {
+        if !Self::is_whitelisted(env.clone(), address.clone()) {
+            panic!("not whitelisted");
+        }
+        if threshold > extend_to {
+            panic!("invalid ttl");
+        }
+        Self::bump_whitelist_entry(&env, &address, threshold, extend_to);
+    }
+
+    /// Read-only: ledger until which the whitelist entry is known to live
+    /// (None if not whitelisted or never bumped)
+    pub fn whitelist_live_until(env: Env, address: Address) -> Option<u32> {
+        if !Self::is_whitelisted(env.clone(), address.clone()) {
+            return None;
+        }
+        env.storage()
+            .persistent()
+            .get(&DataKey::WhitelistLiveUntil(address))
+    }
+
+    // ---------- MAINTAINERS ----------
+    //
+    // Maintainers run janitorial entrypoints (`bump_instance`, `bump_pinned`,
+    // `prune_epoch_counters`) so the owner key can stay offline. They get no
+    // other rights: they can't store or change the whitelist.
+
+    /// Owner-only: grant or revoke the maintainer designation
+    pub fn set_maintainer(env: Env, address: Address, enabled: bool) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        let key = DataKey::Maintainer(address.clone());
+        if enabled {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        events::maintainer_set(&env, &address, enabled);
+    }
+
+    /// Read-only: whether `address` is a maintainer
+    pub fn is_maintainer(env: Env, address: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Maintainer(address))
+    }
+
+    /// Internal: `caller` signed and is the owner or a maintainer
+    fn assert_owner_or_maintainer(env: &Env, caller: &Address) {
+        caller.require_auth();
+        if caller != &Self::owner_address(env.clone())
+            && !Self::is_maintainer(env.clone(), caller.clone())
+        {
+            panic!("not authorized: only owner or maintainer");
+        }
+    }
+
+    /// Owner or maintainer: extend the contract instance (and its code) TTL
+    pub fn bump_instance(env: Env, caller: Address, extend_to: u32) {
+        Self::assert_owner_or_maintainer(&env, &caller);
+        env.storage().instance().extend_ttl(extend_to, extend_to);
+    }
+
+    // ---------- CONSENT ----------
+    //
+    // A subject who acknowledged a document (by witnessing it) may later
+    // withdraw consent. The document stays `Active`; verifiers see the flag.
+    // The record is permanent: the owner can annotate it but not remove it.
+
+    /// Subject of the document: withdraw consent given by a prior witness
+    pub fn revoke_consent(env: Env, subject: Address, hash: String, reason: String) {
+        Self::assert_not_sealed(&env);
+        subject.require_auth();
+        if Self::document_subject(env.clone(), hash.clone()) != Some(subject.clone()) {
+            panic!("not authorized: only the document subject");
+        }
+        if reason.len() > MAX_REASON_LEN {
+            panic!("reason too long");
+        }
+        if !Self::has_witnessed(env.clone(), subject.clone(), hash.clone()) {
+            panic!("no prior acknowledgment");
+        }
+        let key = DataKey::ConsentRevocation(hash.clone());
+        if env.storage().persistent().has(&key) {
+            panic!("consent already revoked");
+        }
+        let record = ConsentRevocation {
+            subject,
+            reason,
+            revoked_at: env.ledger().timestamp(),
+            owner_note: String::from_str(&env, ""),
+        };
+        env.storage().persistent().set(&key, &record);
+        events::consent_revoked(&env, &hash, &record.subject, &record.reason);
+    }
+
+    /// Owner-only: attach a note to a consent revocation
+    pub fn annotate_consent_revocation(env: Env, hash: String, note: String) {
+        Self::assert_not_sealed(&env);
+        let _owner = Self::assert_owner(&env);
+        if note.len() > MAX_NOTE_LEN {
+            panic!("note too long");
+        }
+        let key = DataKey::ConsentRevocation(hash.clone());
+        let mut record: ConsentRevocation = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .expect("consent not revoked");
+        record.owner_note = note;
+        env.storage().persistent().set(&key, &record);
+    }
+
+    /// Read-only: the subject's consent revocation, if any
+    pub fn get_consent_revocation(env: Env, hash: String) -> Option<ConsentRevocation> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ConsentRevocation(hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{
+        symbol_short, Address, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
+    };
+    use soroban_sdk::testutils::{
+        Address as _, Events, Ledger, MockAuth, MockAuthInvoke, // trait import
+    };
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    fn setup(env: &Env) -> (Address, Address) {
+        let owner: Address = Address::generate(env);
+        let contract_addr: Address = env.register_contract(None, Contract);
+        let client = ContractClient::new(env, &contract_addr);
+        client.init(&owner);
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1_800_000_000;
+            li.sequence_number += 1;
+        });
+        (owner, contract_addr)
+    }
+
+    #[test]
+    #[should_panic] // require_auth should fail without mocked auth
+    fn only_owner_can_store_panics_without_auth() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+
+        let name = String::from_str(&env, "Confidential.pdf");
+        let hash = String::from_str(&env, "abc123");
+
+        // Now requires the actor argument; without mock auth this should panic.
+        client.store_document(&owner, &name, &hash);
+    }
+
+    #[test]
+    fn store_and_verify_document_with_owner_auth() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "OfferLetter.pdf");
+        let hash = String::from_str(
+            &env,
+            "2d8f1bd06c6f0c2c2f2b2b4a7b3a9b2e4a5b8d6f9e0c1d3f4a6b7c8d9e0f1a2b",
+        );
+
+        // pass owner as the authorized actor
+        client.store_document(&owner, &name, &hash);
+
+        let stored = client.read_document(&hash).expect("document should exist");
+        assert_eq!(stored.name, name);
+        assert_eq!(stored.hash, hash);
+        assert_eq!(stored.added_by, owner);
+        assert!(stored.timestamp > 0);
+
+        let verified = client.verify_document(&hash).expect("should verify");
+        assert_eq!(verified.hash, hash);
+        assert_eq!(verified.added_by, owner);
+        assert!(verified.timestamp > 0);
+        assert!(verified.verified_document);
+    }
+
+    #[test]
+    fn store_and_verify_multiple_documents_with_owner_auth() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "OfferLetter.pdf");
+        let hash = String::from_str(
+            &env,
+            "2d8f1bd06c6f0c2c2f2b2b4a7b3a9b2e4a5b8d6f9e0c1d3f4a6b7c8d9e0f1a2b",
+        );
+        let hash1 = String::from_str(
+            &env,
+            "3d8f1bd06c6f0c2c2f2b2b4a7b3a9b2e4a5b8d6f9e0c1d3f4a6b7c8d9e0f1a2b",
+        );
+
+        // actor is required; use owner so no whitelist setup needed
+        client.store_document(&owner, &name, &hash);
+        client.store_document(&owner, &name, &hash1);
+
+        let stored = client.read_document(&hash).expect("document should exist");
+        let stored1 = client.read_document(&hash1).expect("document should exist");
+        assert_eq!(stored.name, name);
+        assert_eq!(stored.hash, hash);
+        assert_eq!(stored1.hash, hash1);
+    }
+
+    #[test]
+    fn whitelist_add_two_and_remove_one_with_owner_auth() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        // Allow require_auth to pass in tests
+        env.mock_all_auths();
+
+        // two random addresses
+        let a1 = Address::generate(&env);
+        let a2 = Address::generate(&env);
+
+        // add both to whitelist (owner-only method; mock_all_auths lets it pass)
+        client.whitelist_address(&a1);
+        client.whitelist_address(&a2);
+
+        // check both are whitelisted
+        assert!(client.is_whitelisted(&a1), "a1 should be whitelisted");
+        assert!(client.is_whitelisted(&a2), "a2 should be whitelisted");
+
+        // remove one (a1) and check states
+        client.remove_from_whitelist(&a1);
+        assert!(!client.is_whitelisted(&a1), "a1 should NOT be whitelisted anymore");
+        assert!(client.is_whitelisted(&a2), "a2 should remain whitelisted");
+    }
+     #[test]
+    fn store_document_by_whitelisted_user() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        // create & whitelist a non-owner user
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+
+        let name = String::from_str(&env, "Whitelisted-Doc.pdf");
+        let hash = String::from_str(
+            &env,
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        );
+
+        // should succeed because `user` is whitelisted
+        client.store_document(&user, &name, &hash);
+
+        let stored = client.read_document(&hash).expect("document should exist");
+        assert_eq!(stored.name, name);
+        assert_eq!(stored.hash, hash);
+        assert_eq!(stored.added_by, user, "added_by must be the whitelisted caller");
+        assert!(stored.timestamp > 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "not authorized: only owner or whitelisted address")]
+    fn store_document_by_non_whitelisted_user_panics() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths(); // make require_auth() pass so we fail specifically on whitelist
+
+        // random user NOT added to whitelist
+        let user = Address::generate(&env);
+
+        let name = String::from_str(&env, "Not-Whitelisted-Doc.pdf");
+        let hash = String::from_str(
+            &env,
+            "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb",
+        );
+
+        // should panic because user is neither owner nor whitelisted
+        client.store_document(&user, &name, &hash);
+    }
+
+#[test]
+#[should_panic(expected = "Document already registered")]
+fn store_document_duplicate_hash_panics() {
+    let env = Env::default();
+    let (owner, contract_addr) = setup(&env);
+    let client = ContractClient::new(&env, &contract_addr);
+    env.mock_all_auths();
+
+    let name1 = String::from_str(&env, "Doc-v1.pdf");
+    let name2 = String::from_str(&env, "Doc-v2.pdf");
+    let hash = String::from_str(
+        &env,
+        "deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+    );
+
+    // First insert succeeds
+    client.store_document(&owner, &name1, &hash);
+
+    // Second insert with same hash MUST panic
+    client.store_document(&owner, &name2, &hash);
+}
+
+    #[test]
+fn reads_owner_address() {
+    let env = Env::default();
+    let (owner, contract_addr) = setup(&env);
+    let client = ContractClient::new(&env, &contract_addr);
+    let got = client.owner_address();
+    assert_eq!(got, owner);
+}
+    #[test]
+    fn transfer_ownership_with_owner_auth() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+
+        // allow require_auth to pass for the owner
+        env.mock_all_auths();
+
+        // transfer to a new owner address
+        let new_owner = Address::generate(&env);
+        client.transfer_ownership(&new_owner);
+
+        // verify ownership changed
+        // If you implemented `owner_address`, use that:
+        let got = client.owner_address();
+        assert_eq!(got, new_owner, "ownership should be transferred to new_owner");
+    }
+
+    #[test]
+    #[should_panic] // should fail because owner did not authorize
+    fn transfer_ownership_without_owner_auth_panics() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+
+        // DO NOT mock auth here so require_auth() fails
+        let new_owner = Address::generate(&env);
+
+        // This should panic: current owner didn't authorize this call
+        client.transfer_ownership(&new_owner);
+    }
+
+    // ---------- SEAL ----------
+
+    fn sealed_setup(env: &Env) -> (Address, ContractClient<'_>) {
+        let (owner, contract_addr) = setup(env);
+        let client = ContractClient::new(env, &contract_addr);
+        env.mock_all_auths();
+        client.seal_registry();
+        (owner, client)
+    }
+
+    #[test]
+    fn seal_records_timestamp_and_document_count() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Final.pdf");
+        let hash = String::from_str(
+            &env,
+            "1111111111111111111111111111111111111111111111111111111111111111",
+        );
+        client.store_document(&owner, &name, &hash);
+        assert!(!client.is_sealed());
+        assert_eq!(client.seal_info(), None);
+
+        client.seal_registry();
+        assert!(client.is_sealed());
+        let info = client.seal_info().expect("seal info should exist");
+        assert_eq!(info.sealed_at, 1_800_000_000);
+        assert_eq!(info.document_count, 1);
+
+        // reads keep working
+        assert!(client.verify_document(&hash).is_some());
+        assert_eq!(client.owner_address(), owner);
+    }
+
+    #[test]
+    #[should_panic(expected = "registry sealed")]
+    fn store_document_after_seal_panics() {
+        let env = Env::default();
+        let (owner, client) = sealed_setup(&env);
+        let name = String::from_str(&env, "Late.pdf");
+        let hash = String::from_str(
+            &env,
+            "2222222222222222222222222222222222222222222222222222222222222222",
+        );
+        client.store_document(&owner, &name, &hash);
+    }
+
+    #[test]
+    #[should_panic(expected = "registry sealed")]
+    fn whitelist_address_after_seal_panics() {
+        let env = Env::default();
+        let (_owner, client) = sealed_setup(&env);
+        client.whitelist_address(&Address::generate(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "registry sealed")]
+    fn remove_from_whitelist_after_seal_panics() {
+        let env = Env::default();
+        let (_owner, client) = sealed_setup(&env);
+        client.remove_from_whitelist(&Address::generate(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "registry sealed")]
+    fn transfer_ownership_after_seal_panics() {
+        let env = Env::default();
+        let (_owner, client) = sealed_setup(&env);
+        client.transfer_ownership(&Address::generate(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "registry sealed")]
+    fn seal_registry_twice_panics() {
+        let env = Env::default();
+        let (_owner, client) = sealed_setup(&env);
+        client.seal_registry();
+    }
+
+    // ---------- NAMESPACES ----------
+
+    #[test]
+    fn same_hash_can_exist_in_two_namespaces() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let ns_a = Symbol::new(&env, "client_a");
+        let ns_b = Symbol::new(&env, "client_b");
+        let admin_a = Address::generate(&env);
+        let admin_b = Address::generate(&env);
+        client.create_namespace(&ns_a, &admin_a);
+        client.create_namespace(&ns_b, &admin_b);
+
+        let hash = String::from_str(
+            &env,
+            "cccccccccccccccccccccccccccccccccccccccccccccccccccccccccccccccc",
+        );
+        let name_a = String::from_str(&env, "A.pdf");
+        let name_b = String::from_str(&env, "B.pdf");
+        client.store_document_ns(&ns_a, &admin_a, &name_a, &hash);
+        client.store_document_ns(&ns_b, &admin_b, &name_b, &hash);
+
+        let a = client.read_document_ns(&ns_a, &hash).expect("doc in ns a");
+        let b = client.read_document_ns(&ns_b, &hash).expect("doc in ns b");
+        assert_eq!(a.name, name_a);
+        assert_eq!(a.added_by, admin_a);
+        assert_eq!(b.name, name_b);
+        assert_eq!(b.added_by, admin_b);
+        assert!(client.verify_document_ns(&ns_a, &hash).expect("verify").verified_document);
+
+        // flat (default) registry is untouched
+        assert!(client.read_document(&hash).is_none());
+    }
+
+    #[test]
+    fn namespace_whitelisted_user_stores_only_in_own_namespace() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let ns_a = Symbol::new(&env, "client_a");
+        let ns_b = Symbol::new(&env, "client_b");
+        let admin_a = Address::generate(&env);
+        client.create_namespace(&ns_a, &admin_a);
+        client.create_namespace(&ns_b, &Address::generate(&env));
+
+        let user = Address::generate(&env);
+        client.whitelist_address_ns(&ns_a, &admin_a, &user);
+        assert!(client.is_whitelisted_ns(&ns_a, &user));
+        assert!(!client.is_whitelisted_ns(&ns_b, &user));
+        assert!(!client.is_whitelisted(&user));
+
+        let name = String::from_str(&env, "Scoped.pdf");
+        let hash = String::from_str(
+            &env,
+            "dddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddd",
+        );
+        client.store_document_ns(&ns_a, &user, &name, &hash);
+        assert!(client.try_store_document_ns(&ns_b, &user, &name, &hash).is_err());
+
+        client.remove_from_whitelist_ns(&ns_a, &admin_a, &user);
+        assert!(!client.is_whitelisted_ns(&ns_a, &user));
+    }
+
+    #[test]
+    #[should_panic(expected = "not authorized: only namespace admin")]
+    fn namespace_admin_cannot_whitelist_in_other_namespace() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let ns_a = Symbol::new(&env, "client_a");
+        let ns_b = Symbol::new(&env, "client_b");
+        let admin_a = Address::generate(&env);
+        client.create_namespace(&ns_a, &admin_a);
+        client.create_namespace(&ns_b, &Address::generate(&env));
+
+        client.whitelist_address_ns(&ns_b, &admin_a, &Address::generate(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "not authorized: only namespace admin or whitelisted address")]
+    fn namespace_admin_cannot_store_in_other_namespace() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let ns_a = Symbol::new(&env, "client_a");
+        let ns_b = Symbol::new(&env, "client_b");
+        let admin_a = Address::generate(&env);
+        client.create_namespace(&ns_a, &admin_a);
+        client.create_namespace(&ns_b, &Address::generate(&env));
+
+        let name = String::from_str(&env, "Intruder.pdf");
+        let hash = String::from_str(
+            &env,
+            "eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee",
+        );
+        client.store_document_ns(&ns_b, &admin_a, &name, &hash);
+    }
+
+    #[test]
+    #[should_panic(expected = "namespace already exists")]
+    fn create_namespace_twice_panics() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let ns = Symbol::new(&env, "client_a");
+        client.create_namespace(&ns, &Address::generate(&env));
+        client.create_namespace(&ns, &Address::generate(&env));
+    }
+
+    fn two_namespaces(env: &Env, client: &ContractClient) -> (Symbol, Address, Symbol, Address) {
+        let ns_a = Symbol::new(env, "client_a");
+        let ns_b = Symbol::new(env, "client_b");
+        let admin_a = Address::generate(env);
+        let admin_b = Address::generate(env);
+        client.create_namespace(&ns_a, &admin_a);
+        client.create_namespace(&ns_b, &admin_b);
+        (ns_a, admin_a, ns_b, admin_b)
+    }
+
+    #[test]
+    fn move_document_rekeys_between_namespaces() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let (ns_a, admin_a, ns_b, _admin_b) = two_namespaces(&env, &client);
+        let name = String::from_str(&env, "Moving.pdf");
+        client.store_document_ns(&ns_a, &admin_a, &name, &hash_n(&env, 1));
+        client.store_document_ns(&ns_a, &admin_a, &name, &hash_n(&env, 2));
+        advance_time(&env, 500);
+
+        client.move_document(&admin_a, &ns_a, &ns_b, &hash_n(&env, 1));
+        let topics = (symbol_short!("namespace"), symbol_short!("moved"), hash_n(&env, 1));
+        let payload = (ns_a.clone(), ns_b.clone());
+        assert_last_event(&env, topics.into_val(&env), payload.into_val(&env));
+
+        assert_eq!(client.read_document_ns(&ns_a, &hash_n(&env, 1)), None);
+        let moved = client.read_document_ns(&ns_b, &hash_n(&env, 1)).unwrap();
+        assert_eq!((moved.timestamp, moved.added_by), (1_800_000_000, admin_a));
+        let source = client.list_documents_ns(&ns_a, &0, &10).items;
+        assert_eq!(source.len(), 1);
+        assert_eq!(source.get(0).unwrap().hash, hash_n(&env, 2));
+        let dest = client.list_documents_ns(&ns_b, &0, &10).items;
+        assert_eq!(dest.len(), 1);
+        assert_eq!(dest.get(0).unwrap().hash, hash_n(&env, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Document already registered")]
+    fn move_document_refuses_taken_destination() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let (ns_a, admin_a, ns_b, admin_b) = two_namespaces(&env, &client);
+        let name = String::from_str(&env, "Twice.pdf");
+        client.store_document_ns(&ns_a, &admin_a, &name, &hash_n(&env, 1));
+        client.store_document_ns(&ns_b, &admin_b, &name, &hash_n(&env, 1));
+        client.move_document(&owner, &ns_a, &ns_b, &hash_n(&env, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "not authorized: only namespace admins")]
+    fn move_document_needs_an_admin_or_owner() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let (ns_a, admin_a, ns_b, _admin_b) = two_namespaces(&env, &client);
+        let name = String::from_str(&env, "Mine.pdf");
+        client.store_document_ns(&ns_a, &admin_a, &name, &hash_n(&env, 1));
+        client.move_document(&Address::generate(&env), &ns_a, &ns_b, &hash_n(&env, 1));
+    }
+
+    // ---------- QUARANTINE ON REMOVAL ----------
+
+    fn hash_n(env: &Env, n: u32) -> String {
+        let mut buf = [b'0'; 64];
+        let digits = b"0123456789abcdef";
+        let mut v = n;
+        let mut i = 64;
+        while v > 0 {
+            i -= 1;
+            buf[i] = digits[(v % 16) as usize];
+            v /= 16;
+        }
+        String::from_bytes(env, &buf)
+    }
+
+    #[test]
+    fn remove_and_quarantine_resumes_across_two_calls() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let name = String::from_str(&env, "Batch.pdf");
+        for i in 0..12 {
+            client.store_document(&user, &name, &hash_n(&env, i));
+        }
+        assert_eq!(client.actor_document_count(&user), 12);
+
+        // first call: whitelist entry gone, first batch quarantined
+        let next = client.remove_and_quarantine(&user, &0);
+        assert_eq!(next, Some(10));
+        assert!(!client.is_whitelisted(&user));
+        assert!(!client.verify_document(&hash_n(&env, 9)).unwrap().verified_document);
+        assert!(client.verify_document(&hash_n(&env, 10)).unwrap().verified_document);
+
+        // second call finishes the index
+        let next = client.remove_and_quarantine(&user, &10);
+        assert_eq!(next, None);
+        for i in 0..12 {
+            assert_eq!(
+                client.document_status(&hash_n(&env, i)),
+                Some(DocumentStatus::Quarantined)
+            );
+        }
+
+        // owner clears one document individually
+        client.clear_quarantine(&hash_n(&env, 3));
+        assert!(client.verify_document(&hash_n(&env, 3)).unwrap().verified_document);
+        assert!(!client.verify_document(&hash_n(&env, 4)).unwrap().verified_document);
+    }
+
+    #[test]
+    fn plain_remove_from_whitelist_leaves_documents_verified() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let name = String::from_str(&env, "Kept.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
+
+        client.remove_from_whitelist(&user);
+        assert!(client.verify_document(&hash_n(&env, 1)).unwrap().verified_document);
+    }
+
+    #[test]
+    #[should_panic(expected = "document not quarantined")]
+    fn clear_quarantine_on_active_document_panics() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Active.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.clear_quarantine(&hash_n(&env, 1));
+    }
+
+    // ---------- SELF-SERVICE DELETION ----------
+
+    fn advance_time(env: &Env, secs: u64) {
+        env.ledger().with_mut(|li| {
+            li.timestamp += secs;
+        });
+    }
+
+    #[test]
+    fn delete_own_document_exactly_at_grace_boundary() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let name = String::from_str(&env, "Typo.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
+        assert_eq!(client.document_count(), 1);
+
+        advance_time(&env, DEFAULT_SELF_DELETE_GRACE_SECS);
+        client.delete_own_document(&user, &hash_n(&env, 1));
+
+        assert!(client.read_document(&hash_n(&env, 1)).is_none());
+        assert_eq!(client.document_count(), 0);
+        // the hash can be registered again with the corrected name
+        let fixed = String::from_str(&env, "Fixed.pdf");
+        client.store_document(&user, &fixed, &hash_n(&env, 1));
+        assert_eq!(client.read_document(&hash_n(&env, 1)).unwrap().name, fixed);
+    }
+
+    #[test]
+    #[should_panic(expected = "grace period expired")]
+    fn delete_own_document_one_second_after_grace_panics() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let name = String::from_str(&env, "Late.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
+
+        advance_time(&env, DEFAULT_SELF_DELETE_GRACE_SECS + 1);
+        client.delete_own_document(&user, &hash_n(&env, 1));
+    }
+
+    #[test]
+    fn delete_own_document_honours_configured_grace() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.set_config(&Config {
+            self_delete_grace_secs: 60,
+            ..client.get_config()
+        });
+        let name = String::from_str(&env, "Short.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.store_document(&owner, &name, &hash_n(&env, 2));
+
+        advance_time(&env, 60);
+        client.delete_own_document(&owner, &hash_n(&env, 1));
+        advance_time(&env, 1);
+        assert!(client.try_delete_own_document(&owner, &hash_n(&env, 2)).is_err());
+        assert!(client.read_document(&hash_n(&env, 2)).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "not authorized: only the submitter")]
+    fn delete_own_document_by_other_actor_panics() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Owners.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.delete_own_document(&Address::generate(&env), &hash_n(&env, 1));
+    }
+
+    // ---------- PINNING ----------
+
+    fn doc_ttl(env: &Env, contract_addr: &Address, hash: &String) -> u32 {
+        env.as_contract(contract_addr, || {
+            env.storage()
+                .persistent()
+                .get_ttl(&DataKey::Document(hash.clone()))
+        })
+    }
+
+    #[test]
+    #[should_panic(expected = "document pinned")]
+    fn pinned_document_refuses_delete() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Decade.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.pin_document(&hash_n(&env, 1));
+        assert!(client.is_pinned(&hash_n(&env, 1)));
+
+        client.delete_own_document(&owner, &hash_n(&env, 1));
+    }
+
+    #[test]
+    fn unpinned_document_can_be_deleted_again() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Decade.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.pin_document(&hash_n(&env, 1));
+        client.unpin_document(&hash_n(&env, 1));
+        assert!(!client.is_pinned(&hash_n(&env, 1)));
+
+        client.delete_own_document(&owner, &hash_n(&env, 1));
+        assert!(client.read_document(&hash_n(&env, 1)).is_none());
+    }
+
+    #[test]
+    fn bump_pinned_extends_all_ttls_in_one_call() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Pinned.pdf");
+        let hashes = Vec::from_array(&env, [hash_n(&env, 1), hash_n(&env, 2)]);
+        for hash in hashes.iter() {
+            client.store_document(&owner, &name, &hash);
+            client.pin_document(&hash);
+        }
+
+        client.bump_pinned(&owner, &hashes, &1_000_000);
+        for hash in hashes.iter() {
+            assert_eq!(doc_ttl(&env, &contract_addr, &hash), 1_000_000);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "document not pinned")]
+    fn bump_pinned_rejects_unpinned_hash() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Loose.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.store_document(&owner, &name, &hash_n(&env, 2));
+        client.pin_document(&hash_n(&env, 1));
+
+        let hashes = Vec::from_array(&env, [hash_n(&env, 1), hash_n(&env, 2)]);
+        client.bump_pinned(&owner, &hashes, &1_000_000);
+    }
+
+    // ---------- LIVENESS ----------
+
+    fn advance_ledgers(env: &Env, ledgers: u32) {
+        env.ledger().with_mut(|li| {
+            li.sequence_number += ledgers;
+        });
+    }
+
+    #[test]
+    fn document_live_until_matches_host_ttl() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Live.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+
+        let seq = env.ledger().sequence();
+        let live_until = client.document_live_until(&hash_n(&env, 1)).expect("tracked");
+        assert_eq!(live_until, seq + DOC_BUMP_AMOUNT);
+        assert_eq!(seq + doc_ttl(&env, &contract_addr, &hash_n(&env, 1)), live_until);
+        assert_eq!(client.document_live_until(&hash_n(&env, 2)), None);
+    }
+
+    #[test]
+    fn document_ttl_status_transitions() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.set_config(&Config {
+            ttl_warning_ledgers: DOC_BUMP_AMOUNT - 1_000,
+            ..client.get_config()
+        });
+        let name = String::from_str(&env, "Ageing.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        assert_eq!(client.document_ttl_status(&hash_n(&env, 1)), TtlStatus::Live);
+        assert_eq!(client.document_ttl_status(&hash_n(&env, 2)), TtlStatus::Missing);
+
+        // host TTL shrinks as the ledger advances
+        let before = doc_ttl(&env, &contract_addr, &hash_n(&env, 1));
+        advance_ledgers(&env, 1_000);
+        assert_eq!(doc_ttl(&env, &contract_addr, &hash_n(&env, 1)), before - 1_000);
+        assert_eq!(client.document_ttl_status(&hash_n(&env, 1)), TtlStatus::NearExpiry);
+
+        // bumping moves it back to Live
+        client.pin_document(&hash_n(&env, 1));
+        let hashes = Vec::from_array(&env, [hash_n(&env, 1)]);
+        client.bump_pinned(&owner, &hashes, &(2 * DOC_BUMP_AMOUNT));
+        assert_eq!(client.document_ttl_status(&hash_n(&env, 1)), TtlStatus::Live);
+        assert_eq!(
+            client.document_live_until(&hash_n(&env, 1)),
+            Some(env.ledger().sequence() + 2 * DOC_BUMP_AMOUNT)
+        );
+    }
+
+    // ---------- RESTORATION ----------
+
+    #[test]
+    fn restore_evicted_document_preserves_provenance() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let original_submitter = Address::generate(&env);
+        let name = String::from_str(&env, "Archived.pdf");
+        client.restore_evicted_document(
+            &name,
+            &hash_n(&env, 1),
+            &1_600_000_000,
+            &original_submitter,
+        );
+
+        let stored = client.read_document(&hash_n(&env, 1)).expect("restored");
+        assert_eq!(stored.timestamp, 1_600_000_000);
+        assert_eq!(stored.added_by, original_submitter);
+
+        let verified = client.verify_document(&hash_n(&env, 1)).expect("verifies");
+        assert!(verified.verified_document);
+        assert!(verified.restored);
+        assert_eq!(verified.restored_at, Some(1_800_000_000));
+        assert_eq!(verified.timestamp, 1_600_000_000);
+    }
+
+    #[test]
+    fn native_document_is_not_flagged_restored() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Native.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let verified = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert!(!verified.restored);
+        assert_eq!(verified.restored_at, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Document already registered")]
+    fn restore_live_document_panics() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Live.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.restore_evicted_document(&name, &hash_n(&env, 1), &1_600_000_000, &owner);
+    }
+
+    // ---------- REFERENCES ----------
+
+    #[test]
+    fn document_references_keep_order_after_removal() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let name = String::from_str(&env, "Contract.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
+
+        let pdf = String::from_str(&env, "ipfs://signed-copy");
+        let scan = String::from_str(&env, "https://example.com/scan.png");
+        let mail = String::from_str(&env, "https://example.com/thread.eml");
+        client.add_document_reference(&user, &hash_n(&env, 1), &pdf);
+        client.add_document_reference(&user, &hash_n(&env, 1), &scan);
+        client.add_document_reference(&user, &hash_n(&env, 1), &mail);
+
+        client.remove_document_reference(&user, &hash_n(&env, 1), &1);
+        let refs = client.get_document_references(&hash_n(&env, 1));
+        assert_eq!(refs, Vec::from_array(&env, [pdf.clone(), mail.clone()]));
+
+        client.remove_document_reference(&user, &hash_n(&env, 1), &0);
+        assert_eq!(client.get_document_references(&hash_n(&env, 1)), Vec::from_array(&env, [mail]));
+    }
+
+    #[test]
+    #[should_panic(expected = "too many references")]
+    fn document_references_cap_enforced() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Busy.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let uri = String::from_str(&env, "https://example.com/ref");
+        for _ in 0..=MAX_DOC_REFS {
+            client.add_document_reference(&owner, &hash_n(&env, 1), &uri);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "reference index out of range")]
+    fn remove_document_reference_out_of_range_panics() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Single.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let uri = String::from_str(&env, "https://example.com/ref");
+        client.add_document_reference(&owner, &hash_n(&env, 1), &uri);
+        client.remove_document_reference(&owner, &hash_n(&env, 1), &1);
+    }
+
+    #[test]
+    #[should_panic(expected = "not authorized: only owner or document controller")]
+    fn add_document_reference_by_stranger_panics() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Guarded.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let uri = String::from_str(&env, "https://example.com/ref");
+        client.add_document_reference(&Address::generate(&env), &hash_n(&env, 1), &uri);
+    }
+
+    // ---------- EVENT SEQUENCE ----------
+
+    /// Sequence number carried by the most recent event
+    fn last_emitted_seq(env: &Env) -> u64 {
+        let (_contract, _topics, data) = env.events().all().last().expect("an event");
+        let fields: Vec<Val> = Vec::try_from_val(env, &data).unwrap();
+        u64::try_from_val(env, &fields.get(1).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn event_sequence_strictly_increases_across_operations() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        assert_eq!(client.last_event_seq(), 0);
+
+        let user = Address::generate(&env);
+        let name = String::from_str(&env, "Indexed.pdf");
+        let mut seen = 0u64;
+        let mut check = |env: &Env, client: &ContractClient| {
+            let seq = last_emitted_seq(env);
+            assert!(seq > seen, "event sequence must increase");
+            assert_eq!(seq, client.last_event_seq());
+            seen = seq;
+        };
+
+        client.whitelist_address(&user);
+        check(&env, &client);
+        client.store_document(&user, &name, &hash_n(&env, 1));
+        check(&env, &client);
+        client.pin_document(&hash_n(&env, 1));
+        check(&env, &client);
+        client.set_config(&client.get_config());
+        check(&env, &client);
+        client.remove_from_whitelist(&user);
+        check(&env, &client);
+        client.store_document(&owner, &name, &hash_n(&env, 2));
+        check(&env, &client);
+        client.transfer_ownership(&Address::generate(&env));
+        check(&env, &client);
+
+        assert_eq!(client.last_event_seq(), 7);
+    }
+
+    #[test]
+    fn reads_do_not_advance_event_sequence() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Quiet.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let seq = client.last_event_seq();
+        client.read_document(&hash_n(&env, 1));
+        client.verify_document(&hash_n(&env, 1));
+        assert_eq!(client.last_event_seq(), seq);
+    }
+
+    // ---------- SUMMARIES ----------
+
+    #[test]
+    fn document_summary_mirrors_full_record() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let name = String::from_str(&env, "A-Rather-Long-Document-Name.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
+
+        let full = client.read_document(&hash_n(&env, 1)).unwrap();
+        let summary = client.read_document_summary(&hash_n(&env, 1)).unwrap();
+        assert_eq!(summary.hash, full.hash);
+        assert_eq!(summary.timestamp, full.timestamp);
+        assert_eq!(summary.added_by, full.added_by);
+        assert_eq!(summary.status, DocumentStatus::Active);
+        assert_eq!(client.read_document_summary(&hash_n(&env, 2)), None);
+    }
+
+    #[test]
+    fn list_documents_by_actor_returns_summaries_and_skips_deleted() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let name = String::from_str(&env, "Listed.pdf");
+        for i in 0..4 {
+            client.store_document(&user, &name, &hash_n(&env, i));
+        }
+        client.delete_own_document(&user, &hash_n(&env, 1));
+        client.remove_and_quarantine(&user, &3);
+
+        let page = client.list_documents_by_actor(&user, &0, &10);
+        assert_eq!(page.len(), 3);
+        assert_eq!(page.get(0).unwrap().hash, hash_n(&env, 0));
+        assert_eq!(page.get(1).unwrap().hash, hash_n(&env, 2));
+        let last = page.get(2).unwrap();
+        assert_eq!(last.hash, hash_n(&env, 3));
+        assert_eq!(last.status, DocumentStatus::Quarantined);
+
+        let second_page = client.list_documents_by_actor(&user, &2, &1);
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page.get(0).unwrap().hash, hash_n(&env, 2));
+    }
+
+    // ---------- BATCH LIMITS ----------
+
+    fn inputs(env: &Env, from: u32, count: u32) -> Vec<DocumentInput> {
+        let mut docs = Vec::new(env);
+        for i in from..from + count {
+            docs.push_back(DocumentInput {
+                name: String::from_str(env, "Batch.pdf"),
+                hash: hash_n(env, i),
+            });
+        }
+        docs
+    }
+
+    #[test]
+    fn store_documents_accepts_exactly_the_limit() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let limit = client.get_limits().max_batch_store;
+        assert_eq!(limit, DEFAULT_MAX_BATCH_STORE);
+        client.store_documents(&owner, &inputs(&env, 0, limit));
+        assert_eq!(client.document_count(), limit as u64);
+        assert!(client.try_store_documents(&owner, &inputs(&env, 100, limit + 1)).is_err());
+        assert_eq!(client.document_count(), limit as u64);
+    }
+
+    #[test]
+    fn read_documents_limit_boundary_and_order() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let mut limits = client.get_limits();
+        limits.max_batch_read = 3;
+        client.set_config(&Config {
+            limits,
+            ..client.get_config()
+        });
+        let name = String::from_str(&env, "Read.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 2));
+
+        let hashes = Vec::from_array(&env, [hash_n(&env, 1), hash_n(&env, 2), hash_n(&env, 3)]);
+        let docs = client.read_documents(&hashes);
+        assert_eq!(docs.len(), 3);
+        assert!(docs.get(0).unwrap().is_none());
+        assert_eq!(docs.get(1).unwrap().unwrap().hash, hash_n(&env, 2));
+        assert!(docs.get(2).unwrap().is_none());
+
+        let mut too_many = hashes.clone();
+        too_many.push_back(hash_n(&env, 4));
+        assert!(client.try_read_documents(&too_many).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "batch too large")]
+    fn whitelist_addresses_over_limit_panics() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let mut addresses = Vec::new(&env);
+        for _ in 0..=DEFAULT_MAX_BATCH_WHITELIST {
+            addresses.push_back(Address::generate(&env));
+        }
+        client.whitelist_addresses(&addresses);
+    }
+
+    #[test]
+    fn empty_batches_are_a_no_op() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.store_documents(&owner, &Vec::new(&env));
+        client.whitelist_addresses(&Vec::new(&env));
+        assert_eq!(client.read_documents(&Vec::new(&env)).len(), 0);
+        assert_eq!(client.document_count(), 0);
+        assert_eq!(client.last_event_seq(), 0);
+    }
+
+    // ---------- CONTROLLERS ----------
+
+    #[test]
+    fn co_controller_can_amend_and_revoke() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let legal = Address::generate(&env);
+        let finance = Address::generate(&env);
+        client.whitelist_address(&legal);
+        let name = String::from_str(&env, "Joint.pdf");
+        client.store_document(&legal, &name, &hash_n(&env, 1));
+        assert_eq!(
+            client.get_document_controllers(&hash_n(&env, 1)),
+            Vec::from_array(&env, [legal.clone()])
+        );
+
+        client.add_document_controller(&legal, &hash_n(&env, 1), &finance);
+        assert_eq!(
+            client.get_document_controllers(&hash_n(&env, 1)),
+            Vec::from_array(&env, [legal.clone(), finance.clone()])
+        );
+
+        // finance was never whitelisted but controls this document
+        let uri = String::from_str(&env, "https://example.com/ledger");
+        client.add_document_reference(&finance, &hash_n(&env, 1), &uri);
+        client.revoke_document(&finance, &hash_n(&env, 1));
+        assert_eq!(client.document_status(&hash_n(&env, 1)), Some(DocumentStatus::Revoked));
+        assert!(!client.verify_document(&hash_n(&env, 1)).unwrap().verified_document);
+
+        // the submitter can be dropped while another controller remains
+        client.remove_document_controller(&finance, &hash_n(&env, 1), &legal);
+        assert_eq!(
+            client.get_document_controllers(&hash_n(&env, 1)),
+            Vec::from_array(&env, [finance])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "document must keep a controller")]
+    fn removing_last_controller_panics() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Solo.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.remove_document_controller(&owner, &hash_n(&env, 1), &owner);
+    }
+
+    #[test]
+    #[should_panic(expected = "not authorized: only owner or document controller")]
+    fn non_controller_cannot_revoke() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        let other = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.whitelist_address(&other);
+        let name = String::from_str(&env, "Mine.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
+
+        client.revoke_document(&other, &hash_n(&env, 1));
+    }
+
+    #[test]
+    fn quarantine_on_removal_keeps_revoked_status() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let name = String::from_str(&env, "Old.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
+        client.store_document(&user, &name, &hash_n(&env, 2));
+        client.revoke_document(&user, &hash_n(&env, 1));
+
+        client.remove_and_quarantine(&user, &0);
+        assert_eq!(client.document_status(&hash_n(&env, 1)), Some(DocumentStatus::Revoked));
+        assert_eq!(client.document_status(&hash_n(&env, 2)), Some(DocumentStatus::Quarantined));
+    }
+
+    // ---------- CONTRACT ACTORS ----------
+
+    mod pipeline {
+        use crate::ContractClient;
+        use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+        /// Minimal issuance pipeline that registers documents as itself
+        #[contract]
+        pub struct Pipeline;
+
+        #[contractimpl]
+        impl Pipeline {
+            pub fn issue(env: Env, registry: Address, name: String, hash: String) {
+                let actor = env.current_contract_address();
+                ContractClient::new(&env, &registry).store_document(&actor, &name, &hash);
+            }
+        }
+    }
+
+    #[test]
+    fn whitelisted_contract_stores_as_direct_invoker() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        let pipeline_addr = env.register_contract(None, pipeline::Pipeline);
+        let pipeline = pipeline::PipelineClient::new(&env, &pipeline_addr);
+
+        env.mock_all_auths();
+        client.whitelist_address(&pipeline_addr);
+        // stop mocking: the pipeline's own invocation must satisfy require_auth
+        env.set_auths(&[]);
+
+        let name = String::from_str(&env, "Issued.pdf");
+        pipeline.issue(&contract_addr, &name, &hash_n(&env, 1));
+
+        let stored = client.read_document(&hash_n(&env, 1)).expect("stored by contract");
+        assert_eq!(stored.added_by, pipeline_addr);
+        assert_eq!(client.actor_document_count(&pipeline_addr), 1);
+    }
+
+    #[test]
+    fn non_whitelisted_contract_is_rejected() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        let pipeline_addr = env.register_contract(None, pipeline::Pipeline);
+        let pipeline = pipeline::PipelineClient::new(&env, &pipeline_addr);
+
+        let name = String::from_str(&env, "Rogue.pdf");
+        assert!(pipeline.try_issue(&contract_addr, &name, &hash_n(&env, 1)).is_err());
+        assert!(client.read_document(&hash_n(&env, 1)).is_none());
+    }
+
+    // ---------- TWO-STEP OWNERSHIP ----------
+
+    #[test]
+    fn pending_owner_lifecycle_with_accept() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let candidate = Address::generate(&env);
+        assert_eq!(client.pending_owner(), None);
+
+        client.propose_ownership(&candidate);
+        assert_eq!(client.pending_owner(), Some(candidate.clone()));
+        let (_contract, topics, data) = env.events().all().last().unwrap();
+        let expected: Vec<Val> = (
+            symbol_short!("ownership"),
+            symbol_short!("proposed"),
+            candidate.clone(),
+        )
+            .into_val(&env);
+        assert_eq!(topics, expected);
+        let payload = <(u32, u64, Address)>::try_from_val(&env, &data).unwrap();
+        assert_eq!(payload.2, owner);
+        assert_eq!(client.owner_address(), owner, "owner unchanged until accepted");
+
+        client.accept_ownership();
+        assert_eq!(client.pending_owner(), None);
+        assert_eq!(client.owner_address(), candidate);
+    }
+
+    #[test]
+    fn pending_owner_lifecycle_with_cancel() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let candidate = Address::generate(&env);
+        client.propose_ownership(&candidate);
+        assert_eq!(client.pending_owner(), Some(candidate));
+
+        client.cancel_ownership_transfer();
+        assert_eq!(client.pending_owner(), None);
+        assert_eq!(client.owner_address(), owner);
+        assert!(client.try_accept_ownership().is_err());
+    }
+
+    #[test]
+    #[should_panic] // candidate did not authorize
+    fn accept_ownership_requires_candidate_auth() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        let candidate = Address::generate(&env);
+
+        env.mock_auths(&[MockAuth {
+            address: &owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract_addr,
+                fn_name: "propose_ownership",
+                args: (candidate.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        client.propose_ownership(&candidate);
+
+        env.set_auths(&[]);
+        client.accept_ownership();
+    }
+
+    // ---------- REMOVAL REASONS ----------
+
+    #[test]
+    fn remove_with_reason_records_and_emits() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        assert_eq!(client.get_last_removal(&user), None);
+
+        let reason = String::from_str(&env, "key compromise reported 2026-03-01");
+        client.remove_whitelisted_with_reason(&user, &reason);
+        assert!(!client.is_whitelisted(&user));
+
+        let (_contract, topics, data) = env.events().all().last().unwrap();
+        let addr = Address::try_from_val(&env, &topics.get(2).unwrap()).unwrap();
+        let (_version, _seq, (ev_reason, ts)) =
+            <(u32, u64, (String, u64))>::try_from_val(&env, &data).unwrap();
+        assert_eq!(addr, user);
+        assert_eq!(ev_reason, reason);
+        assert_eq!(ts, 1_800_000_000);
+
+        let record = client.get_last_removal(&user).expect("record kept");
+        assert_eq!(record.reason, reason);
+        assert_eq!(record.removed_at, 1_800_000_000);
+    }
+
+    #[test]
+    fn plain_removal_emits_empty_reason() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.remove_from_whitelist(&user);
+
+        let (_contract, _topics, data) = env.events().all().last().unwrap();
+        let (_version, _seq, (reason, _ts)) =
+            <(u32, u64, (String, u64))>::try_from_val(&env, &data).unwrap();
+        assert_eq!(reason, String::from_str(&env, ""));
+        assert_eq!(client.get_last_removal(&user).unwrap().reason, reason);
+    }
+
+    #[test]
+    #[should_panic(expected = "reason too long")]
+    fn remove_with_overlong_reason_panics() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let reason = String::from_bytes(&env, &[b'x'; (MAX_REASON_LEN + 1) as usize]);
+        client.remove_whitelisted_with_reason(&user, &reason);
+    }
+
+    #[test]
+    fn whitelist_removal_bumps_principal_epoch() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let partner = Address::generate(&env);
+        assert_eq!(client.get_principal_epoch(&partner), 0);
+
+        client.whitelist_address(&partner);
+        assert_eq!(client.get_principal_epoch(&partner), 0);
+        client.remove_from_whitelist(&partner);
+        assert_eq!(client.get_principal_epoch(&partner), 1);
+
+        client.whitelist_address(&partner);
+        client.remove_whitelisted_with_reason(&partner, &String::from_str(&env, "audit"));
+        assert_eq!(client.get_principal_epoch(&partner), 2);
+    }
+
+    #[test]
+    fn removing_a_non_member_leaves_epoch_and_record_alone() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let stranger = Address::generate(&env);
+        client.remove_from_whitelist(&stranger);
+        assert_eq!(client.get_principal_epoch(&stranger), 0);
+        assert_eq!(client.get_last_removal(&stranger), None);
+
+        let partner = Address::generate(&env);
+        client.whitelist_address(&partner);
+        client.remove_from_whitelist(&partner);
+        // Repeating the removal is a no-op
+        client.remove_from_whitelist(&partner);
+        assert_eq!(client.get_principal_epoch(&partner), 1);
+    }
+
+    // ---------- AUDIT ----------
+
+    #[test]
+    fn audit_documents_reports_each_variant_in_order() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let good = String::from_str(&env, "Q1-Report.pdf");
+        let renamed = String::from_str(&env, "Q1-Report-final.pdf");
+        client.store_document(&owner, &good, &hash_n(&env, 1));
+        client.store_document(&owner, &good, &hash_n(&env, 2));
+        client.store_document(&owner, &good, &hash_n(&env, 3));
+        client.revoke_document(&owner, &hash_n(&env, 3));
+
+        let entry = |n: u32, expected: &String| AuditEntry {
+            hash: hash_n(&env, n),
+            expected_name: expected.clone(),
+        };
+        let manifest = Vec::from_array(
+            &env,
+            [
+                entry(1, &good),
+                entry(9, &good),
+                entry(2, &renamed),
+                entry(3, &good),
+            ],
+        );
+        let results = client.audit_documents(&manifest);
+        assert_eq!(
+            results,
+            Vec::from_array(
+                &env,
+                [
+                    AuditResult::Ok,
+                    AuditResult::NotFound,
+                    AuditResult::NameMismatch,
+                    AuditResult::Revoked,
+                ]
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "batch too large")]
+    fn audit_documents_over_cap_panics() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+
+        let mut manifest = Vec::new(&env);
+        for i in 0..=DEFAULT_MAX_BATCH_READ {
+            manifest.push_back(AuditEntry {
+                hash: hash_n(&env, i),
+                expected_name: String::from_str(&env, "x"),
+            });
+        }
+        client.audit_documents(&manifest);
+    }
+
+    // ---------- CHECKPOINTS ----------
+
+    #[test]
+    fn publish_and_read_checkpoints() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        assert_eq!(client.latest_checkpoint(), None);
+
+        let name = String::from_str(&env, "Doc.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.store_document(&owner, &name, &hash_n(&env, 2));
+
+        let root = BytesN::from_array(&env, &[7u8; 32]);
+        let note = String::from_str(&env, "Q1 close");
+        let id = client.publish_checkpoint(&root, &2, &note);
+        assert_eq!(id, 1);
+
+        let cp = client.get_checkpoint(&1).expect("checkpoint 1");
+        assert_eq!(cp.root, root);
+        assert_eq!(cp.doc_count, 2);
+        assert_eq!(cp.note, note);
+        assert_eq!(cp.timestamp, 1_800_000_000);
+        assert_eq!(cp.last_event_seq, client.last_event_seq());
+
+        client.store_document(&owner, &name, &hash_n(&env, 3));
+        let root2 = BytesN::from_array(&env, &[8u8; 32]);
+        assert_eq!(client.publish_checkpoint(&root2, &3, &note), 2);
+        assert_eq!(client.latest_checkpoint().unwrap().root, root2);
+        assert_eq!(client.get_checkpoint(&1).unwrap().root, root);
+        assert_eq!(client.get_checkpoint(&3), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "document count mismatch")]
+    fn checkpoint_with_wrong_count_panics() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Doc.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let root = BytesN::from_array(&env, &[7u8; 32]);
+        client.publish_checkpoint(&root, &2, &String::from_str(&env, ""));
+    }
+
+    // ---------- BACKDATED IMPORTS ----------
+
+    #[test]
+    fn backdated_document_keeps_both_times() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Notarized-2019.pdf");
+        client.store_document_backdated(&name, &hash_n(&env, 1), &1_550_000_000);
+
+        let stored = client.read_document(&hash_n(&env, 1)).unwrap();
+        assert_eq!(stored.timestamp, 1_550_000_000);
+        assert_eq!(stored.added_by, owner);
+        assert_eq!(client.document_registered_at(&hash_n(&env, 1)), Some(1_800_000_000));
+
+        let verified = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert_eq!(verified.timestamp, 1_550_000_000);
+        assert_eq!(verified.registered_at, 1_800_000_000);
+
+        // regular stores report identical times
+        client.store_document(&owner, &name, &hash_n(&env, 2));
+        let regular = client.verify_document(&hash_n(&env, 2)).unwrap();
+        assert_eq!(regular.registered_at, regular.timestamp);
+    }
+
+    #[test]
+    #[should_panic(expected = "timestamp in the future")]
+    fn backdated_document_rejects_future_timestamp() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Tomorrow.pdf");
+        client.store_document_backdated(&name, &hash_n(&env, 1), &1_800_000_001);
+    }
+
+    #[test]
+    #[should_panic] // only the owner's signature is accepted
+    fn backdated_document_rejects_whitelisted_signer() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let name = String::from_str(&env, "Sneaky.pdf");
+        env.mock_auths(&[MockAuth {
+            address: &user,
+            invoke: &MockAuthInvoke {
+                contract: &contract_addr,
+                fn_name: "store_document_backdated",
+                args: (name.clone(), hash_n(&env, 1), 1_000_000_000u64).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        client.store_document_backdated(&name, &hash_n(&env, 1), &1_000_000_000);
+    }
+
+    #[test]
+    fn daily_store_limit_resets_at_epoch_boundary() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.set_config(&Config {
+            daily_store_limit: 2,
+            ..client.get_config()
+        });
+
+        let name = String::from_str(&env, "Daily.pdf");
+        let day = client.current_epoch();
+        client.store_document(&user, &name, &hash_n(&env, 1));
+        client.store_document(&user, &name, &hash_n(&env, 2));
+        assert_eq!(client.epoch_store_count(&user, &day), 2);
+        assert!(client.try_store_document(&user, &name, &hash_n(&env, 3)).is_err());
+        assert!(client.try_store_documents(&user, &inputs(&env, 3, 1)).is_err());
+
+        // Owner is exempt
+        client.store_document(&owner, &name, &hash_n(&env, 4));
+
+        advance_time(&env, 86_400);
+        assert_eq!(client.current_epoch(), day + 1);
+        client.store_document(&user, &name, &hash_n(&env, 3));
+        assert_eq!(client.epoch_store_count(&user, &(day + 1)), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "daily limit reached")]
+    fn daily_store_limit_counts_whole_batch() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.set_config(&Config {
+            daily_store_limit: 2,
+            ..client.get_config()
+        });
+
+        client.store_documents(&user, &inputs(&env, 1, 3));
+    }
+
+    #[test]
+    fn prune_epoch_counters_removes_past_buckets_only() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.set_config(&Config {
+            daily_store_limit: 5,
+            ..client.get_config()
+        });
+
+        let name = String::from_str(&env, "Daily.pdf");
+        let day = client.current_epoch();
+        client.store_document(&user, &name, &hash_n(&env, 1));
+        advance_time(&env, 86_400);
+        client.store_document(&user, &name, &hash_n(&env, 2));
+
+        let mut current = Vec::new(&env);
+        current.push_back(day + 1);
+        assert!(client.try_prune_epoch_counters(&owner, &user, &current).is_err());
+
+        let mut past = Vec::new(&env);
+        past.push_back(day);
+        client.prune_epoch_counters(&owner, &user, &past);
+        assert_eq!(client.epoch_store_count(&user, &day), 0);
+        assert_eq!(client.epoch_store_count(&user, &(day + 1)), 1);
+    }
+
+    #[test]
+    fn get_actor_stats_matches_individual_getters() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.set_config(&Config {
+            daily_store_limit: 5,
+            ..client.get_config()
+        });
+
+        let name = String::from_str(&env, "Stats.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
+        advance_time(&env, 30);
+        client.store_documents(&user, &inputs(&env, 2, 2));
+
+        let stats = client.get_actor_stats(&user);
+        let epoch = client.current_epoch();
+        assert_eq!(
+            stats,
+            ActorStats {
+                documents_stored: client.actor_document_count(&user),
+                last_store_at: Some(env.ledger().timestamp()),
+                remaining_daily_quota: Some(5 - client.epoch_store_count(&user, &epoch)),
+                whitelisted: client.is_whitelisted(&user),
+            }
+        );
+        assert_eq!(stats.documents_stored, 3);
+        assert_eq!(stats.remaining_daily_quota, Some(2));
+    }
+
+    #[test]
+    fn get_actor_stats_unknown_address_is_empty() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+
+        let stranger = Address::generate(&env);
+        assert_eq!(
+            client.get_actor_stats(&stranger),
+            ActorStats {
+                documents_stored: 0,
+                last_store_at: None,
+                remaining_daily_quota: None,
+                whitelisted: false,
+            }
+        );
+    }
+
+    #[test]
+    fn decommission_blocks_writes_and_surfaces_successor() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Legacy.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        assert_eq!(client.get_successor(), None);
+        assert!(!client.verify_document(&hash_n(&env, 1)).unwrap().deprecated);
+
+        let successor = Address::generate(&env);
+        client.decommission(&successor);
+        assert_eq!(client.get_successor(), Some(successor.clone()));
+
+        let verified = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert!(verified.verified_document);
+        assert!(verified.deprecated);
+        assert_eq!(verified.successor, Some(successor));
+        assert!(client.read_document(&hash_n(&env, 1)).is_some());
+
+        let user = Address::generate(&env);
+        assert!(client.try_store_document(&owner, &name, &hash_n(&env, 2)).is_err());
+        assert!(client.try_store_documents(&owner, &inputs(&env, 2, 1)).is_err());
+        assert!(client.try_whitelist_address(&user).is_err());
+        assert!(client.try_remove_from_whitelist(&user).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "registry decommissioned")]
+    fn decommission_twice_fails() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.decommission(&Address::generate(&env));
+        client.decommission(&Address::generate(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "registry sealed")]
+    fn decommission_after_seal_panics() {
+        let env = Env::default();
+        let (_owner, client) = sealed_setup(&env);
+        client.decommission(&Address::generate(&env));
+    }
+
+    fn fee_token(env: &Env, holder: &Address, balance: i128) -> Address {
+        let issuer = Address::generate(env);
+        let token = env.register_stellar_asset_contract_v2(issuer).address();
+        token::StellarAssetClient::new(env, &token).mint(holder, &balance);
+        token
+    }
+
+    #[test]
+    fn category_fee_overrides_global_fee() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let token = fee_token(&env, &user, 1_000);
+        let balance = token::Client::new(&env, &token);
+
+        client.set_config(&Config {
+            registration_fee: FeeSetting::Fixed(Fee { token: token.clone(), amount: 10 }),
+            ..client.get_config()
+        });
+        let finance = symbol_short!("finance");
+        let memo = symbol_short!("memo");
+        client.set_category_fee(&finance, &token, &100);
+        assert_eq!(client.get_category_fee(&finance).unwrap().amount, 100);
+        assert_eq!(client.get_category_fee(&memo).unwrap().amount, 10);
+
+        let name = String::from_str(&env, "Ledger.pdf");
+        client.store_document_categorized(&user, &name, &hash_n(&env, 1), &finance);
+        assert_eq!(balance.balance(&user), 900);
+        assert_eq!(balance.balance(&owner), 100);
+
+        client.store_document_categorized(&user, &name, &hash_n(&env, 2), &memo);
+        assert_eq!(balance.balance(&user), 890);
+        assert_eq!(client.document_category(&hash_n(&env, 2)), Some(memo));
+
+        client.store_document(&user, &name, &hash_n(&env, 3));
+        assert_eq!(balance.balance(&user), 880);
+    }
+
+    #[test]
+    fn zero_category_fee_is_free() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let token = fee_token(&env, &user, 50);
+
+        client.set_config(&Config {
+            registration_fee: FeeSetting::Fixed(Fee { token: token.clone(), amount: 10 }),
+            ..client.get_config()
+        });
+        let internal = symbol_short!("internal");
+        client.set_category_fee(&internal, &token, &0);
+
+        let name = String::from_str(&env, "Memo.txt");
+        client.store_document_categorized(&user, &name, &hash_n(&env, 1), &internal);
+        assert_eq!(token::Client::new(&env, &token).balance(&user), 50);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid fee")]
+    fn negative_category_fee_rejected() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let token = Address::generate(&env);
+        client.set_category_fee(&symbol_short!("finance"), &token, &-1);
+    }
+
+    #[test]
+    fn free_quota_then_paid_at_exact_boundary() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let token = fee_token(&env, &user, 1_000);
+        let balance = token::Client::new(&env, &token);
+        client.set_config(&Config {
+            registration_fee: FeeSetting::Fixed(Fee { token: token.clone(), amount: 10 }),
+            free_quota_per_actor: 2,
+            ..client.get_config()
+        });
+
+        let name = String::from_str(&env, "Onboard.pdf");
+        let free_topic: Vec<Val> =
+            (symbol_short!("fee"), symbol_short!("free"), user.clone()).into_val(&env);
+        client.store_document(&user, &name, &hash_n(&env, 1));
+        client.store_document(&user, &name, &hash_n(&env, 2));
+        assert!(env.events().all().iter().any(|(_, topics, _)| topics == free_topic));
+        assert_eq!(balance.balance(&user), 1_000);
+
+        client.store_document(&user, &name, &hash_n(&env, 3));
+        assert_eq!(balance.balance(&user), 990);
+    }
+
+    #[test]
+    fn free_quota_splits_batch_and_is_not_retroactive() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let token = fee_token(&env, &user, 1_000);
+        let balance = token::Client::new(&env, &token);
+        client.set_config(&Config {
+            registration_fee: FeeSetting::Fixed(Fee { token: token.clone(), amount: 10 }),
+            free_quota_per_actor: 1,
+            ..client.get_config()
+        });
+
+        client.store_documents(&user, &inputs(&env, 1, 3));
+        assert_eq!(balance.balance(&user), 980);
+
+        // Shrinking the quota doesn't charge for past free stores
+        client.set_config(&Config {
+            free_quota_per_actor: 0,
+            ..client.get_config()
+        });
+        assert_eq!(balance.balance(&user), 980);
+        client.store_documents(&user, &inputs(&env, 4, 1));
+        assert_eq!(balance.balance(&user), 970);
+    }
+
+    #[test]
+    fn verify_document_detailed_enumerates_outcomes() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let name = String::from_str(&env, "Outcome.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
+        client.store_document(&user, &name, &hash_n(&env, 2));
+        client.store_document(&user, &name, &hash_n(&env, 3));
+        client.revoke_document(&user, &hash_n(&env, 1));
+        client.remove_and_quarantine(&user, &0);
+        client.clear_quarantine(&hash_n(&env, 3));
+
+        let status_of = |n: u32| match client.verify_document_detailed(&hash_n(&env, n)) {
+            VerificationOutcome::Found(v) => Some((v.status, v.verified_document)),
+            VerificationOutcome::NotFound => None,
+        };
+        assert_eq!(status_of(1), Some((DocumentStatus::Revoked, false)));
+        assert_eq!(status_of(2), Some((DocumentStatus::Quarantined, false)));
+        assert_eq!(status_of(3), Some((DocumentStatus::Active, true)));
+        assert_eq!(status_of(4), None);
+        assert_eq!(
+            client.verify_document_detailed(&hash_n(&env, 4)),
+            VerificationOutcome::NotFound
+        );
+    }
+
+    #[test]
+    fn verify_document_reports_liveness() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.set_config(&Config {
+            ttl_warning_ledgers: DOC_BUMP_AMOUNT - 1_000,
+            ..client.get_config()
+        });
+        let name = String::from_str(&env, "Longterm.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let verified = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert!(!verified.near_expiry);
+        assert_eq!(
+            verified.live_until_ledger,
+            env.ledger().sequence() + doc_ttl(&env, &contract_addr, &hash_n(&env, 1))
+        );
+
+        advance_ledgers(&env, 1_000);
+        assert!(client.verify_document(&hash_n(&env, 1)).unwrap().near_expiry);
+
+        client.pin_document(&hash_n(&env, 1));
+        let hashes = Vec::from_array(&env, [hash_n(&env, 1)]);
+        client.bump_pinned(&owner, &hashes, &(2 * DOC_BUMP_AMOUNT));
+        let verified = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert!(!verified.near_expiry);
+        assert_eq!(verified.live_until_ledger, env.ledger().sequence() + 2 * DOC_BUMP_AMOUNT);
+    }
+
+    #[test]
+    fn embargoed_name_unlocks_at_time() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Launch-Press.pdf");
+        let unlock_at = env.ledger().timestamp() + 3_600;
+        client.store_embargoed_document(&owner, &name, &hash_n(&env, 1), &unlock_at);
+        assert_eq!(client.embargo_until(&hash_n(&env, 1)), Some(unlock_at));
+
+        let empty = String::from_str(&env, "");
+        let verified = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert!(verified.embargoed);
+        assert!(verified.verified_document);
+        assert_eq!(client.read_document(&hash_n(&env, 1)).unwrap().name, empty);
+
+        advance_time(&env, 3_599);
+        assert!(client.verify_document(&hash_n(&env, 1)).unwrap().embargoed);
+        advance_time(&env, 1);
+        let verified = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert!(!verified.embargoed);
+        assert_eq!(client.read_document(&hash_n(&env, 1)).unwrap().name, name);
+    }
+
+    #[test]
+    fn lift_embargo_reveals_name_early() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+
+        let name = String::from_str(&env, "Results.pdf");
+        let unlock_at = env.ledger().timestamp() + 86_400;
+        client.store_embargoed_document(&user, &name, &hash_n(&env, 1), &unlock_at);
+        assert!(client.try_lift_embargo(&hash_n(&env, 2)).is_err());
+
+        client.lift_embargo(&hash_n(&env, 1));
+        assert_eq!(client.embargo_until(&hash_n(&env, 1)), None);
+        assert_eq!(client.read_document(&hash_n(&env, 1)).unwrap().name, name);
+    }
+
+    #[test]
+    #[should_panic(expected = "unlock time must be in the future")]
+    fn embargo_in_the_past_rejected() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Late.pdf");
+        let now = env.ledger().timestamp();
+        client.store_embargoed_document(&owner, &name, &hash_n(&env, 1), &now);
+    }
+
+    #[test]
+    fn split_storage_read_paths_agree() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Split.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let full = client.read_document(&hash_n(&env, 1)).unwrap();
+        let verified = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert_eq!(full.name, name);
+        assert_eq!(
+            (verified.hash, verified.timestamp, verified.added_by),
+            (full.hash, full.timestamp, full.added_by)
+        );
+
+        env.as_contract(&contract_addr, || {
+            let storage = env.storage().persistent();
+            let core: CompactCore = storage.get(&DataKey::Document(hash_n(&env, 1))).unwrap();
+            let ext: DocumentExt = storage.get(&DataKey::DocumentExt(hash_n(&env, 1))).unwrap();
+            assert_eq!(Contract::actor_by_id(env.clone(), core.actor), Some(owner.clone()));
+            assert_eq!(ext.name, name);
+        });
+    }
+
+    #[test]
+    fn legacy_documents_read_and_migrate() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Legacy.pdf");
+        let legacy = LegacyDocument {
+            name: name.clone(),
+            hash: hash_n(&env, 1),
+            timestamp: 1_700_000_000,
+            added_by: owner.clone(),
+        };
+        env.as_contract(&contract_addr, || {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Document(hash_n(&env, 1)), &legacy);
+        });
+
+        let check = || {
+            let full = client.read_document(&hash_n(&env, 1)).unwrap();
+            let verified = client.verify_document(&hash_n(&env, 1)).unwrap();
+            let summary = client.read_document_summary(&hash_n(&env, 1)).unwrap();
+            assert_eq!(full.name, name);
+            assert_eq!(full.timestamp, 1_700_000_000);
+            assert_eq!(full.sequence, 0);
+            assert_eq!(verified.sequence, 0);
+            assert_eq!(verified.timestamp, full.timestamp);
+            assert_eq!(verified.added_by, full.added_by);
+            assert_eq!(summary.timestamp, full.timestamp);
+        };
+        check();
+
+        let hashes = Vec::from_array(&env, [hash_n(&env, 1), hash_n(&env, 2)]);
+        assert_eq!(client.migrate_documents(&hashes), 1);
+        assert_eq!(client.migrate_documents(&hashes), 0);
+        check();
+        env.as_contract(&contract_addr, || {
+            let core: Option<CompactCore> =
+                env.storage().persistent().get(&DataKey::Document(hash_n(&env, 1)));
+            assert_eq!(core.unwrap().timestamp, 1_700_000_000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "registry sealed")]
+    fn migrate_documents_after_seal_panics() {
+        let env = Env::default();
+        let (_owner, client) = sealed_setup(&env);
+        client.migrate_documents(&Vec::from_array(&env, [hash_n(&env, 1)]));
+    }
+
+    #[test]
+    fn legacy_namespace_entries_still_read() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let ns = symbol_short!("client_a");
+        client.create_namespace(&ns, &owner);
+        let legacy = LegacyDocument {
+            name: String::from_str(&env, "Legacy.pdf"),
+            hash: hash_n(&env, 1),
+            timestamp: 1_700_000_000,
+            added_by: owner.clone(),
+        };
+        env.as_contract(&contract_addr, || {
+            env.storage()
+                .persistent()
+                .set(&DataKey::NsDocument(ns.clone(), hash_n(&env, 1)), &legacy);
+        });
+
+        let doc = client.read_document_ns(&ns, &hash_n(&env, 1)).unwrap();
+        assert_eq!((doc.name, doc.sequence), (legacy.name, 0));
+    }
+
+    #[test]
+    fn delete_own_document_removes_ext_entry() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Gone.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.delete_own_document(&owner, &hash_n(&env, 1));
+        env.as_contract(&contract_addr, || {
+            assert!(!env
+                .storage()
+                .persistent()
+                .has(&DataKey::DocumentExt(hash_n(&env, 1))));
+        });
+    }
+
+    #[test]
+    fn actor_ids_are_distinct_and_round_trip() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        assert_eq!(client.actor_id_of(&user), None);
+
+        let name = String::from_str(&env, "Interned.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.store_document(&user, &name, &hash_n(&env, 2));
+        client.store_document(&user, &name, &hash_n(&env, 3));
+
+        let owner_id = client.actor_id_of(&owner).unwrap();
+        let user_id = client.actor_id_of(&user).unwrap();
+        assert_ne!(owner_id, user_id);
+        assert_eq!(client.actor_by_id(&owner_id), Some(owner.clone()));
+        assert_eq!(client.actor_by_id(&user_id), Some(user.clone()));
+
+        assert_eq!(client.read_document(&hash_n(&env, 1)).unwrap().added_by, owner);
+        assert_eq!(client.verify_document(&hash_n(&env, 2)).unwrap().added_by, user);
+        assert_eq!(client.read_document_summary(&hash_n(&env, 3)).unwrap().added_by, user);
+    }
+
+    #[test]
+    fn address_bearing_core_migrates_to_actor_id() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let submitter = Address::generate(&env);
+        let name = String::from_str(&env, "PreIntern.pdf");
+        env.as_contract(&contract_addr, || {
+            let storage = env.storage().persistent();
+            let core = DocumentCore {
+                hash: hash_n(&env, 1),
+                timestamp: 1_750_000_000,
+                added_by: submitter.clone(),
+            };
+            storage.set(&DataKey::Document(hash_n(&env, 1)), &core);
+            let ext = DocumentExt { name: name.clone() };
+            storage.set(&DataKey::DocumentExt(hash_n(&env, 1)), &ext);
+        });
+        assert_eq!(client.read_document(&hash_n(&env, 1)).unwrap().added_by, submitter);
+
+        let hashes = Vec::from_array(&env, [hash_n(&env, 1)]);
+        assert_eq!(client.migrate_documents(&hashes), 1);
+        let id = client.actor_id_of(&submitter).expect("interned on migration");
+        env.as_contract(&contract_addr, || {
+            let core: CompactCore =
+                env.storage().persistent().get(&DataKey::Document(hash_n(&env, 1))).unwrap();
+            assert_eq!(core.actor, id);
+        });
+        let doc = client.read_document(&hash_n(&env, 1)).unwrap();
+        assert_eq!((doc.name, doc.added_by, doc.timestamp), (name, submitter, 1_750_000_000));
+    }
+
+    #[test]
+    fn document_pages_survive_deletion_between_fetches() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.store_documents(&owner, &inputs(&env, 1, 6));
+        let first = client.list_documents_page(&0, &3);
+        assert_eq!(first.items.len(), 3);
+        assert_eq!(first.next_cursor, Some(3));
+
+        // Delete one already seen and one not yet seen
+        client.delete_own_document(&owner, &hash_n(&env, 2));
+        client.delete_own_document(&owner, &hash_n(&env, 5));
+
+        let second = client.list_documents_page(&first.next_cursor.unwrap(), &3);
+        assert_eq!(second.next_cursor, None);
+        let seen: std::vec::Vec<String> = first
+            .items
+            .iter()
+            .chain(second.items.iter())
+            .map(|s| s.hash)
+            .collect();
+        let expected: std::vec::Vec<String> =
+            [1, 2, 3, 4, 6].iter().map(|n| hash_n(&env, *n)).collect();
+        assert_eq!(seen, expected);
+
+        let by_actor = client.list_documents_by_actor_page(&owner, &3, &10);
+        assert_eq!(by_actor.items.len(), 2);
+        assert_eq!(by_actor.next_cursor, None);
+    }
+
+    #[test]
+    fn stale_cursor_returns_empty_page() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.store_documents(&owner, &inputs(&env, 1, 2));
+        let page = client.list_documents_page(&10, &5);
+        assert!(page.items.is_empty());
+        assert_eq!(page.next_cursor, None);
+        assert!(client.list_documents_by_actor_page(&owner, &10, &5).items.is_empty());
+        assert!(client.list_whitelist_page(&10, &5).items.is_empty());
+    }
+
+    #[test]
+    fn whitelist_pages_skip_removed_addresses() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let mut users = std::vec::Vec::new();
+        for _ in 0..4 {
+            let user = Address::generate(&env);
+            client.whitelist_address(&user);
+            users.push(user);
+        }
+        // Re-whitelisting doesn't add a second slot
+        client.whitelist_address(&users[0]);
+
+        let addresses = |page: &WhitelistPage| {
+            let mut out = Vec::new(&env);
+            for entry in page.items.iter() {
+                out.push_back(entry.address);
+            }
+            out
+        };
+        let first = client.list_whitelist_page(&0, &2);
+        assert_eq!(addresses(&first), Vec::from_array(&env, [users[0].clone(), users[1].clone()]));
+        client.remove_from_whitelist(&users[2]);
+
+        let second = client.list_whitelist_page(&first.next_cursor.unwrap(), &2);
+        assert_eq!(addresses(&second), Vec::from_array(&env, [users[3].clone()]));
+        assert_eq!(second.next_cursor, None);
+    }
+
+    #[test]
+    fn whitelist_cursor_survives_removal_of_an_earlier_page() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let mut users = std::vec::Vec::new();
+        for _ in 0..4 {
+            let user = Address::generate(&env);
+            client.whitelist_address(&user);
+            users.push(user);
+        }
+
+        let first = client.list_whitelist_page(&0, &2);
+        assert_eq!(first.next_cursor, Some(2));
+        client.remove_from_whitelist(&users[0]);
+
+        let second = client.list_whitelist_page(&2, &2);
+        let mut listed = Vec::new(&env);
+        for entry in second.items.iter() {
+            listed.push_back(entry.address);
+        }
+        assert_eq!(listed, Vec::from_array(&env, [users[2].clone(), users[3].clone()]));
+        assert_eq!(second.next_cursor, None);
+    }
+
+    #[test]
+    fn recent_documents_are_newest_first_across_actors() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        assert_eq!(client.latest_document(), None);
+
+        let name = String::from_str(&env, "Recent.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.store_document(&user, &name, &hash_n(&env, 2));
+        client.store_document(&owner, &name, &hash_n(&env, 3));
+        client.store_document(&user, &name, &hash_n(&env, 4));
+
+        let recent = client.list_recent_documents(&3);
+        let hashes: std::vec::Vec<String> = recent.iter().map(|s| s.hash).collect();
+        assert_eq!(hashes, [4, 3, 2].map(|n| hash_n(&env, n)).to_vec());
+        assert_eq!(recent.get(0).unwrap().added_by, user);
+        assert_eq!(recent.get(1).unwrap().added_by, owner);
+
+        assert!(
+            client.document_sequence(&hash_n(&env, 1)).unwrap()
+                < client.document_sequence(&hash_n(&env, 4)).unwrap()
+        );
+        assert_eq!(client.document_sequence(&hash_n(&env, 9)), None);
+
+        client.delete_own_document(&user, &hash_n(&env, 4));
+        assert_eq!(client.latest_document().unwrap().hash, hash_n(&env, 3));
+    }
+
+    #[test]
+    fn activity_fields_advance_independently() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        assert_eq!(client.get_activity(), ActivityInfo::default());
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let after_whitelist = client.get_activity();
+        assert_eq!(after_whitelist.last_whitelist_change_at, 1_800_000_000);
+        assert_eq!(after_whitelist.last_store_at, 0);
+        assert_eq!(after_whitelist.last_ownership_change_at, 0);
+
+        advance_time(&env, 10);
+        let name = String::from_str(&env, "Active.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let after_store = client.get_activity();
+        assert_eq!(after_store.last_store_at, 1_800_000_010);
+        assert_eq!(after_store.last_whitelist_change_at, 1_800_000_000);
+        assert_eq!(after_store.last_ownership_change_at, 0);
+
+        advance_time(&env, 10);
+        client.propose_ownership(&user);
+        client.accept_ownership();
+        let after_transfer = client.get_activity();
+        assert_eq!(after_transfer.last_ownership_change_at, 1_800_000_020);
+        assert_eq!(after_transfer.last_store_at, 1_800_000_010);
+        assert_eq!(after_transfer.last_whitelist_change_at, 1_800_000_000);
+    }
+
+    #[test]
+    fn get_stats_matches_individual_counters() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        client.whitelist_address(&alice);
+        client.whitelist_address(&bob);
+        client.whitelist_address(&bob);
+        client.store_documents(&owner, &inputs(&env, 1, 4));
+        client.revoke_document(&owner, &hash_n(&env, 1));
+        client.revoke_document(&owner, &hash_n(&env, 2));
+        client.delete_own_document(&owner, &hash_n(&env, 2));
+        client.remove_from_whitelist(&alice);
+        client.remove_from_whitelist(&alice);
+
+        let stats = client.get_stats();
+        assert_eq!(
+            stats,
+            RegistryStats {
+                document_count: client.document_count(),
+                whitelisted_count: client.whitelisted_count(),
+                revoked_count: client.revoked_count(),
+                sealed: client.is_sealed(),
+                decommissioned: client.get_successor().is_some(),
+                schema_version: SCHEMA_VERSION,
+            }
+        );
+        assert_eq!(stats.document_count, 3);
+        assert_eq!(stats.whitelisted_count, 1);
+        assert_eq!(stats.revoked_count, 1);
+        assert!(!stats.sealed);
+
+        client.seal_registry();
+        assert!(client.get_stats().sealed);
+    }
+
+    #[test]
+    fn quarantine_document_full_cycle() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Suspicious.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let note = String::from_str(&env, "hash reported by partner");
+        client.quarantine_document(&hash_n(&env, 1), &note);
+
+        let verified = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert_eq!(verified.status, DocumentStatus::Quarantined);
+        assert!(!verified.verified_document);
+        assert_eq!(client.read_document(&hash_n(&env, 1)).unwrap().name, name);
+        assert_eq!(
+            client.get_quarantine(&hash_n(&env, 1)),
+            Some(QuarantineRecord {
+                note: note.clone(),
+                quarantined_at: 1_800_000_000,
+            })
+        );
+        assert!(client.try_quarantine_document(&hash_n(&env, 1), &note).is_err());
+
+        client.clear_quarantine(&hash_n(&env, 1));
+        assert!(client.verify_document(&hash_n(&env, 1)).unwrap().verified_document);
+        assert_eq!(client.get_quarantine(&hash_n(&env, 1)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "document revoked")]
+    fn quarantine_revoked_document_fails() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Gone.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.revoke_document(&owner, &hash_n(&env, 1));
+        client.quarantine_document(&hash_n(&env, 1), &String::from_str(&env, "late"));
+    }
+
+    #[test]
+    fn veto_window_pending_then_verified() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.set_config(&Config {
+            veto_window_secs: 3_600,
+            ..client.get_config()
+        });
+
+        let name = String::from_str(&env, "Submitted.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
+        client.store_document(&owner, &name, &hash_n(&env, 2));
+        let pending = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert_eq!(pending.status, DocumentStatus::Pending);
+        assert!(!pending.verified_document);
+        assert_eq!(client.veto_window_end(&hash_n(&env, 1)), Some(1_800_003_600));
+
+        // Owner submissions skip the window
+        assert!(client.verify_document(&hash_n(&env, 2)).unwrap().verified_document);
+        assert_eq!(client.veto_window_end(&hash_n(&env, 2)), None);
+
+        advance_time(&env, 3_600);
+        let verified = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert_eq!(verified.status, DocumentStatus::Active);
+        assert!(verified.verified_document);
+        let reason = String::from_str(&env, "too late");
+        assert!(client.try_veto_document(&hash_n(&env, 1), &reason).is_err());
+    }
+
+    #[test]
+    fn veto_within_window_is_permanent() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.set_config(&Config {
+            veto_window_secs: 3_600,
+            ..client.get_config()
+        });
+
+        let name = String::from_str(&env, "Dubious.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
+        advance_time(&env, 3_599);
+        let reason = String::from_str(&env, "wrong counterparty");
+        client.veto_document(&hash_n(&env, 1), &reason);
+        assert_eq!(
+            client.get_veto(&hash_n(&env, 1)),
+            Some(VetoRecord {
+                reason,
+                vetoed_at: 1_800_003_599,
+            })
+        );
+
+        advance_time(&env, 10);
+        let vetoed = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert_eq!(vetoed.status, DocumentStatus::Vetoed);
+        assert!(!vetoed.verified_document);
+    }
+
+    #[test]
+    #[should_panic(expected = "veto window closed")]
+    fn veto_after_window_fails() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.set_config(&Config {
+            veto_window_secs: 60,
+            ..client.get_config()
+        });
+
+        let name = String::from_str(&env, "Late.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
+        advance_time(&env, 60);
+        client.veto_document(&hash_n(&env, 1), &String::from_str(&env, "late"));
+    }
+
+    #[test]
+    fn rotated_key_inherits_document_control() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let old = Address::generate(&env);
+        let new = Address::generate(&env);
+        client.whitelist_address(&old);
+
+        let name = String::from_str(&env, "Partner.pdf");
+        client.store_document(&old, &name, &hash_n(&env, 1));
+        client.rotate_whitelisted_address(&old, &new);
+
+        assert!(client.is_whitelisted(&new));
+        assert!(!client.is_whitelisted(&old));
+        assert_eq!(client.rotated_from(&new), Some(old.clone()));
+        assert!(client.try_revoke_document(&old, &hash_n(&env, 1)).is_err());
+
+        client.revoke_document(&new, &hash_n(&env, 1));
+        assert_eq!(client.document_status(&hash_n(&env, 1)), Some(DocumentStatus::Revoked));
+        assert_eq!(client.read_document(&hash_n(&env, 1)).unwrap().added_by, old);
+    }
+
+    #[test]
+    #[should_panic(expected = "address not whitelisted")]
+    fn rotate_unknown_address_fails() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.rotate_whitelisted_address(&Address::generate(&env), &Address::generate(&env));
+    }
+
+    #[test]
+    fn owner_note_hidden_from_non_owner() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+
+        let name = String::from_str(&env, "Invoice.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
+        let note = String::from_str(&env, "customer disputed invoice, see ticket 4521");
+        client.set_owner_note(&hash_n(&env, 1), &note);
+
+        assert_eq!(client.get_owner_note(&owner, &hash_n(&env, 1)), Some(note));
+        assert_eq!(client.get_owner_note(&user, &hash_n(&env, 1)), None);
+
+        let updated = String::from_str(&env, "resolved");
+        client.set_owner_note(&hash_n(&env, 1), &updated);
+        assert_eq!(client.get_owner_note(&owner, &hash_n(&env, 1)), Some(updated));
+
+        client.clear_owner_note(&hash_n(&env, 1));
+        assert_eq!(client.get_owner_note(&owner, &hash_n(&env, 1)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "note too long")]
+    fn owner_note_length_bounded() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Memo.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let long = String::from_bytes(&env, &[b'x'; MAX_NOTE_LEN as usize + 1]);
+        client.set_owner_note(&hash_n(&env, 1), &long);
+    }
+
+    #[test]
+    fn owner_note_frozen_by_seal() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Memo.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let note = String::from_str(&env, "keep");
+        client.set_owner_note(&hash_n(&env, 1), &note);
+        client.seal_registry();
+
+        let other = String::from_str(&env, "changed");
+        assert!(client.try_set_owner_note(&hash_n(&env, 1), &other).is_err());
+        assert!(client.try_clear_owner_note(&hash_n(&env, 1)).is_err());
+        assert_eq!(client.get_owner_note(&owner, &hash_n(&env, 1)), Some(note));
+    }
+
+    #[test]
+    fn multihash_lookup_by_either_digest() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "CrossChain.pdf");
+        let sha = hash_n(&env, 1);
+        let keccak = hash_n(&env, 2);
+        client.store_document_multihash(&owner, &name, &sha, &keccak);
+
+        let by_sha = client.read_document(&sha).unwrap();
+        let by_keccak = client.read_document(&keccak).unwrap();
+        assert_eq!(by_keccak.hash, sha);
+        assert_eq!(
+            (by_sha.name, by_sha.hash, by_sha.timestamp, by_sha.added_by),
+            (by_keccak.name, by_keccak.hash, by_keccak.timestamp, by_keccak.added_by)
+        );
+        assert_eq!(client.verify_document(&sha), client.verify_document(&keccak));
+        assert_eq!(client.document_count(), 1);
+    }
+
+    #[test]
+    fn multihash_rejects_alias_collisions() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Collide.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.store_document_multihash(&owner, &name, &hash_n(&env, 2), &hash_n(&env, 3));
+
+        // alias equal to an existing document
+        let clash = client.try_store_document_multihash(
+            &owner,
+            &name,
+            &hash_n(&env, 4),
+            &hash_n(&env, 1),
+        );
+        assert!(clash.is_err());
+        // alias equal to an existing alias
+        let clash = client.try_store_document_multihash(
+            &owner,
+            &name,
+            &hash_n(&env, 4),
+            &hash_n(&env, 3),
+        );
+        assert!(clash.is_err());
+        // plain store under a digest that is already an alias
+        assert!(client.try_store_document(&owner, &name, &hash_n(&env, 3)).is_err());
+    }
+
+    #[test]
+    fn bundle_lifecycle_and_seal() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.store_documents(&user, &inputs(&env, 1, 3));
+
+        let id = String::from_str(&env, "closing-2026-10");
+        client.create_bundle(&user, &id, &String::from_str(&env, "Closing binder"));
+        client.add_to_bundle(&user, &id, &hash_n(&env, 1));
+        client.add_to_bundle(&user, &id, &hash_n(&env, 2));
+        assert!(client.try_add_to_bundle(&user, &id, &hash_n(&env, 2)).is_err());
+        assert!(client.try_add_to_bundle(&user, &id, &hash_n(&env, 9)).is_err());
+
+        advance_time(&env, 60);
+        client.seal_bundle(&user, &id);
+        let bundle = client.get_bundle(&id);
+        assert!(bundle.sealed);
+        assert_eq!(bundle.creator, user);
+        assert_eq!(bundle.created_at, 1_800_000_000);
+        assert_eq!(bundle.sealed_at, Some(1_800_000_060));
+        assert_eq!(bundle.members, Vec::from_array(&env, [hash_n(&env, 1), hash_n(&env, 2)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "bundle sealed")]
+    fn add_to_sealed_bundle_fails() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        client.store_documents(&owner, &inputs(&env, 1, 2));
+
+        let id = String::from_str(&env, "binder");
+        client.create_bundle(&owner, &id, &String::from_str(&env, "Binder"));
+        client.add_to_bundle(&owner, &id, &hash_n(&env, 1));
+        client.seal_bundle(&owner, &id);
+        client.add_to_bundle(&owner, &id, &hash_n(&env, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "not authorized: only bundle creator or owner")]
+    fn stranger_cannot_modify_bundle() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        client.store_documents(&owner, &inputs(&env, 1, 1));
+
+        let id = String::from_str(&env, "binder");
+        client.create_bundle(&owner, &id, &String::from_str(&env, "Binder"));
+        client.add_to_bundle(&Address::generate(&env), &id, &hash_n(&env, 1));
+    }
+
+    #[test]
+    fn witnesses_are_counted_once_each() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Public.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let witnesses = [
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        for w in witnesses.iter() {
+            client.witness_document(w, &hash_n(&env, 1));
+        }
+        assert!(client.try_witness_document(&witnesses[0], &hash_n(&env, 1)).is_err());
+
+        assert_eq!(client.witness_count(&hash_n(&env, 1)), 3);
+        assert_eq!(client.verify_document(&hash_n(&env, 1)).unwrap().witness_count, 3);
+        assert!(client.has_witnessed(&witnesses[1], &hash_n(&env, 1)));
+        assert!(!client.has_witnessed(&owner, &hash_n(&env, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "already witnessed")]
+    fn duplicate_witness_rejected() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Public.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let witness = Address::generate(&env);
+        client.witness_document(&witness, &hash_n(&env, 1));
+        client.witness_document(&witness, &hash_n(&env, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "registry sealed")]
+    fn witness_after_seal_panics() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Public.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.seal_registry();
+        client.witness_document(&Address::generate(&env), &hash_n(&env, 1));
+    }
+
+    #[test]
+    fn store_document_returns_receipt() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        // stays inside the instance entry's initial TTL
+        env.ledger().with_mut(|li| li.sequence_number = 2_424);
+
+        let name = String::from_str(&env, "Footer.pdf");
+        let receipt = client.store_document(&owner, &name, &hash_n(&env, 1));
+        assert_eq!(
+            receipt,
+            Receipt {
+                hash: hash_n(&env, 1),
+                name,
+                timestamp: 1_800_000_000,
+                ledger_sequence: 2_424,
+                contract: contract_addr.clone(),
+                event_seq: client.last_event_seq(),
+                created_at: None,
+                doc_id: client.document_id(&hash_n(&env, 1)),
+            }
+        );
+
+        advance_ledgers(&env, 10);
+        advance_time(&env, 5);
+        assert_eq!(client.get_receipt(&hash_n(&env, 1)), Some(receipt));
+        assert_eq!(client.get_receipt(&hash_n(&env, 2)), None);
+    }
+
+    // ---------- EVENT SCHEMA ----------
+
+    /// Assert the exact topics and data of the most recent event. Any change
+    /// here is a schema change and needs an `EVENT_SCHEMA_VERSION` bump.
+    fn assert_last_event(env: &Env, topics: Vec<Val>, payload: Val) {
+        let (contract, got_topics, got_data) = env.events().all().last().expect("an event");
+        assert_eq!(got_topics, topics);
+        let seq = ContractClient::new(env, &contract).last_event_seq();
+        let expected: Vec<Val> = (events::EVENT_SCHEMA_VERSION, seq, payload).into_val(env);
+        let got: Vec<Val> = Vec::try_from_val(env, &got_data).unwrap();
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn event_schema_version_is_pinned() {
+        assert_eq!(events::EVENT_SCHEMA_VERSION, 1);
+    }
+
+    #[test]
+    fn whitelist_event_shapes() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let topics = (symbol_short!("whitelist"), symbol_short!("added"), user.clone());
+        assert_last_event(&env, topics.into_val(&env), ().into_val(&env));
+
+        client.remove_from_whitelist(&user);
+        let topics = (symbol_short!("whitelist"), symbol_short!("removed"), user.clone());
+        let payload = (String::from_str(&env, ""), 1_800_000_000u64);
+        assert_last_event(&env, topics.into_val(&env), payload.into_val(&env));
+    }
+
+    #[test]
+    fn document_event_shapes() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "Shape.pdf"), &hash);
+        let topics = (symbol_short!("document"), symbol_short!("stored"), hash.clone());
+        assert_last_event(&env, topics.into_val(&env), owner.into_val(&env));
+
+        client.revoke_document(&owner, &hash);
+        let topics = (symbol_short!("document"), symbol_short!("revoked"), hash.clone());
+        assert_last_event(&env, topics.into_val(&env), owner.into_val(&env));
+    }
+
+    #[test]
+    fn owner_and_config_event_shapes() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let config = client.get_config();
+        client.set_config(&config);
+        let topics = (symbol_short!("config"), symbol_short!("updated"), owner.clone());
+        assert_last_event(&env, topics.into_val(&env), config.into_val(&env));
+
+        let new_owner = Address::generate(&env);
+        client.transfer_ownership(&new_owner);
+        let topics = (symbol_short!("ownership"), symbol_short!("transfer"), new_owner);
+        assert_last_event(&env, topics.into_val(&env), owner.into_val(&env));
+    }
+
+    // ---------- ACTOR ROLE ----------
+
+    #[test]
+    fn auth_helper_reports_owner_and_whitelisted_roles() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        env.as_contract(&contract_addr, || {
+            let store = symbol_short!("store");
+            let role = Contract::assert_owner_or_whitelisted_actor(&env, &owner, store.clone());
+            assert_eq!(role, ActorRole::Owner);
+            let role = Contract::assert_owner_or_whitelisted_actor(&env, &user, store);
+            assert_eq!(role, ActorRole::Whitelisted);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "not authorized: only owner or whitelisted address")]
+    fn auth_helper_rejects_unknown_actor() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        env.mock_all_auths();
+
+        let stranger = Address::generate(&env);
+        env.as_contract(&contract_addr, || {
+            Contract::assert_owner_or_whitelisted_actor(&env, &stranger, symbol_short!("store"));
+        });
+    }
+
+    // ---------- ACTOR LABELS ----------
+
+    #[test]
+    fn actor_label_appears_in_entry_and_listing() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        let label = String::from_str(&env, "Acme Notary GmbH");
+        assert_eq!(client.get_whitelist_entry(&user), None);
+        client.whitelist_address(&user);
+        client.set_actor_label(&user, &label);
+
+        assert_eq!(client.get_actor_label(&user), Some(label.clone()));
+        let entry = client.get_whitelist_entry(&user).unwrap();
+        assert_eq!(entry.label, Some(label.clone()));
+        let page = client.list_whitelist_page(&0, &10);
+        assert_eq!(page.items.get(0).unwrap(), entry);
+
+        client.set_actor_label(&user, &String::from_str(&env, ""));
+        assert_eq!(client.get_actor_label(&user), None);
+        assert_eq!(client.get_whitelist_entry(&user).unwrap().label, None);
+    }
+
+    #[test]
+    fn actor_label_survives_whitelist_removal() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        let label = String::from_str(&env, "Branch office");
+        client.whitelist_address(&user);
+        client.set_actor_label(&user, &label);
+        client.remove_from_whitelist(&user);
+
+        assert_eq!(client.get_whitelist_entry(&user), None);
+        assert_eq!(client.get_actor_label(&user), Some(label.clone()));
+        client.whitelist_address(&user);
+        assert_eq!(client.get_whitelist_entry(&user).unwrap().label, Some(label));
+    }
+
+    #[test]
+    #[should_panic(expected = "label too long")]
+    fn actor_label_length_is_bounded() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let label = String::from_bytes(&env, &[b'x'; (MAX_LABEL_LEN + 1) as usize]);
+        client.set_actor_label(&Address::generate(&env), &label);
+    }
+
+    // ---------- ACTOR PROFILES ----------
+
+    fn profile(env: &Env, org_name: &str) -> ActorProfile {
+        ActorProfile {
+            org_name: String::from_str(env, org_name),
+            contact_hash: BytesN::from_array(env, &[7u8; 32]),
+            url: String::from_str(env, "https://acme.example"),
+        }
+    }
+
+    #[test]
+    fn actor_publishes_own_profile_and_owner_can_override() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        assert_eq!(client.get_actor_profile(&user), None);
+
+        client.set_actor_profile(&user, &profile(&env, "Acme"));
+        assert_eq!(client.get_actor_profile(&user), Some(profile(&env, "Acme")));
+        client.set_actor_profile_admin(&user, &profile(&env, "Acme Corp"));
+        assert_eq!(client.get_actor_profile(&user), Some(profile(&env, "Acme Corp")));
+    }
+
+    #[test]
+    fn profile_is_removed_on_dewhitelisting() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.set_actor_profile(&user, &profile(&env, "Acme"));
+        client.remove_from_whitelist(&user);
+        assert_eq!(client.get_actor_profile(&user), None);
+
+        client.whitelist_address(&user);
+        assert_eq!(client.get_actor_profile(&user), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "not whitelisted")]
+    fn non_whitelisted_actor_cannot_publish_profile() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.set_actor_profile(&Address::generate(&env), &profile(&env, "Acme"));
+    }
+
+    #[test]
+    #[should_panic(expected = "organization name too long")]
+    fn profile_org_name_is_bounded() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let mut long = profile(&env, "Acme");
+        long.org_name = String::from_bytes(&env, &[b'x'; (MAX_ORG_NAME_LEN + 1) as usize]);
+        client.set_actor_profile(&user, &long);
+    }
+
+    // ---------- DOCUMENT TYPES ----------
+
+    #[test]
+    fn list_by_type_filters_a_mixed_registry() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Typed.pdf");
+        let types = [DocType::Invoice, DocType::Contract, DocType::Invoice, DocType::Report];
+        for (i, doc_type) in types.iter().enumerate() {
+            client.store_document_typed(&owner, &name, &hash_n(&env, i as u32 + 1), doc_type);
+        }
+        client.store_document(&owner, &name, &hash_n(&env, 9));
+
+        let invoices = client.list_by_type(&DocType::Invoice, &0, &10);
+        assert_eq!(invoices.len(), 2);
+        assert_eq!(invoices.get(0).unwrap().hash, hash_n(&env, 1));
+        assert_eq!(invoices.get(1).unwrap().hash, hash_n(&env, 3));
+        let contracts = client.list_by_type(&DocType::Contract, &0, &10);
+        assert_eq!(contracts.len(), 1);
+        assert_eq!(contracts.get(0).unwrap().hash, hash_n(&env, 2));
+        assert!(client.list_by_type(&DocType::Certificate, &0, &10).is_empty());
+        assert_eq!(client.list_by_type(&DocType::Invoice, &1, &10).len(), 1);
+    }
+
+    #[test]
+    fn untyped_documents_read_back_as_other() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let name = String::from_str(&env, "Typed.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.store_document_typed(&owner, &name, &hash_n(&env, 2), &DocType::Certificate);
+
+        assert_eq!(client.document_type(&hash_n(&env, 1)), Some(DocType::Other));
+        assert_eq!(client.verify_document(&hash_n(&env, 1)).unwrap().doc_type, DocType::Other);
+        let verified = client.verify_document(&hash_n(&env, 2)).unwrap();
+        assert_eq!(verified.doc_type, DocType::Certificate);
+        assert_eq!(client.document_type(&hash_n(&env, 3)), None);
+    }
+
+    #[test]
+    fn type_grants_are_ignored_when_not_strict() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let name = String::from_str(&env, "Invoice.pdf");
+        client.store_document_typed(&user, &name, &hash_n(&env, 1), &DocType::Invoice);
+        assert_eq!(client.document_type(&hash_n(&env, 1)), Some(DocType::Invoice));
+    }
+
+    #[test]
+    fn strict_type_permissions_require_a_grant() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        client.set_config(&Config { strict_type_permissions: true, ..client.get_config() });
+
+        let finance = Address::generate(&env);
+        client.whitelist_address(&finance);
+        let name = String::from_str(&env, "Invoice.pdf");
+        let denied = client.try_store_document_typed(
+            &finance,
+            &name,
+            &hash_n(&env, 1),
+            &DocType::Invoice,
+        );
+        assert!(denied.is_err());
+
+        client.allow_type_for(&finance, &DocType::Invoice);
+        assert!(client.is_type_allowed(&finance, &DocType::Invoice));
+        client.store_document_typed(&finance, &name, &hash_n(&env, 1), &DocType::Invoice);
+        let other = client.try_store_document_typed(
+            &finance,
+            &name,
+            &hash_n(&env, 2),
+            &DocType::Contract,
+        );
+        assert!(other.is_err());
+
+        // The owner needs no grant
+        client.store_document_typed(&owner, &name, &hash_n(&env, 3), &DocType::Contract);
+
+        client.disallow_type_for(&finance, &DocType::Invoice);
+        let revoked = client.try_store_document_typed(
+            &finance,
+            &name,
+            &hash_n(&env, 4),
+            &DocType::Invoice,
+        );
+        assert!(revoked.is_err());
+    }
+
+    // ---------- SUPERSEDE ----------
+
+    fn chain_hashes(chain: &Vec<DocumentSummary>) -> std::vec::Vec<String> {
+        chain.iter().map(|s| s.hash).collect()
+    }
+
+    #[test]
+    fn document_chain_is_oldest_first_from_any_member() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.store_documents(&owner, &inputs(&env, 1, 3));
+        client.supersede_document(&owner, &hash_n(&env, 1), &hash_n(&env, 2));
+        client.supersede_document(&owner, &hash_n(&env, 2), &hash_n(&env, 3));
+
+        let expected = std::vec![hash_n(&env, 1), hash_n(&env, 2), hash_n(&env, 3)];
+        for i in 1..=3 {
+            let chain = client.get_document_chain(&hash_n(&env, i), &10);
+            assert_eq!(chain_hashes(&chain), expected);
+        }
+        assert_eq!(client.superseded_by(&hash_n(&env, 1)), Some(hash_n(&env, 2)));
+        assert_eq!(client.supersedes(&hash_n(&env, 1)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "supersede chain exceeds max hops")]
+    fn document_chain_respects_hop_bound() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.store_documents(&owner, &inputs(&env, 1, 3));
+        client.supersede_document(&owner, &hash_n(&env, 1), &hash_n(&env, 2));
+        client.supersede_document(&owner, &hash_n(&env, 2), &hash_n(&env, 3));
+        client.get_document_chain(&hash_n(&env, 2), &1);
+    }
+
+    #[test]
+    #[should_panic(expected = "supersede cycle detected")]
+    fn document_chain_detects_cycles() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.store_documents(&owner, &inputs(&env, 1, 3));
+        client.supersede_document(&owner, &hash_n(&env, 1), &hash_n(&env, 2));
+        client.supersede_document(&owner, &hash_n(&env, 2), &hash_n(&env, 3));
+        client.supersede_document(&owner, &hash_n(&env, 3), &hash_n(&env, 1));
+        client.get_document_chain(&hash_n(&env, 1), &10);
+    }
+
+    // ---------- UPDATE TRACKING ----------
+
+    #[test]
+    fn update_info_advances_on_each_mutation() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let hash = hash_n(&env, 1);
+        client.store_document(&user, &String::from_str(&env, "Edited.pdf"), &hash);
+        let info = client.get_update_info(&hash).unwrap();
+        assert_eq!(info.last_updated, 1_800_000_000);
+        assert_eq!(info.updated_by, user);
+        assert_eq!(info.update_count, 0);
+
+        env.ledger().with_mut(|li| li.timestamp += 100);
+        let uri = String::from_str(&env, "ipfs://bafy-edited");
+        client.add_document_reference(&user, &hash, &uri);
+        let info = client.get_update_info(&hash).unwrap();
+        assert_eq!(info.last_updated, 1_800_000_100);
+        assert_eq!(info.updated_by, user);
+        assert_eq!(info.update_count, 1);
+
+        env.ledger().with_mut(|li| li.timestamp += 100);
+        client.revoke_document(&owner, &hash);
+        let info = client.get_update_info(&hash).unwrap();
+        assert_eq!(info.last_updated, 1_800_000_200);
+        assert_eq!(info.updated_by, owner);
+        assert_eq!(info.update_count, 2);
+
+        assert_eq!(client.get_update_info(&hash_n(&env, 2)), None);
+    }
+
+    // ---------- LEDGER SEQUENCE ----------
+
+    #[test]
+    fn ledger_sequence_round_trips_through_reads_and_receipt() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        // both ledgers stay inside the instance entry's initial TTL
+        env.ledger().with_mut(|li| li.sequence_number = 2_424);
+        let hash = hash_n(&env, 1);
+        let receipt = client.store_document(&owner, &String::from_str(&env, "Seq.pdf"), &hash);
+        env.ledger().with_mut(|li| li.sequence_number = 3_000);
+
+        assert_eq!(receipt.ledger_sequence, 2_424);
+        assert_eq!(client.read_document(&hash).unwrap().sequence, 2_424);
+        assert_eq!(client.verify_document(&hash).unwrap().sequence, 2_424);
+        assert_eq!(client.get_receipt(&hash).unwrap().ledger_sequence, 2_424);
+    }
+
+    // ---------- SUBJECT DELETION REQUESTS ----------
+
+    /// Store hash 1 with `subject` recorded on it
+    fn store_with_subject(env: &Env, client: &ContractClient, owner: &Address) -> Address {
+        let subject = Address::generate(env);
+        client.store_document(owner, &String::from_str(env, "Personal.pdf"), &hash_n(env, 1));
+        client.set_document_subject(owner, &hash_n(env, 1), &subject);
+        subject
+    }
+
+    #[test]
+    #[should_panic(expected = "not authorized: only the document subject")]
+    fn non_subject_cannot_request_deletion() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        store_with_subject(&env, &client, &owner);
+        let reason = String::from_str(&env, "erase me");
+        client.request_deletion(&Address::generate(&env), &hash_n(&env, 1), &reason);
+    }
+
+    #[test]
+    fn approved_deletion_soft_deletes_the_document() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let subject = store_with_subject(&env, &client, &owner);
+        let reason = String::from_str(&env, "GDPR art. 17");
+        client.request_deletion(&subject, &hash_n(&env, 1), &reason);
+        let request = client.get_deletion_request(&hash_n(&env, 1)).unwrap();
+        assert_eq!(request.subject, subject);
+        assert_eq!(request.expires_at, 1_800_000_000 + DEFAULT_DELETION_REQUEST_TTL_SECS);
+        assert_eq!(client.list_deletion_requests(&0, &10).items, Vec::from_array(&env, [request]));
+
+        client.approve_deletion(&hash_n(&env, 1));
+        assert_eq!(client.get_deletion_request(&hash_n(&env, 1)), None);
+        assert!(client.list_deletion_requests(&0, &10).items.is_empty());
+        let verified = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert_eq!(verified.status, DocumentStatus::Erased);
+        assert!(!verified.verified_document);
+        let erased = client.read_document(&hash_n(&env, 1)).unwrap();
+        assert_eq!(erased.name, String::from_str(&env, ""));
+        let name = String::from_str(&env, "Personal.pdf");
+        assert!(client.try_store_document(&owner, &name, &hash_n(&env, 1)).is_err());
+    }
+
+    #[test]
+    fn denied_deletion_keeps_the_document() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let subject = store_with_subject(&env, &client, &owner);
+        client.request_deletion(&subject, &hash_n(&env, 1), &String::from_str(&env, "erase"));
+        client.deny_deletion(&hash_n(&env, 1), &String::from_str(&env, "legal hold"));
+
+        assert_eq!(client.get_deletion_request(&hash_n(&env, 1)), None);
+        assert!(client.verify_document(&hash_n(&env, 1)).unwrap().verified_document);
+        assert!(client.try_approve_deletion(&hash_n(&env, 1)).is_err());
+    }
+
+    #[test]
+    fn expired_deletion_request_cannot_be_approved() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        client.set_config(&Config { deletion_request_ttl_secs: 60, ..client.get_config() });
+
+        let subject = store_with_subject(&env, &client, &owner);
+        let reason = String::from_str(&env, "erase");
+        client.request_deletion(&subject, &hash_n(&env, 1), &reason);
+        advance_time(&env, 61);
+
+        assert_eq!(client.get_deletion_request(&hash_n(&env, 1)), None);
+        assert!(client.list_deletion_requests(&0, &10).items.is_empty());
+        assert!(client.try_approve_deletion(&hash_n(&env, 1)).is_err());
+
+        // The subject may ask again once the old request lapsed
+        client.request_deletion(&subject, &hash_n(&env, 1), &reason);
+        client.approve_deletion(&hash_n(&env, 1));
+        assert_eq!(client.document_status(&hash_n(&env, 1)), Some(DocumentStatus::Erased));
+    }
+
+    // ---------- RETENTION ----------
+
+    #[test]
+    fn purge_deletes_only_documents_past_retention() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let seven_years = 7 * 365 * 86_400;
+        client.set_retention_period(&DocType::Invoice, &seven_years);
+        assert_eq!(client.get_retention_period(&DocType::Invoice), Some(seven_years));
+        let name = String::from_str(&env, "Retained.pdf");
+        client.store_document_typed(&owner, &name, &hash_n(&env, 1), &DocType::Invoice);
+        client.store_document_typed(&owner, &name, &hash_n(&env, 2), &DocType::Invoice);
+        client.store_document_typed(&owner, &name, &hash_n(&env, 3), &DocType::Contract);
+        client.pin_document(&hash_n(&env, 2));
+        assert!(!client.is_purge_eligible(&hash_n(&env, 1)));
+
+        advance_time(&env, seven_years - 1);
+        client.store_document_typed(&owner, &name, &hash_n(&env, 4), &DocType::Invoice);
+        advance_time(&env, 1);
+        assert!(client.is_purge_eligible(&hash_n(&env, 1)));
+
+        let batch = Vec::from_array(
+            &env,
+            [hash_n(&env, 1), hash_n(&env, 2), hash_n(&env, 3), hash_n(&env, 4), hash_n(&env, 9)],
+        );
+        assert_eq!(client.purge_eligible(&batch), 1);
+        assert!(client.read_document(&hash_n(&env, 1)).is_none());
+        for i in 2..=4 {
+            assert!(client.read_document(&hash_n(&env, i)).is_some());
+        }
+        assert_eq!(client.document_count(), 3);
+    }
+
+    // ---------- LEGAL HOLD ----------
+
+    #[test]
+    fn legal_hold_blocks_self_delete_until_released() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.store_document(&user, &String::from_str(&env, "Held.pdf"), &hash_n(&env, 1));
+        client.set_legal_hold(&hash_n(&env, 1), &true);
+        assert!(client.is_on_legal_hold(&hash_n(&env, 1)));
+        assert!(client.try_delete_own_document(&user, &hash_n(&env, 1)).is_err());
+
+        // Revocation doesn't destroy data, so it stays allowed
+        client.revoke_document(&user, &hash_n(&env, 1));
+
+        client.set_legal_hold(&hash_n(&env, 1), &false);
+        assert!(!client.is_on_legal_hold(&hash_n(&env, 1)));
+        client.delete_own_document(&user, &hash_n(&env, 1));
+        assert!(client.read_document(&hash_n(&env, 1)).is_none());
+    }
+
+    #[test]
+    fn legal_hold_blocks_erasure_and_purge() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let subject = store_with_subject(&env, &client, &owner);
+        client.set_retention_period(&DocType::Other, &60);
+        client.request_deletion(&subject, &hash_n(&env, 1), &String::from_str(&env, "erase"));
+        client.set_legal_hold(&hash_n(&env, 1), &true);
+        advance_time(&env, 60);
+
+        assert!(client.try_approve_deletion(&hash_n(&env, 1)).is_err());
+        assert!(!client.is_purge_eligible(&hash_n(&env, 1)));
+        assert_eq!(client.purge_eligible(&Vec::from_array(&env, [hash_n(&env, 1)])), 0);
+
+        client.set_legal_hold(&hash_n(&env, 1), &false);
+        assert!(client.is_purge_eligible(&hash_n(&env, 1)));
+        client.approve_deletion(&hash_n(&env, 1));
+        assert_eq!(client.document_status(&hash_n(&env, 1)), Some(DocumentStatus::Erased));
+    }
+
+    #[test]
+    #[should_panic(expected = "document on legal hold")]
+    fn held_document_reports_legal_hold() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.store_document(&owner, &String::from_str(&env, "Held.pdf"), &hash_n(&env, 1));
+        client.set_legal_hold(&hash_n(&env, 1), &true);
+        client.delete_own_document(&owner, &hash_n(&env, 1));
+    }
+
+    // ---------- REDACTION ----------
+
+    #[test]
+    fn redaction_removes_name_but_keeps_attestation() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let subject = store_with_subject(&env, &client, &owner);
+        let hash = hash_n(&env, 1);
+        client.add_document_reference(&owner, &hash, &String::from_str(&env, "mailto:jane@x.org"));
+        client.redact_document(&hash);
+
+        let doc = client.read_document(&hash).unwrap();
+        assert_eq!(doc.name, String::from_str(&env, REDACTION_MARKER));
+        assert_eq!(doc.timestamp, 1_800_000_000);
+        assert_eq!(doc.added_by, owner);
+        assert_eq!(client.document_subject(&hash), None);
+        assert!(client.get_document_references(&hash).is_empty());
+        let reason = String::from_str(&env, "erase");
+        assert!(client.try_request_deletion(&subject, &hash, &reason).is_err());
+
+        let verified = client.verify_document(&hash).unwrap();
+        assert!(verified.verified_document);
+        assert!(verified.redacted);
+        assert_eq!(verified.redacted_at, Some(1_800_000_000));
+        assert!(client.try_redact_document(&hash).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "document on legal hold")]
+    fn redaction_refuses_held_documents() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.store_document(&owner, &String::from_str(&env, "Held.pdf"), &hash_n(&env, 1));
+        client.set_legal_hold(&hash_n(&env, 1), &true);
+        client.redact_document(&hash_n(&env, 1));
+    }
+
+    // ---------- STORE HOOK ----------
+
+    mod hook_receiver {
+        use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String};
+
+        /// Hook receiver that counts notifications and remembers the last hash
+        #[contract]
+        pub struct HookReceiver;
+
+        #[contractimpl]
+        impl HookReceiver {
+            pub fn on_document_stored(env: Env, hash: String, added_by: Address, timestamp: u64) {
+                let calls: u32 = env.storage().instance().get(&symbol_short!("calls")).unwrap_or(0);
+                env.storage().instance().set(&symbol_short!("calls"), &(calls + 1));
+                env.storage().instance().set(&symbol_short!("last"), &(hash, added_by, timestamp));
+            }
+
+            pub fn calls(env: Env) -> u32 {
+                env.storage().instance().get(&symbol_short!("calls")).unwrap_or(0)
+            }
+        }
+    }
+
+    mod reentrant_receiver {
+        use crate::ContractClient;
+        use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String};
+
+        /// Hook receiver that tries to store the same hash again
+        #[contract]
+        pub struct ReentrantReceiver;
+
+        #[contractimpl]
+        impl ReentrantReceiver {
+            pub fn init(env: Env, registry: Address) {
+                env.storage().instance().set(&symbol_short!("registry"), &registry);
+            }
+
+            pub fn on_document_stored(env: Env, hash: String, added_by: Address, _timestamp: u64) {
+                let registry: Address =
+                    env.storage().instance().get(&symbol_short!("registry")).unwrap();
+                let name = String::from_str(&env, "Again.pdf");
+                ContractClient::new(&env, &registry).store_document(&added_by, &name, &hash);
+            }
+        }
+    }
+
+    #[test]
+    fn store_hook_is_notified_after_each_store() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let receiver = env.register_contract(None, hook_receiver::HookReceiver);
+        let receiver_client = hook_receiver::HookReceiverClient::new(&env, &receiver);
+        client.set_store_hook(&receiver, &HookMode::Strict);
+        assert_eq!(client.get_store_hook().unwrap().contract, receiver);
+
+        let name = String::from_str(&env, "Hooked.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.store_documents(&owner, &inputs(&env, 2, 2));
+        assert_eq!(receiver_client.calls(), 3);
+        env.as_contract(&receiver, || {
+            let last: (String, Address, u64) =
+                env.storage().instance().get(&symbol_short!("last")).unwrap();
+            assert_eq!(last, (hash_n(&env, 3), owner.clone(), 1_800_000_000));
+        });
+
+        client.clear_store_hook();
+        client.store_document(&owner, &name, &hash_n(&env, 4));
+        assert_eq!(receiver_client.calls(), 3);
+    }
+
+    #[test]
+    fn failing_hook_aborts_only_in_strict_mode() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        // The registry itself has no `on_document_stored`, so every call fails
+        let broken = env.register_contract(None, Contract);
+        let name = String::from_str(&env, "Hooked.pdf");
+        client.set_store_hook(&broken, &HookMode::Strict);
+        assert!(client.try_store_document(&owner, &name, &hash_n(&env, 1)).is_err());
+        assert!(client.read_document(&hash_n(&env, 1)).is_none());
+
+        client.set_store_hook(&broken, &HookMode::BestEffort);
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        assert!(client.read_document(&hash_n(&env, 1)).is_some());
+    }
+
+    #[test]
+    fn reentrant_hook_cannot_store_during_notification() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let receiver = env.register_contract(None, reentrant_receiver::ReentrantReceiver);
+        let reentrant = reentrant_receiver::ReentrantReceiverClient::new(&env, &receiver);
+        reentrant.init(&contract_addr);
+        let name = String::from_str(&env, "Hooked.pdf");
+
+        client.set_store_hook(&receiver, &HookMode::Strict);
+        assert!(client.try_store_document(&owner, &name, &hash_n(&env, 1)).is_err());
+
+        client.set_store_hook(&receiver, &HookMode::BestEffort);
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        assert_eq!(client.document_count(), 1);
+        assert_eq!(client.read_document(&hash_n(&env, 1)).unwrap().name, name);
+    }
+
+    #[test]
+    #[should_panic(expected = "registry sealed")]
+    fn clear_store_hook_after_seal_panics() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let receiver = env.register_contract(None, hook_receiver::HookReceiver);
+        client.set_store_hook(&receiver, &HookMode::Strict);
+        client.seal_registry();
+        client.clear_store_hook();
+    }
+
+    // ---------- AUTH PROVIDER ----------
+
+    mod mock_acl {
+        use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Symbol};
+
+        /// ACL that admits addresses registered through `allow`, for `store` only
+        #[contract]
+        pub struct MockAcl;
+
+        #[contractimpl]
+        impl MockAcl {
+            pub fn allow(env: Env, actor: Address) {
+                env.storage().persistent().set(&actor, &true);
+            }
+
+            pub fn is_authorized(env: Env, actor: Address, action: Symbol) -> bool {
+                action == symbol_short!("store") && env.storage().persistent().has(&actor)
+            }
+        }
+    }
+
+    mod trapping_acl {
+        use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+        /// ACL whose every call traps
+        #[contract]
+        pub struct TrappingAcl;
+
+        #[contractimpl]
+        impl TrappingAcl {
+            pub fn is_authorized(_env: Env, _actor: Address, _action: Symbol) -> bool {
+                panic!("acl unavailable");
+            }
+        }
+    }
+
+    #[test]
+    fn auth_provider_admits_actors_alongside_whitelist() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let acl = env.register_contract(None, mock_acl::MockAcl);
+        let partner = Address::generate(&env);
+        mock_acl::MockAclClient::new(&env, &acl).allow(&partner);
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.set_auth_provider(&acl);
+        assert_eq!(client.get_auth_provider(), Some(acl));
+
+        let name = String::from_str(&env, "Acl.pdf");
+        client.store_document(&partner, &name, &hash_n(&env, 1));
+        client.store_document(&user, &name, &hash_n(&env, 2));
+        let stranger = Address::generate(&env);
+        assert!(client.try_store_document(&stranger, &name, &hash_n(&env, 3)).is_err());
+        // The provider only grants `store`
+        let bundle_id = String::from_str(&env, "b-1");
+        assert!(client.try_create_bundle(&partner, &bundle_id, &name).is_err());
+
+        client.clear_auth_provider();
+        assert!(client.try_store_document(&partner, &name, &hash_n(&env, 3)).is_err());
+    }
+
+    #[test]
+    fn exclusive_auth_provider_overrides_whitelist() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let acl = env.register_contract(None, mock_acl::MockAcl);
+        let partner = Address::generate(&env);
+        mock_acl::MockAclClient::new(&env, &acl).allow(&partner);
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.set_auth_provider(&acl);
+        client.set_config(&Config { auth_provider_exclusive: true, ..client.get_config() });
+
+        let name = String::from_str(&env, "Acl.pdf");
+        assert!(client.try_store_document(&user, &name, &hash_n(&env, 1)).is_err());
+        client.store_document(&partner, &name, &hash_n(&env, 1));
+        client.store_document(&owner, &name, &hash_n(&env, 2));
+    }
+
+    #[test]
+    fn trapping_auth_provider_counts_as_denial() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let acl = env.register_contract(None, trapping_acl::TrappingAcl);
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.set_auth_provider(&acl);
+
+        let name = String::from_str(&env, "Acl.pdf");
+        let stranger = Address::generate(&env);
+        assert!(client.try_store_document(&stranger, &name, &hash_n(&env, 1)).is_err());
+        client.store_document(&user, &name, &hash_n(&env, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "registry sealed")]
+    fn clear_auth_provider_after_seal_panics() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let acl = env.register_contract(None, mock_acl::MockAcl);
+        client.set_auth_provider(&acl);
+        client.seal_registry();
+        client.clear_auth_provider();
+    }
+
+    // ---------- EXPORT ----------
+
+    #[test]
+    fn export_chunks_reassemble_the_full_state() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        client.store_documents(&owner, &inputs(&env, 1, 7));
+        let mut users = std::vec::Vec::new();
+        for _ in 0..5 {
+            let user = Address::generate(&env);
+            client.whitelist_address(&user);
+            users.push(user);
+        }
+
+        let mut docs = std::vec::Vec::new();
+        let mut start = 0;
+        loop {
+            let chunk = client.export_documents(&start, &3);
+            if chunk.is_empty() {
+                break;
+            }
+            docs.extend(chunk.iter().map(|d| d.hash));
+            start += 3;
+        }
+        let expected: std::vec::Vec<String> = (1..=7).map(|i| hash_n(&env, i)).collect();
+        assert_eq!(docs, expected);
+
+        let mut exported = std::vec::Vec::new();
+        for start in [0, 2, 4] {
+            for (address, entry) in client.export_whitelist(&start, &2).iter() {
+                assert_eq!(entry.address, address);
+                exported.push(address);
+            }
+        }
+        assert_eq!(exported, users);
+    }
+
+    // ---------- DOCUMENT DIGEST ----------
+
+    /// Fixed inputs for the digest test vector
+    fn digest_vector(env: &Env) -> (String, String, u64, Address, Address) {
+        let hash = String::from_str(env, &"ab".repeat(32));
+        let name = String::from_str(env, "Contract.pdf");
+        // ed25519 key 0x02 * 32 and contract id 0x01 * 32
+        let account = "GABAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEJXA";
+        let contract = "CAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQC526";
+        let added_by = Address::from_string(&String::from_str(env, account));
+        let contract = Address::from_string(&String::from_str(env, contract));
+        (hash, name, 1_800_000_000, added_by, contract)
+    }
+
+    #[test]
+    fn canonical_encoding_matches_documented_layout() {
+        let env = Env::default();
+        let (hash, name, timestamp, added_by, contract) = digest_vector(&env);
+        let buf =
+            Contract::canonical_encoding(&env, &hash, &name, timestamp, &added_by, &contract);
+
+        let mut expected = std::vec::Vec::new();
+        expected.extend_from_slice(b"AITT-DOC-V1");
+        expected.extend_from_slice(&[0, 0, 0, 14, 0, 0, 0, 64]);
+        expected.extend_from_slice("ab".repeat(32).as_bytes());
+        expected.extend_from_slice(&[0, 0, 0, 14, 0, 0, 0, 12]);
+        expected.extend_from_slice(b"Contract.pdf");
+        expected.extend_from_slice(&[0, 0, 0, 5]);
+        expected.extend_from_slice(&1_800_000_000u64.to_be_bytes());
+        expected.extend_from_slice(&[0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0]);
+        expected.extend_from_slice(&[2u8; 32]);
+        expected.extend_from_slice(&[0, 0, 0, 18, 0, 0, 0, 1]);
+        expected.extend_from_slice(&[1u8; 32]);
+        assert_eq!(buf, Bytes::from_slice(&env, &expected));
+
+        let digest: BytesN<32> = env.crypto().sha256(&buf).into();
+        let vector = [
+            0xb4, 0xe4, 0xc3, 0x34, 0x46, 0x5a, 0x96, 0xb9, 0xc7, 0x87, 0x5b, 0xfb, 0xdc, 0x5d,
+            0x46, 0x82, 0x33, 0xf3, 0x8c, 0xae, 0x10, 0xa2, 0xc3, 0xb8, 0x71, 0xdc, 0xfb, 0x3a,
+            0x75, 0xf9, 0x73, 0x32,
+        ];
+        assert_eq!(digest.to_array(), vector);
+    }
+
+    #[test]
+    fn document_digest_survives_revocation_but_not_redaction() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "Digest.pdf"), &hash);
+        let digest = client.get_document_digest(&hash).unwrap();
+        let expected: BytesN<32> = env.as_contract(&contract_addr, || {
+            let name = String::from_str(&env, "Digest.pdf");
+            let buf = Contract::canonical_encoding(
+                &env,
+                &hash,
+                &name,
+                1_800_000_000,
+                &owner,
+                &contract_addr,
+            );
+            env.crypto().sha256(&buf).into()
+        });
+        assert_eq!(digest, expected);
+
+        client.revoke_document(&owner, &hash);
+        assert_eq!(client.get_document_digest(&hash), Some(digest.clone()));
+        client.redact_document(&hash);
+        assert_ne!(client.get_document_digest(&hash), Some(digest));
+        assert_eq!(client.get_document_digest(&hash_n(&env, 2)), None);
+    }
+
+    // ---------- PREFLIGHT ----------
+
+    #[test]
+    fn can_store_predicts_store_document_outcomes() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.set_config(&Config {
+            daily_store_limit: 1,
+            ..client.get_config()
+        });
+        let name = String::from_str(&env, "Preflight.pdf");
+
+        assert_eq!(client.can_store(&stranger, &hash_n(&env, 1)), StoreCheck::NotAuthorized);
+        assert!(client.try_store_document(&stranger, &name, &hash_n(&env, 1)).is_err());
+
+        assert_eq!(client.can_store(&user, &hash_n(&env, 1)), StoreCheck::Ok);
+        client.store_document(&user, &name, &hash_n(&env, 1));
+
+        assert_eq!(client.can_store(&owner, &hash_n(&env, 1)), StoreCheck::Duplicate);
+        assert!(client.try_store_document(&owner, &name, &hash_n(&env, 1)).is_err());
+
+        assert_eq!(client.can_store(&user, &hash_n(&env, 2)), StoreCheck::QuotaExceeded);
+        assert!(client.try_store_document(&user, &name, &hash_n(&env, 2)).is_err());
+        assert_eq!(client.can_store(&owner, &hash_n(&env, 2)), StoreCheck::Ok);
+
+        client.seal_registry();
+        assert_eq!(client.can_store(&owner, &hash_n(&env, 2)), StoreCheck::Paused);
+        assert!(client.try_store_document(&owner, &name, &hash_n(&env, 2)).is_err());
+    }
+
+    #[test]
+    fn can_store_predicts_hash_rule_failures() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        client.set_validation_rules(&rules(&env, &[64], true));
+        let name = String::from_str(&env, "Preflight.pdf");
+
+        // "hash length not allowed"
+        let short = String::from_str(&env, "abcd");
+        assert_eq!(client.can_store(&owner, &short), StoreCheck::InvalidHash);
+        assert!(client.try_store_document(&owner, &name, &short).is_err());
+
+        // "hash not hex"
+        let not_hex = String::from_str(
+            &env,
+            "zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz",
+        );
+        assert_eq!(client.can_store(&owner, &not_hex), StoreCheck::InvalidHash);
+        assert!(client.try_store_document(&owner, &name, &not_hex).is_err());
+
+        assert_eq!(client.can_store(&owner, &hash_n(&env, 1)), StoreCheck::Ok);
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+    }
+
+    #[test]
+    fn can_store_needs_no_signature_and_writes_nothing() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.set_config(&Config {
+            daily_store_limit: 1,
+            ..client.get_config()
+        });
+
+        // No auth mocks from here on
+        env.set_auths(&[]);
+        let day = client.current_epoch();
+        let seq = client.last_event_seq();
+        assert_eq!(client.can_store(&user, &hash_n(&env, 1)), StoreCheck::Ok);
+        assert_eq!(client.can_store(&user, &hash_n(&env, 1)), StoreCheck::Ok);
+        assert_eq!(client.epoch_store_count(&user, &day), 0);
+        assert_eq!(client.last_event_seq(), seq);
+        assert_eq!(client.document_count(), 0);
+    }
+
+    // ---------- PERMISSIONS ----------
+
+    #[test]
+    fn get_permissions_reflects_scripted_setup() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        client.whitelist_address(&user);
+        let token = fee_token(&env, &user, 1_000);
+        client.set_config(&Config {
+            daily_store_limit: 3,
+            registration_fee: FeeSetting::Fixed(Fee { token, amount: 10 }),
+            free_quota_per_actor: 1,
+            ..client.get_config()
+        });
+
+        assert_eq!(
+            client.get_permissions(&owner),
+            Permissions {
+                is_owner: true,
+                is_whitelisted: false,
+                can_store: true,
+                can_revoke: true,
+                remaining_quota: None,
+                fee_exempt: true,
+            }
+        );
+        let fresh = Permissions {
+            is_owner: false,
+            is_whitelisted: true,
+            can_store: true,
+            can_revoke: false,
+            remaining_quota: Some(3),
+            fee_exempt: true,
+        };
+        assert_eq!(client.get_permissions(&user), fresh);
+
+        client.store_document(&user, &String::from_str(&env, "Perm.pdf"), &hash_n(&env, 1));
+        assert_eq!(
+            client.get_permissions(&user),
+            Permissions {
+                remaining_quota: Some(2),
+                fee_exempt: false,
+                ..fresh
+            }
+        );
+        assert_eq!(
+            client.get_permissions(&stranger),
+            Permissions {
+                is_owner: false,
+                is_whitelisted: false,
+                can_store: false,
+                can_revoke: false,
+                remaining_quota: Some(3),
+                // its free quota is untouched, even though it can't store
+                fee_exempt: true,
+            }
+        );
+
+        client.seal_registry();
+        let sealed = client.get_permissions(&owner);
+        assert!(!sealed.can_store && !sealed.can_revoke && sealed.is_owner);
+    }
+
+    // ---------- CONTRACT INFO ----------
+
+    #[test]
+    fn contract_info_matches_individual_getters() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.store_document(&user, &String::from_str(&env, "Info.pdf"), &hash_n(&env, 1));
+        client.propose_ownership(&candidate);
+        let fee = Fee { token: fee_token(&env, &user, 0), amount: 5 };
+        client.set_config(&Config {
+            registration_fee: FeeSetting::Fixed(fee.clone()),
+            ..client.get_config()
+        });
+
+        let info = client.get_contract_info();
+        let stats = client.get_stats();
+        assert_eq!(info.interface_version, INTERFACE_VERSION);
+        assert_eq!(info.schema_version, stats.schema_version);
+        assert_eq!(info.event_schema_version, events::EVENT_SCHEMA_VERSION);
+        assert_eq!(info.owner, owner);
+        assert_eq!(info.pending_owner, client.pending_owner());
+        assert_eq!(info.pending_owner, Some(candidate));
+        assert_eq!(info.document_count, client.document_count());
+        assert_eq!(info.whitelisted_count, client.whitelisted_count());
+        assert_eq!(info.registration_fee, FeeSetting::Fixed(fee));
+        assert!(!info.sealed && !info.decommissioned);
+
+        client.seal_registry();
+        assert!(client.get_contract_info().sealed);
+    }
+
+    #[test]
+    fn interface_version_is_pinned() {
+        // Bump together with the contractmeta! entry when entrypoints change
+        assert_eq!(INTERFACE_VERSION, 1);
+    }
+
+    fn symbols(env: &Env, names: &[&str]) -> Vec<Symbol> {
+        let mut out = Vec::new(env);
+        for name in names {
+            out.push_back(Symbol::new(env, name));
+        }
+        out
+    }
+
+    #[test]
+    fn supported_features_follow_config() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        assert_eq!(client.interface_version(), INTERFACE_VERSION);
+        let built_in = [
+            "bundles",
+            "categories",
+            "chain",
+            "idempotency",
+            "namespaces",
+            "recovery",
+            "retention",
+            "revocation",
+            "tsa",
+            "views",
+        ];
+        assert_eq!(client.supported_features(), symbols(&env, &built_in));
+
+        let token = fee_token(&env, &Address::generate(&env), 0);
+        client.set_config(&Config {
+            registration_fee: FeeSetting::Fixed(Fee { token, amount: 1 }),
+            unique_names: true,
+            ..client.get_config()
+        });
+        let expected = [
+            "bundles",
+            "categories",
+            "chain",
+            "fees",
+            "idempotency",
+            "namespaces",
+            "recovery",
+            "retention",
+            "revocation",
+            "tsa",
+            "unique_names",
+            "views",
+        ];
+        assert_eq!(client.supported_features(), symbols(&env, &expected));
+        // Stable between calls
+        assert_eq!(client.supported_features(), client.supported_features());
+    }
+
+    // ---------- INIT WITH WHITELIST ----------
+
+    #[test]
+    fn init_with_whitelist_seeds_addresses_once() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let contract_addr = env.register_contract(None, Contract);
+        let client = ContractClient::new(&env, &contract_addr);
+        let seeded = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        let mut list = Vec::from_array(&env, seeded.clone());
+        list.push_back(seeded[1].clone());
+
+        client.init_with_whitelist(&owner, &list);
+        for address in seeded.iter() {
+            assert!(client.is_whitelisted(address));
+        }
+        assert_eq!(client.whitelisted_count(), 3);
+        assert_eq!(client.owner_address(), owner);
+        let added: Vec<Val> = (symbol_short!("whitelist"), symbol_short!("added")).into_val(&env);
+        let count = env
+            .events()
+            .all()
+            .iter()
+            .filter(|(_, topics, _)| topics.slice(0..2) == added)
+            .count();
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "batch too large")]
+    fn init_with_whitelist_is_bounded() {
+        let env = Env::default();
+        let contract_addr = env.register_contract(None, Contract);
+        let client = ContractClient::new(&env, &contract_addr);
+        let limit = client.get_limits().max_batch_whitelist;
+        let mut list = Vec::new(&env);
+        for _ in 0..=limit {
+            list.push_back(Address::generate(&env));
+        }
+        client.init_with_whitelist(&Address::generate(&env), &list);
+    }
+
+    // ---------- INIT WITH DOCUMENTS ----------
+
+    fn imported(env: &Env, n: u32, timestamp: u64) -> ImportedDocument {
+        ImportedDocument {
+            name: String::from_str(env, "Genesis.pdf"),
+            hash: hash_n(env, n),
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn init_with_documents_keeps_original_timestamps() {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.timestamp = 1_800_000_000);
+        let owner = Address::generate(&env);
+        let contract_addr = env.register_contract(None, Contract);
+        let client = ContractClient::new(&env, &contract_addr);
+        let docs = Vec::from_array(
+            &env,
+            [imported(&env, 1, 1_500_000_000), imported(&env, 2, 1_600_000_000)],
+        );
+
+        client.init_with_documents(&owner, &docs);
+        for (n, timestamp) in [(1, 1_500_000_000), (2, 1_600_000_000)] {
+            let verified = client.verify_document(&hash_n(&env, n)).unwrap();
+            assert_eq!(verified.timestamp, timestamp);
+            assert_eq!(verified.registered_at, 1_800_000_000);
+            assert_eq!(verified.added_by, owner);
+            assert!(verified.imported && verified.verified_document);
+        }
+        assert_eq!(client.document_count(), 2);
+    }
+
+    #[test]
+    fn init_with_documents_is_all_or_nothing() {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.timestamp = 1_800_000_000);
+        let contract_addr = env.register_contract(None, Contract);
+        let client = ContractClient::new(&env, &contract_addr);
+        let owner = Address::generate(&env);
+        let future = Vec::from_array(
+            &env,
+            [imported(&env, 1, 1_500_000_000), imported(&env, 2, 1_900_000_000)],
+        );
+        assert!(client.try_init_with_documents(&owner, &future).is_err());
+        let dup = Vec::from_array(
+            &env,
+            [imported(&env, 1, 1_500_000_000), imported(&env, 1, 1_600_000_000)],
+        );
+        assert!(client.try_init_with_documents(&owner, &dup).is_err());
+
+        assert!(client.try_owner_address().is_err());
+        assert_eq!(client.verify_document(&hash_n(&env, 1)), None);
+    }
+
+    // ---------- HASH CHAIN ----------
+
+    #[test]
+    fn chain_head_recomputes_from_document_digests() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let zero = BytesN::from_array(&env, &[0; 32]);
+        assert_eq!(client.get_chain_head(), zero);
+
+        let name = String::from_str(&env, "Chain.pdf");
+        let mut hashes = Vec::new(&env);
+        let mut head = zero;
+        for n in 1..=3 {
+            client.store_document(&owner, &name, &hash_n(&env, n));
+            hashes.push_back(hash_n(&env, n));
+            assert_eq!(client.get_prev_head(&hash_n(&env, n)), Some(head.clone()));
+            let mut buf = Bytes::from_array(&env, &head.to_array());
+            let digest = client.get_document_digest(&hash_n(&env, n)).unwrap();
+            buf.append(&Bytes::from_array(&env, &digest.to_array()));
+            head = env.crypto().sha256(&buf).into();
+        }
+        assert_eq!(client.get_chain_head(), head);
+        assert!(client.verify_chain_segment(&hashes));
+        assert!(client.verify_chain_segment(&hashes.slice(1..3)));
+    }
+
+    #[test]
+    fn chain_segment_rejects_gaps_and_rewritten_documents() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let name = String::from_str(&env, "Chain.pdf");
+        for n in 1..=3 {
+            client.store_document(&owner, &name, &hash_n(&env, n));
+        }
+
+        let gap = Vec::from_array(&env, [hash_n(&env, 1), hash_n(&env, 3)]);
+        assert!(!client.verify_chain_segment(&gap));
+        let reordered = Vec::from_array(&env, [hash_n(&env, 2), hash_n(&env, 1)]);
+        assert!(!client.verify_chain_segment(&reordered));
+        let missing = Vec::from_array(&env, [hash_n(&env, 3), hash_n(&env, 4)]);
+        assert!(!client.verify_chain_segment(&missing));
+
+        client.redact_document(&hash_n(&env, 1));
+        let all = Vec::from_array(&env, [hash_n(&env, 1), hash_n(&env, 2)]);
+        assert!(!client.verify_chain_segment(&all));
+    }
+
+    // ---------- FULL DOCUMENT READ ----------
+
+    #[test]
+    fn get_document_full_assembles_every_attribute() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let hash = hash_n(&env, 1);
+        let name = String::from_str(&env, "Full.pdf");
+        let category = symbol_short!("legal");
+        client.store_document_categorized(&owner, &name, &hash, &category);
+
+        let controller = Address::generate(&env);
+        let subject = Address::generate(&env);
+        let uri = String::from_str(&env, "ipfs://full");
+        client.add_document_controller(&owner, &hash, &controller);
+        client.add_document_reference(&controller, &hash, &uri);
+        client.set_document_subject(&owner, &hash, &subject);
+        client.witness_document(&Address::generate(&env), &hash);
+        client.pin_document(&hash);
+        client.set_legal_hold(&hash, &true);
+        client.store_document(&owner, &name, &hash_n(&env, 2));
+        client.supersede_document(&owner, &hash, &hash_n(&env, 2));
+
+        let full = client.get_document_full(&hash).unwrap();
+        assert_eq!(full.document, client.read_document(&hash).unwrap());
+        assert_eq!(full.verification, client.verify_document(&hash).unwrap());
+        assert_eq!(full.verification.status, DocumentStatus::Active);
+        assert_eq!(full.verification.witness_count, 1);
+        assert_eq!(full.controllers, Vec::from_array(&env, [owner.clone(), controller]));
+        assert_eq!(full.references, Vec::from_array(&env, [uri]));
+        assert_eq!(full.category, Some(category));
+        assert_eq!(full.subject, Some(subject));
+        assert!(full.pinned && full.legal_hold);
+        assert_eq!(full.superseded_by, Some(hash_n(&env, 2)));
+        assert_eq!(full.update.updated_by, owner);
+        assert_eq!(full.update, client.get_update_info(&hash).unwrap());
+    }
+
+    #[test]
+    fn get_document_full_defaults_unused_features() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "Plain.pdf"), &hash);
+
+        let full = client.get_document_full(&hash).unwrap();
+        assert_eq!(full.controllers, Vec::from_array(&env, [owner.clone()]));
+        assert!(full.references.is_empty());
+        assert_eq!(full.category, None);
+        assert_eq!(full.subject, None);
+        assert!(!full.pinned && !full.legal_hold);
+        assert_eq!(full.superseded_by, None);
+        assert_eq!(full.update.update_count, 0);
+        assert_eq!(client.get_document_full(&hash_n(&env, 2)), None);
+    }
+
+    // ---------- STATUS LISTINGS ----------
+
+    #[test]
+    fn list_by_status_follows_transitions() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.set_config(&Config {
+            veto_window_secs: 100,
+            ..client.get_config()
+        });
+        let name = String::from_str(&env, "Status.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.store_document(&user, &name, &hash_n(&env, 2));
+        let one = Vec::from_array(&env, [hash_n(&env, 1)]);
+        let two = Vec::from_array(&env, [hash_n(&env, 2)]);
+
+        assert_eq!(client.list_by_status(&DocumentStatus::Active, &0, &10), one);
+        assert_eq!(client.list_by_status(&DocumentStatus::Pending, &0, &10), two);
+
+        // Pending is computed: it lapses into Active with the window
+        advance_time(&env, 101);
+        assert!(client.list_by_status(&DocumentStatus::Pending, &0, &10).is_empty());
+        assert_eq!(client.list_by_status(&DocumentStatus::Active, &0, &10).len(), 2);
+
+        client.revoke_document(&owner, &hash_n(&env, 1));
+        assert_eq!(client.list_by_status(&DocumentStatus::Active, &0, &10), two);
+        assert_eq!(client.list_by_status(&DocumentStatus::Revoked, &0, &10), one);
+        assert!(client.list_by_status(&DocumentStatus::Revoked, &1, &10).is_empty());
+    }
+
+    #[test]
+    fn documents_in_range_span_two_calls() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let name = String::from_str(&env, "Audit.pdf");
+        for n in 1..=5 {
+            client.store_document(&owner, &name, &hash_n(&env, n));
+            advance_time(&env, 100);
+        }
+        // Timestamps 0, 100, .., 400 past the start; ask for 100..=300
+        let (from, to) = (1_800_000_100, 1_800_000_301);
+
+        let (first, cursor) = client.list_documents_in_range(&from, &to, &0, &3);
+        assert_eq!(first.len(), 2);
+        assert_eq!(first.get(0).unwrap().hash, hash_n(&env, 2));
+        assert_eq!(cursor, Some(3));
+
+        let (rest, cursor) = client.list_documents_in_range(&from, &to, &3, &3);
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest.get(0).unwrap().hash, hash_n(&env, 4));
+        assert_eq!(cursor, None);
+    }
+
+    #[test]
+    fn documents_in_range_rejects_bad_bounds() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        assert!(client.try_list_documents_in_range(&10, &5, &0, &10).is_err());
+        assert!(client.try_list_documents_in_range(&10, &10, &0, &10).is_err());
+    }
+
+    // ---------- BULK REVOCATION ----------
+
+    #[test]
+    fn revoke_documents_by_actor_continues_over_window() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let name = String::from_str(&env, "Incident.pdf");
+        // Stored at t0 + 0, 100, 200, 300, 400; the incident covers 100..400
+        let t0 = env.ledger().timestamp();
+        for n in 1..=5 {
+            client.store_document(&user, &name, &hash_n(&env, n));
+            advance_time(&env, 100);
+        }
+        client.revoke_document(&user, &hash_n(&env, 3));
+
+        let (after, before) = (t0 + 100, t0 + 400);
+        assert_eq!(client.revoke_documents_by_actor(&user, &after, &before, &0, &3), (1, Some(3)));
+        assert_eq!(client.revoke_documents_by_actor(&user, &after, &before, &3, &3), (1, None));
+
+        let status = |n| client.document_status(&hash_n(&env, n)).unwrap();
+        assert_eq!(status(1), DocumentStatus::Active);
+        assert_eq!(status(2), DocumentStatus::Revoked);
+        assert_eq!(status(3), DocumentStatus::Revoked);
+        assert_eq!(status(4), DocumentStatus::Revoked);
+        assert_eq!(status(5), DocumentStatus::Active);
+        assert_eq!(client.revoked_count(), 3);
+    }
+
+    // ---------- CREATION TIME ----------
+
+    #[test]
+    fn created_at_is_kept_beside_ledger_timestamp() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let name = String::from_str(&env, "Drafted.pdf");
+        let created = 1_799_000_000;
+
+        let receipt =
+            client.store_document_with_created_at(&owner, &name, &hash_n(&env, 1), &Some(created));
+        assert_eq!(receipt.timestamp, 1_800_000_000);
+        assert_eq!(receipt.created_at, Some(created));
+        assert_eq!(client.get_receipt(&hash_n(&env, 1)), Some(receipt));
+        let verified = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert_eq!((verified.timestamp, verified.created_at), (1_800_000_000, Some(created)));
+        assert_eq!(client.document_created_at(&hash_n(&env, 1)), Some(created));
+
+        let plain = client.store_document_with_created_at(&owner, &name, &hash_n(&env, 2), &None);
+        assert_eq!(plain.created_at, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "created_at in the future")]
+    fn created_at_in_the_future_is_rejected() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let name = String::from_str(&env, "Later.pdf");
+        let future = Some(1_800_000_001);
+        client.store_document_with_created_at(&owner, &name, &hash_n(&env, 1), &future);
+    }
+
+    // ---------- JURISDICTION & LANGUAGE ----------
+
+    #[test]
+    fn jurisdiction_tags_drive_filtered_listing() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let (de, fr, lang) = (symbol_short!("DE"), symbol_short!("FR"), symbol_short!("de"));
+        client.add_allowed_jurisdiction(&de);
+        client.add_allowed_jurisdiction(&fr);
+        let name = String::from_str(&env, "Local.pdf");
+        client.store_document_localized(&owner, &name, &hash_n(&env, 1), &Some(de.clone()), &None);
+        client.store_document_localized(
+            &owner,
+            &name,
+            &hash_n(&env, 2),
+            &Some(de.clone()),
+            &Some(lang.clone()),
+        );
+        client.store_document(&owner, &name, &hash_n(&env, 3));
+
+        let unspecified = Symbol::new(&env, "unspecified");
+        assert_eq!(client.document_jurisdiction(&hash_n(&env, 2)), de);
+        assert_eq!(client.document_language(&hash_n(&env, 2)), lang);
+        assert_eq!(client.document_language(&hash_n(&env, 1)), unspecified);
+        assert_eq!(client.document_jurisdiction(&hash_n(&env, 3)), unspecified);
+        assert_eq!(client.list_by_jurisdiction(&de, &0, &10).len(), 2);
+
+        // Re-tagging moves the document between listings, and back without duplicates
+        client.set_document_locale(&owner, &hash_n(&env, 1), &Some(fr.clone()), &None);
+        let listed = client.list_by_jurisdiction(&de, &0, &10);
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed.get(0).unwrap().hash, hash_n(&env, 2));
+        assert_eq!(client.list_by_jurisdiction(&fr, &0, &10).len(), 1);
+        client.set_document_locale(&owner, &hash_n(&env, 1), &Some(de.clone()), &None);
+        assert_eq!(client.list_by_jurisdiction(&de, &0, &10).len(), 2);
+        assert!(client.list_by_jurisdiction(&fr, &0, &10).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "jurisdiction not allowed")]
+    fn jurisdiction_outside_allowed_set_is_rejected() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        client.add_allowed_jurisdiction(&symbol_short!("DE"));
+        let name = String::from_str(&env, "Local.pdf");
+        let us = Some(symbol_short!("US"));
+        client.store_document_localized(&owner, &name, &hash_n(&env, 1), &us, &None);
+    }
+
+    // ---------- GUARDIAN RECOVERY ----------
+
+    /// 2-of-3 guardian set with a one-hour delay; returns the guardians
+    fn setup_guardians(env: &Env, client: &ContractClient) -> [Address; 3] {
+        let guardians = [Address::generate(env), Address::generate(env), Address::generate(env)];
+        client.set_guardians(&Vec::from_array(env, guardians.clone()), &2);
+        client.set_config(&Config {
+            recovery_delay_secs: 3_600,
+            ..client.get_config()
+        });
+        guardians
+    }
+
+    #[test]
+    fn two_of_three_guardians_recover_ownership() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let [g1, g2, _g3] = setup_guardians(&env, &client);
+        let new_owner = Address::generate(&env);
+
+        client.initiate_recovery(&g1, &new_owner);
+        let topics = (symbol_short!("recovery"), symbol_short!("initiated"), new_owner.clone());
+        assert_last_event(&env, topics.into_val(&env), g1.into_val(&env));
+        assert!(client.try_finalize_recovery().is_err());
+        client.support_recovery(&g2);
+        advance_time(&env, 3_600);
+        client.finalize_recovery();
+
+        assert_eq!(client.owner_address(), new_owner);
+        assert_eq!(client.get_recovery(), None);
+        let topics = (symbol_short!("recovery"), symbol_short!("finalized"), new_owner);
+        assert_last_event(&env, topics.into_val(&env), owner.into_val(&env));
+    }
+
+    #[test]
+    fn owner_can_veto_recovery() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let [g1, g2, g3] = setup_guardians(&env, &client);
+        let attacker = Address::generate(&env);
+
+        client.initiate_recovery(&g1, &attacker);
+        client.support_recovery(&g2);
+        client.veto_recovery();
+        advance_time(&env, 3_600);
+        assert!(client.try_finalize_recovery().is_err());
+        assert!(client.try_support_recovery(&g3).is_err());
+        assert_eq!(client.owner_address(), owner);
+    }
+
+    #[test]
+    #[should_panic(expected = "registry sealed")]
+    fn veto_recovery_after_seal_panics() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let [g1, _, _] = setup_guardians(&env, &client);
+
+        client.initiate_recovery(&g1, &Address::generate(&env));
+        client.seal_registry();
+        client.veto_recovery();
+    }
+
+    #[test]
+    #[should_panic(expected = "recovery delay not elapsed")]
+    fn premature_recovery_finalize_panics() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let [g1, g2, _g3] = setup_guardians(&env, &client);
+        client.initiate_recovery(&g1, &Address::generate(&env));
+        client.support_recovery(&g2);
+        advance_time(&env, 3_599);
+        client.finalize_recovery();
+    }
+
+    #[test]
+    fn guardian_set_is_validated() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let g = Address::generate(&env);
+        let dup = Vec::from_array(&env, [g.clone(), g.clone()]);
+        assert!(client.try_set_guardians(&dup, &1).is_err());
+        assert!(client.try_set_guardians(&Vec::from_array(&env, [g.clone()]), &2).is_err());
+        assert!(client.try_initiate_recovery(&g, &Address::generate(&env)).is_err());
+    }
+
+    // ---------- VALIDATION RULES ----------
+
+    fn rules(env: &Env, hash_lengths: &[u32], require_hex: bool) -> ValidationRules {
+        let mut lengths = Vec::new(env);
+        for n in hash_lengths {
+            lengths.push_back(*n);
+        }
+        ValidationRules {
+            min_name_len: 0,
+            max_name_len: 256,
+            name_charset: NameCharset::Any,
+            require_extension: false,
+            hash_lengths: lengths,
+            require_hex,
+        }
+    }
+
+    #[test]
+    fn validation_rules_accept_legacy_sha1_digests() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        client.set_validation_rules(&rules(&env, &[40, 64], true));
+        let name = String::from_str(&env, "Legacy.pdf");
+
+        let sha1 = String::from_str(&env, &"a1".repeat(20));
+        client.store_document(&owner, &name, &sha1);
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        let short = String::from_str(&env, &"a1".repeat(16));
+        assert!(client.try_store_document(&owner, &name, &short).is_err());
+        let not_hex = String::from_str(&env, &"zz".repeat(20));
+        assert!(client.try_store_document(&owner, &name, &not_hex).is_err());
+        assert_eq!(client.get_validation_rules(), rules(&env, &[40, 64], true));
+    }
+
+    #[test]
+    fn validation_rules_enforce_filename_names() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        client.set_validation_rules(&ValidationRules {
+            min_name_len: 5,
+            max_name_len: 32,
+            name_charset: NameCharset::Filename,
+            require_extension: true,
+            ..rules(&env, &[64], true)
+        });
+
+        let ok = ["Annual_Report-2026.pdf", "a b.txt"];
+        for (i, name) in ok.iter().enumerate() {
+            client.store_document(&owner, &String::from_str(&env, name), &hash_n(&env, i as u32));
+        }
+        let bad = ["README", "report.", ".hidden", "a/b.pdf", "x.pd", "Résumé.pdf"];
+        for (i, name) in bad.iter().enumerate() {
+            let hash = hash_n(&env, 10 + i as u32);
+            let name = String::from_str(&env, name);
+            assert!(client.try_store_document(&owner, &name, &hash).is_err());
+        }
+    }
+
+    #[test]
+    fn validation_rules_apply_to_new_registrations_only() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let legacy = String::from_str(&env, "doc-1");
+        client.store_document(&owner, &String::from_str(&env, "Ünïcode"), &legacy);
+
+        // Printable names, 64-char hex hashes only
+        client.set_validation_rules(&ValidationRules {
+            name_charset: NameCharset::Printable,
+            ..rules(&env, &[64], true)
+        });
+        assert!(client.verify_document(&legacy).unwrap().verified_document);
+        let name = String::from_str(&env, "Ünïcode");
+        assert!(client.try_store_document(&owner, &name, &hash_n(&env, 1)).is_err());
+        let ns = symbol_short!("ns");
+        client.create_namespace(&ns, &owner);
+        let plain = String::from_str(&env, "Plain name.pdf");
+        assert!(client.try_store_document_ns(&ns, &owner, &plain, &legacy).is_err());
+        client.store_document_ns(&ns, &owner, &plain, &hash_n(&env, 1));
+    }
+
+    #[test]
+    fn insane_validation_rules_are_rejected() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let inverted = ValidationRules {
+            min_name_len: 10,
+            max_name_len: 5,
+            ..rules(&env, &[], false)
+        };
+        assert!(client.try_set_validation_rules(&inverted).is_err());
+        let huge = ValidationRules { max_name_len: 257, ..rules(&env, &[], false) };
+        assert!(client.try_set_validation_rules(&huge).is_err());
+        assert!(client.try_set_validation_rules(&rules(&env, &[0], false)).is_err());
+        assert!(client.try_set_validation_rules(&rules(&env, &[129], false)).is_err());
+    }
+
+    // ---------- TESTUTILS ----------
+
+    #[test]
+    fn test_registry_seeds_documents_and_whitelist() {
+        let env = Env::default();
+        let registry = testutils::TestRegistry::new(&env);
+        env.mock_all_auths();
+        let users = [Address::generate(&env), Address::generate(&env)];
+        let hash = "0".repeat(63) + "1";
+        registry.seed_documents(&[("Seed.pdf", hash.as_str()), ("Other.pdf", "abc123")]);
+        registry.seed_whitelist(&users);
+
+        let client = registry.client();
+        assert_eq!(client.owner_address(), *registry.owner());
+        assert_eq!(client.document_count(), 2);
+        let doc = client.read_document(&hash_n(&env, 1)).unwrap();
+        assert_eq!(doc.name, String::from_str(&env, "Seed.pdf"));
+        assert_eq!(doc.added_by, *registry.owner());
+        assert!(users.iter().all(|u| client.is_whitelisted(u)));
+        assert_eq!(client.address, *registry.address());
+    }
+
+    #[test]
+    fn test_registry_leaves_auth_enforced() {
+        let env = Env::default();
+        let registry = testutils::TestRegistry::new(&env);
+        let client = registry.client();
+        assert!(client.try_whitelist_address(&Address::generate(&env)).is_err());
+        assert_eq!(client.owner_address(), *registry.owner());
+    }
+
+    // ---------- UNIQUE NAMES ----------
+
+    fn enable_unique_names(client: &ContractClient) {
+        client.set_config(&Config {
+            unique_names: true,
+            ..client.get_config()
+        });
+    }
+
+    #[test]
+    fn duplicate_names_allowed_by_default() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let name = String::from_str(&env, "Same.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.store_document(&owner, &name, &hash_n(&env, 2));
+        client.update_document_name(&owner, &hash_n(&env, 2), &name);
+        assert_eq!(client.document_by_name(&name), Some(hash_n(&env, 1)));
+    }
+
+    #[test]
+    fn unique_names_rejects_reuse_on_store_and_rename() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        enable_unique_names(&client);
+        let name = String::from_str(&env, "Unique.pdf");
+        let other = String::from_str(&env, "Other.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.store_document(&owner, &other, &hash_n(&env, 2));
+
+        assert!(client.try_store_document(&owner, &name, &hash_n(&env, 3)).is_err());
+        assert!(client.try_update_document_name(&owner, &hash_n(&env, 2), &name).is_err());
+
+        // Renaming frees the old name for others
+        let renamed = String::from_str(&env, "Renamed.pdf");
+        client.update_document_name(&owner, &hash_n(&env, 1), &renamed);
+        assert_eq!(client.read_document(&hash_n(&env, 1)).unwrap().name, renamed);
+        client.update_document_name(&owner, &hash_n(&env, 2), &name);
+        assert_eq!(client.document_by_name(&name), Some(hash_n(&env, 2)));
+        assert_eq!(client.document_by_name(&other), None);
+    }
+
+    #[test]
+    fn enabling_unique_names_keeps_existing_duplicates() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let name = String::from_str(&env, "Twin.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.store_document(&owner, &name, &hash_n(&env, 2));
+
+        enable_unique_names(&client);
+        for n in 1..=2 {
+            assert_eq!(client.read_document(&hash_n(&env, n)).unwrap().name, name);
+        }
+        assert!(client.try_store_document(&owner, &name, &hash_n(&env, 3)).is_err());
+        // The duplicate holder may keep its own name
+        client.update_document_name(&owner, &hash_n(&env, 2), &name);
+    }
+
+    // ---------- RENAMES ----------
+
+    #[test]
+    fn rename_emits_old_and_new_names_and_counts() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let hash = hash_n(&env, 1);
+        let old = String::from_str(&env, "Draft.pdf");
+        let new = String::from_str(&env, "Final.pdf");
+        client.store_document(&owner, &old, &hash);
+        assert_eq!(client.read_document(&hash).unwrap().rename_count, 0);
+
+        client.update_document_name(&owner, &hash, &new);
+        let topics = (symbol_short!("document"), symbol_short!("renamed"), hash.clone());
+        assert_last_event(&env, topics.into_val(&env), (old, new.clone()).into_val(&env));
+        let doc = client.read_document(&hash).unwrap();
+        assert_eq!((doc.name, doc.rename_count), (new.clone(), 1));
+
+        // Keeping the name is a no-op
+        let seq = client.last_event_seq();
+        client.update_document_name(&owner, &hash, &new);
+        assert_eq!(client.last_event_seq(), seq);
+        assert_eq!(client.read_document(&hash).unwrap().rename_count, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "rename limit reached")]
+    fn renames_are_capped() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "v0.pdf"), &hash);
+        let names = ["v1.pdf", "v2.pdf", "v3.pdf", "v4.pdf", "v5.pdf", "v6.pdf"];
+        let more = ["v7.pdf", "v8.pdf", "v9.pdf", "v10.pdf", "v11.pdf"];
+        for (i, name) in names.iter().chain(more.iter()).enumerate() {
+            if i == MAX_RENAMES as usize {
+                assert_eq!(client.read_document(&hash).unwrap().rename_count, MAX_RENAMES);
+            }
+            client.update_document_name(&owner, &hash, &String::from_str(&env, name));
+        }
+    }
+
+    #[test]
+    fn rename_survives_tightened_hash_rules() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "Old.pdf"), &hash);
+        // The stored hash no longer matches the accepted lengths
+        client.set_validation_rules(&rules(&env, &[32], true));
+
+        let name = String::from_str(&env, "New.pdf");
+        client.update_document_name(&owner, &hash, &name);
+        assert_eq!(client.read_document(&hash).unwrap().name, name);
+    }
+
+    // ---------- DOCUMENT IDS ----------
+
+    #[test]
+    fn document_id_matches_test_vector() {
+        let env = Env::default();
+        let (hash, _name, _timestamp, _added_by, contract) = digest_vector(&env);
+        let id = Contract::derive_document_id(&env, &contract, &hash);
+        let vector = [
+            0x2f, 0xa3, 0x7e, 0x35, 0x4d, 0x14, 0xa2, 0xf0, 0x2c, 0x02, 0xf0, 0x09, 0xe6, 0xfa,
+            0x17, 0x60, 0xce, 0xaf, 0xd2, 0xe1, 0x27, 0x10, 0xd6, 0x53, 0xe3, 0x01, 0xa2, 0xaa,
+            0xb5, 0xe8, 0x3c, 0xd1,
+        ];
+        assert_eq!(id.to_array(), vector);
+
+        // Same preimage written out by hand: 40-byte address XDR, then raw hash
+        let mut preimage = std::vec::Vec::new();
+        preimage.extend_from_slice(&[0, 0, 0, 18, 0, 0, 0, 1]);
+        preimage.extend_from_slice(&[1u8; 32]);
+        preimage.extend_from_slice("ab".repeat(32).as_bytes());
+        let expected: BytesN<32> = env.crypto().sha256(&Bytes::from_slice(&env, &preimage)).into();
+        assert_eq!(id, expected);
+    }
+
+    #[test]
+    fn lookups_by_id_and_hash_agree() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let hash = hash_n(&env, 1);
+        let receipt = client.store_document(&owner, &String::from_str(&env, "Id.pdf"), &hash);
+        assert_eq!(receipt.doc_id, client.document_id(&hash));
+
+        let by_id = client.read_document_by_id(&receipt.doc_id).unwrap();
+        let by_hash = client.read_document(&hash).unwrap();
+        assert_eq!((by_id.hash, by_id.name), (by_hash.hash, by_hash.name));
+        assert_eq!(client.verify_document_by_id(&receipt.doc_id), client.verify_document(&hash));
+        assert_eq!(client.get_receipt(&hash).unwrap().doc_id, receipt.doc_id);
+
+        let unknown = client.document_id(&hash_n(&env, 2));
+        assert!(client.read_document_by_id(&unknown).is_none());
+        assert_eq!(client.verify_document_by_id(&unknown), None);
+    }
+
+    // ---------- RESERVED PREFIXES ----------
+
+    #[test]
+    fn reserved_prefix_limits_whitelisted_actors() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.reserve_name_prefix(&String::from_str(&env, "OFFICIAL-"));
+
+        let official = String::from_str(&env, "OFFICIAL-Notice.pdf");
+        assert!(client.try_store_document(&user, &official, &hash_n(&env, 1)).is_err());
+        // Byte-wise: a different case is not the reserved prefix
+        let lower = String::from_str(&env, "official-copy.pdf");
+        client.store_document(&user, &lower, &hash_n(&env, 2));
+        client.store_document(&user, &String::from_str(&env, "OFFICIAL"), &hash_n(&env, 3));
+        assert!(client.try_update_document_name(&user, &hash_n(&env, 2), &official).is_err());
+
+        client.store_document(&owner, &official, &hash_n(&env, 1));
+        client.release_name_prefix(&String::from_str(&env, "OFFICIAL-"));
+        let released = String::from_str(&env, "OFFICIAL-Copy.pdf");
+        client.store_document(&user, &released, &hash_n(&env, 4));
+        assert!(client.reserved_name_prefixes().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "reserved prefix")]
+    fn reserved_prefix_panics_for_whitelisted_store() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        client.reserve_name_prefix(&String::from_str(&env, "OFFICIAL-"));
+        let name = String::from_str(&env, "OFFICIAL-Fake.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
+    }
+
+    #[test]
+    fn category_counts_follow_recategorization_and_deletes() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let finance = symbol_short!("finance");
+        let legal = symbol_short!("legal");
+        let name = String::from_str(&env, "Ledger.pdf");
+        client.store_document_categorized(&user, &name, &hash_n(&env, 1), &finance);
+        client.store_document_categorized(&user, &name, &hash_n(&env, 2), &finance);
+        client.store_document(&user, &name, &hash_n(&env, 3));
+        assert_eq!(client.count_by_category(&finance), 2);
+        assert_eq!(client.count_by_category(&legal), 0);
+
+        client.recategorize_document(&hash_n(&env, 1), &legal);
+        client.recategorize_document(&hash_n(&env, 3), &legal);
+        assert_eq!(client.document_category(&hash_n(&env, 1)), Some(legal.clone()));
+        assert_eq!(client.count_by_category(&finance), 1);
+        assert_eq!(client.count_by_category(&legal), 2);
+
+        client.delete_own_document(&user, &hash_n(&env, 1));
+        let mut expected = Vec::new(&env);
+        expected.push_back((finance, 1u64));
+        expected.push_back((legal, 1u64));
+        assert_eq!(client.category_breakdown(), expected);
+    }
+
+    #[test]
+    fn recategorize_to_same_category_keeps_count() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let memo = symbol_short!("memo");
+        let name = String::from_str(&env, "Memo.txt");
+        client.store_document_categorized(&owner, &name, &hash_n(&env, 1), &memo);
+        client.recategorize_document(&hash_n(&env, 1), &memo);
+        assert_eq!(client.count_by_category(&memo), 1);
+    }
+
+    // ---------- TSA PROOFS ----------
+
+    #[test]
+    fn tsa_proof_attached_after_store_is_surfaced() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "Deed.pdf"), &hash);
+        assert_eq!(client.read_document(&hash).unwrap().tsa_proof, TsaAttachment::None);
+
+        let token_hash = BytesN::from_array(&env, &[9u8; 32]);
+        client.attach_tsa_proof(&owner, &hash, &token_hash, &1_799_999_000);
+        let proof = TsaProof { token_hash, tsa_time: 1_799_999_000 };
+        let attached = TsaAttachment::Attached(proof.clone());
+        assert_eq!(client.read_document(&hash).unwrap().tsa_proof, attached);
+        assert_eq!(client.verify_document(&hash).unwrap().tsa_proof, attached);
+        assert_eq!(client.document_tsa_proof(&hash), Some(proof));
+    }
+
+    #[test]
+    fn tsa_proof_can_be_given_at_store_time() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let name = String::from_str(&env, "Deed.pdf");
+        let token_hash = BytesN::from_array(&env, &[3u8; 32]);
+        let hash = hash_n(&env, 1);
+        client.store_document_with_tsa_proof(&owner, &name, &hash, &token_hash, &1_800_000_000);
+        let proof = client.read_document(&hash).unwrap().tsa_proof.proof().unwrap();
+        assert_eq!(proof.token_hash, token_hash);
+    }
+
+    #[test]
+    #[should_panic(expected = "tsa proof already attached")]
+    fn tsa_proof_is_single_use() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "Deed.pdf"), &hash);
+        let token_hash = BytesN::from_array(&env, &[9u8; 32]);
+        client.attach_tsa_proof(&owner, &hash, &token_hash, &1_799_999_000);
+        client.attach_tsa_proof(&owner, &hash, &token_hash, &1_799_999_500);
+    }
+
+    // ---------- VIEW COUNTS ----------
+
+    #[test]
+    fn record_view_counts_once_per_ledger() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "Brochure.pdf"), &hash);
+        client.read_document(&hash);
+        assert_eq!(client.get_view_count(&hash), 0);
+
+        assert!(client.record_view(&hash));
+        assert!(!client.record_view(&hash));
+        assert_eq!(client.get_view_count(&hash), 1);
+
+        advance_ledgers(&env, 1);
+        assert!(client.record_view(&hash));
+        assert_eq!(client.get_view_count(&hash), 2);
+    }
+
+    #[test]
+    fn approved_whitelist_request_whitelists_the_applicant() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let partner = Address::generate(&env);
+
+        client.request_whitelist(&partner, &String::from_str(&env, "Acme legal team"));
+        let request = client.get_whitelist_request(&partner).unwrap();
+        assert_eq!(request.expires_at, 1_800_000_000 + DEFAULT_WHITELIST_REQUEST_TTL_SECS);
+        assert_eq!(client.list_whitelist_requests(&0, &10).items, Vec::from_array(&env, [request]));
+
+        client.approve_whitelist_request(&partner);
+        assert!(client.is_whitelisted(&partner));
+        assert_eq!(client.get_whitelist_request(&partner), None);
+        assert!(client.list_whitelist_requests(&0, &10).items.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "whitelist already requested")]
+    fn duplicate_whitelist_request_rejected() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let partner = Address::generate(&env);
+        client.request_whitelist(&partner, &String::from_str(&env, "first"));
+        client.request_whitelist(&partner, &String::from_str(&env, "second"));
+    }
+
+    #[test]
+    fn denied_whitelist_request_is_closed() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let partner = Address::generate(&env);
+        client.request_whitelist(&partner, &String::from_str(&env, "please"));
+        let reason = String::from_str(&env, "unknown org");
+        client.deny_whitelist_request(&partner, &reason);
+
+        let action = Symbol::new(&env, "request_denied");
+        let topics = (symbol_short!("whitelist"), action, partner.clone());
+        assert_last_event(&env, topics.into_val(&env), reason.into_val(&env));
+        assert!(!client.is_whitelisted(&partner));
+        assert_eq!(client.get_whitelist_request(&partner), None);
+        assert!(client.try_approve_whitelist_request(&partner).is_err());
+    }
+
+    #[test]
+    fn expired_whitelist_request_cannot_be_approved() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        client.set_config(&Config { whitelist_request_ttl_secs: 60, ..client.get_config() });
+        let partner = Address::generate(&env);
+        let note = String::from_str(&env, "please");
+        client.request_whitelist(&partner, &note);
+        advance_time(&env, 61);
+
+        assert_eq!(client.get_whitelist_request(&partner), None);
+        assert!(client.try_approve_whitelist_request(&partner).is_err());
+
+        // A lapsed request can be replaced
+        client.request_whitelist(&partner, &note);
+        client.approve_whitelist_request(&partner);
+        assert!(client.is_whitelisted(&partner));
+    }
+
+    #[test]
+    fn access_stake_gates_whitelisted_stores() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let partner = Address::generate(&env);
+        client.whitelist_address(&partner);
+        let token = fee_token(&env, &partner, 1_000);
+        client.set_config(&Config {
+            access_stake: FeeSetting::Fixed(Fee { token: token.clone(), amount: 500 }),
+            ..client.get_config()
+        });
+
+        let name = String::from_str(&env, "Spec.pdf");
+        assert!(client.try_store_document(&partner, &name, &hash_n(&env, 1)).is_err());
+        client.stake_for_access(&partner, &token, &500);
+        assert_eq!(token::Client::new(&env, &token).balance(&contract_addr), 500);
+        client.store_document(&partner, &name, &hash_n(&env, 1));
+    }
+
+    #[test]
+    fn slash_moves_stake_to_owner() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let partner = Address::generate(&env);
+        let token = fee_token(&env, &partner, 1_000);
+        client.stake_for_access(&partner, &token, &400);
+
+        client.slash(&partner, &150, &String::from_str(&env, "forged upload"));
+        assert_eq!(client.get_stake(&partner).unwrap().amount, 250);
+        assert_eq!(token::Client::new(&env, &token).balance(&owner), 150);
+        let reason = String::from_str(&env, "again");
+        assert!(client.try_slash(&partner, &251, &reason).is_err());
+    }
+
+    #[test]
+    fn unstake_waits_for_removal_and_cooldown() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let partner = Address::generate(&env);
+        client.whitelist_address(&partner);
+        let token = fee_token(&env, &partner, 1_000);
+        client.stake_for_access(&partner, &token, &300);
+        advance_time(&env, DEFAULT_STAKE_COOLDOWN_SECS);
+        assert!(client.try_unstake(&partner).is_err());
+
+        client.remove_from_whitelist(&partner);
+        advance_time(&env, DEFAULT_STAKE_COOLDOWN_SECS - 1);
+        assert!(client.try_unstake(&partner).is_err());
+        advance_time(&env, 1);
+        assert_eq!(client.unstake(&partner), 300);
+        assert_eq!(token::Client::new(&env, &token).balance(&partner), 1_000);
+        assert_eq!(client.get_stake(&partner), None);
+    }
+
+    #[test]
+    fn estimate_entry_size_pins_formula() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        let hash = hash_n(&env, 1);
+        // 64 + (4 + 8) + 3 * (4 + 64)
+        assert_eq!(client.estimate_entry_size(&String::from_str(&env, "Spec.pdf"), &hash), 280);
+        // name padded from 5 to 8 bytes
+        assert_eq!(client.estimate_entry_size(&String::from_str(&env, "a.txt"), &hash), 280);
+        // name padded from 13 to 16 bytes
+        let name = String::from_str(&env, "Contract2.pdf");
+        assert_eq!(client.estimate_entry_size(&name, &hash), 288);
+        // short hash: 64 + 12 + 3 * (4 + 8)
+        let short = String::from_str(&env, "deadbeef");
+        assert_eq!(client.estimate_entry_size(&String::from_str(&env, "a.txt"), &short), 112);
+    }
+
+    #[test]
+    #[should_panic(expected = "hash length not allowed")]
+    fn estimate_entry_size_applies_validation_rules() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        client.estimate_entry_size(&String::from_str(&env, "a.txt"), &String::from_str(&env, ""));
+    }
+
+    #[test]
+    fn quote_store_fee_matches_the_charge() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let token = fee_token(&env, &user, 1_000);
+        let balance = token::Client::new(&env, &token);
+        client.set_config(&Config {
+            registration_fee: FeeSetting::Fixed(Fee { token: token.clone(), amount: 10 }),
+            fee_per_byte: 2,
+            ..client.get_config()
+        });
+
+        let short = String::from_str(&env, "a.txt");
+        let quote = client.quote_store_fee(&short, &hash_n(&env, 1));
+        assert_eq!(quote, 20);
+        client.store_document(&user, &short, &hash_n(&env, 1));
+        assert_eq!(balance.balance(&owner), quote);
+
+        let long = String::from_str(&env, "Quarterly-board-minutes-2026.pdf");
+        let quote = client.quote_store_fee(&long, &hash_n(&env, 2));
+        assert_eq!(quote, 74);
+        client.store_document(&user, &long, &hash_n(&env, 2));
+        assert_eq!(balance.balance(&owner), 20 + quote);
+        assert_eq!(balance.balance(&user), 1_000 - 20 - quote);
+    }
+
+    #[test]
+    fn batch_store_charges_per_byte_for_each_name() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let token = fee_token(&env, &user, 1_000);
+        client.set_config(&Config {
+            registration_fee: FeeSetting::Fixed(Fee { token: token.clone(), amount: 0 }),
+            fee_per_byte: 1,
+            ..client.get_config()
+        });
+        let mut docs = Vec::new(&env);
+        for (i, name) in ["a.txt", "Contract2.pdf"].iter().enumerate() {
+            let name = String::from_str(&env, name);
+            docs.push_back(DocumentInput { name, hash: hash_n(&env, i as u32 + 1) });
+        }
+        client.store_documents(&user, &docs);
+        assert_eq!(token::Client::new(&env, &token).balance(&owner), 5 + 13);
+    }
+
+    fn discount_tiers(env: &Env) -> Vec<DiscountTier> {
+        Vec::from_array(
+            env,
+            [
+                DiscountTier { min_docs: 10, discount_bps: 1_000 },
+                DiscountTier { min_docs: 50, discount_bps: 2_500 },
+            ],
+        )
+    }
+
+    #[test]
+    fn quote_batch_fee_uses_one_tier_and_rounds_down() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let token = fee_token(&env, &Address::generate(&env), 0);
+        client.set_config(&Config {
+            registration_fee: FeeSetting::Fixed(Fee { token, amount: 7 }),
+            ..client.get_config()
+        });
+        client.set_discount_tiers(&discount_tiers(&env));
+
+        assert_eq!(client.quote_batch_fee(&9), 63);
+        assert_eq!(client.quote_batch_fee(&10), 63);
+        // 77 * 0.9 = 69.3
+        assert_eq!(client.quote_batch_fee(&11), 69);
+        // 350 * 0.75 = 262.5
+        assert_eq!(client.quote_batch_fee(&50), 262);
+    }
+
+    #[test]
+    fn store_documents_charges_the_discounted_quote() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let token = fee_token(&env, &user, 1_000);
+        client.set_config(&Config {
+            registration_fee: FeeSetting::Fixed(Fee { token: token.clone(), amount: 7 }),
+            ..client.get_config()
+        });
+        client.set_discount_tiers(&discount_tiers(&env));
+
+        let mut docs = Vec::new(&env);
+        for i in 1..=11 {
+            let name = String::from_str(&env, "Batch.pdf");
+            docs.push_back(DocumentInput { name, hash: hash_n(&env, i) });
+        }
+        client.store_documents(&user, &docs);
+        let paid = token::Client::new(&env, &token).balance(&owner);
+        assert_eq!(paid, client.quote_batch_fee(&11));
+        assert_eq!(paid, 69);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid discount tiers")]
+    fn discount_tiers_must_ascend() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let tiers = Vec::from_array(
+            &env,
+            [
+                DiscountTier { min_docs: 50, discount_bps: 2_500 },
+                DiscountTier { min_docs: 10, discount_bps: 1_000 },
+            ],
+        );
+        client.set_discount_tiers(&tiers);
+    }
+
+    #[test]
+    fn event_level_shapes_the_stored_event() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let name = String::from_str(&env, "Level.pdf");
+        let stored = |n: u32| (symbol_short!("document"), symbol_short!("stored"), hash_n(&env, n));
+
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        assert_last_event(&env, stored(1).into_val(&env), owner.clone().into_val(&env));
+
+        client.set_config(&Config { event_level: EventLevel::Minimal, ..client.get_config() });
+        client.store_document(&owner, &name, &hash_n(&env, 2));
+        assert_last_event(&env, stored(2).into_val(&env), ().into_val(&env));
+
+        client.set_config(&Config { event_level: EventLevel::Verbose, ..client.get_config() });
+        client.store_document(&owner, &name, &hash_n(&env, 3));
+        let doc = client.read_document(&hash_n(&env, 3)).unwrap();
+        assert_last_event(&env, stored(3).into_val(&env), doc.into_val(&env));
+    }
+
+    #[test]
+    fn verbose_level_keeps_standard_payload_without_a_record() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        client.set_config(&Config { event_level: EventLevel::Verbose, ..client.get_config() });
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let topics = (symbol_short!("whitelist"), symbol_short!("added"), user.clone());
+        assert_last_event(&env, topics.into_val(&env), ().into_val(&env));
+    }
+
+    #[test]
+    fn idempotency_key_replay_returns_the_original_document() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let name = String::from_str(&env, "Job-17.pdf");
+        let key = Some(BytesN::from_array(&env, &[17u8; 32]));
+
+        let first = client.store_document_idem(&owner, &name, &hash_n(&env, 1), &key);
+        let replay = client.store_document_idem(&owner, &name, &hash_n(&env, 2), &key);
+        assert_eq!(replay, first);
+        assert_eq!(replay.hash, hash_n(&env, 1));
+        assert_eq!(client.read_document(&hash_n(&env, 2)), None);
+        assert_eq!(client.document_count(), 1);
+
+        // Without a key every call stores
+        client.store_document_idem(&owner, &name, &hash_n(&env, 3), &None);
+        assert_eq!(client.document_count(), 2);
+    }
+
+    #[test]
+    fn actor_counts_are_bucketed_by_stats_epoch() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let partner = Address::generate(&env);
+        client.whitelist_address(&partner);
+        let name = String::from_str(&env, "Report.pdf");
+        let first = client.stats_epoch();
+
+        client.store_document(&partner, &name, &hash_n(&env, 1));
+        client.store_document(&owner, &name, &hash_n(&env, 2));
+        client.store_document(&partner, &name, &hash_n(&env, 3));
+        let counts = client.list_actor_counts(&first, &0, &10);
+        assert_eq!(counts, Vec::from_array(&env, [(partner.clone(), 2), (owner.clone(), 1)]));
+
+        advance_time(&env, STATS_EPOCH_SECS);
+        let second = client.stats_epoch();
+        assert_eq!(second, first + 1);
+        client.store_document(&partner, &name, &hash_n(&env, 4));
+        assert_eq!(client.actor_count_in_epoch(&partner, &first), 2);
+        assert_eq!(client.actor_count_in_epoch(&partner, &second), 1);
+        assert_eq!(client.actor_count_in_epoch(&owner, &second), 0);
+        assert_eq!(client.list_actor_counts(&second, &0, &10).len(), 1);
+        assert_eq!(client.list_actor_counts(&first, &1, &10).len(), 1);
+    }
+
+    const MD5_HEX: &str = "9e107d9d372bb6826bd81d3542a419d6";
+
+    #[test]
+    fn legacy_import_accepts_md5_while_enabled() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let name = String::from_str(&env, "Archive-1998.doc");
+        let md5 = String::from_str(&env, MD5_HEX);
+        assert!(client.try_store_legacy_document(&name, &md5, &HashAlgo::Md5).is_err());
+
+        client.set_config(&Config { legacy_hash_import: true, ..client.get_config() });
+        client.store_legacy_document(&name, &md5, &HashAlgo::Md5);
+        let verified = client.verify_document(&md5).unwrap();
+        assert_eq!(verified.legacy_hash, HashAlgo::Md5);
+        let sha1 = "da39a3ee5e6b4b0d3255bfef95601890afd80709";
+        let other = String::from_str(&env, sha1);
+        client.store_legacy_document(&name, &other, &HashAlgo::Sha1);
+        let algo = client.verify_document(&other).unwrap().legacy_hash;
+        assert_eq!(algo, HashAlgo::Sha1);
+        // The algorithm fixes the length
+        let wrong = String::from_str(&env, &sha1[..32]);
+        assert!(client.try_store_legacy_document(&name, &wrong, &HashAlgo::Sha1).is_err());
+        assert!(client.try_store_legacy_document(&name, &wrong, &HashAlgo::None).is_err());
+        assert_eq!(client.verify_document(&hash_n(&env, 1)), None);
+    }
+
+    #[test]
+    fn regular_verification_has_no_legacy_flag() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        client.set_config(&Config { legacy_hash_import: true, ..client.get_config() });
+        client.store_document(&owner, &String::from_str(&env, "New.pdf"), &hash_n(&env, 1));
+        let verified = client.verify_document(&hash_n(&env, 1)).unwrap();
+        assert_eq!(verified.legacy_hash, HashAlgo::None);
+    }
+
+    #[test]
+    fn switching_legacy_import_off_is_permanent() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        client.set_config(&Config { legacy_hash_import: true, ..client.get_config() });
+        client.set_config(&Config { legacy_hash_import: false, ..client.get_config() });
+
+        let enable = Config { legacy_hash_import: true, ..client.get_config() };
+        assert!(client.try_set_config(&enable).is_err());
+        let name = String::from_str(&env, "Archive.doc");
+        let md5 = String::from_str(&env, MD5_HEX);
+        assert!(client.try_store_legacy_document(&name, &md5, &HashAlgo::Md5).is_err());
+    }
+
+    fn whitelist_ttl(env: &Env, contract_addr: &Address, address: &Address) -> u32 {
+        env.as_contract(contract_addr, || {
+            env.storage()
+                .persistent()
+                .get_ttl(&DataKey::Whitelist(address.clone()))
+        })
+    }
+
+    #[test]
+    fn authorizing_bumps_the_whitelist_entry() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let partner = Address::generate(&env);
+        client.whitelist_address(&partner);
+        assert!(whitelist_ttl(&env, &contract_addr, &partner) < WHITELIST_BUMP_THRESHOLD);
+        assert_eq!(client.whitelist_live_until(&partner), None);
+
+        client.store_document(&partner, &String::from_str(&env, "Ping.pdf"), &hash_n(&env, 1));
+        assert_eq!(whitelist_ttl(&env, &contract_addr, &partner), WHITELIST_BUMP_AMOUNT);
+        let seq = env.ledger().sequence();
+        assert_eq!(client.whitelist_live_until(&partner), Some(seq + WHITELIST_BUMP_AMOUNT));
+    }
+
+    #[test]
+    fn anyone_can_extend_a_whitelist_entry() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let partner = Address::generate(&env);
+        client.whitelist_address(&partner);
+        let before = whitelist_ttl(&env, &contract_addr, &partner);
+
+        // Above the threshold nothing changes
+        client.extend_whitelist_ttl(&partner, &0, &50_000);
+        assert_eq!(whitelist_ttl(&env, &contract_addr, &partner), before);
+
+        advance_ledgers(&env, 10);
+        client.extend_whitelist_ttl(&partner, &50_000, &50_000);
+        assert_eq!(whitelist_ttl(&env, &contract_addr, &partner), 50_000);
+        assert!(client.is_whitelisted(&partner));
+        let removed = Address::generate(&env);
+        assert!(client.try_extend_whitelist_ttl(&removed, &1, &50_000).is_err());
+    }
+
+    #[test]
+    fn replace_whitelist_applies_only_the_difference() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let [a, b, c, d, e] = core::array::from_fn(|_| Address::generate(&env));
+        client.whitelist_addresses(&Vec::from_array(&env, [a.clone(), b.clone(), c.clone(), d]));
+
+        let next = Vec::from_array(&env, [b.clone(), c.clone(), e.clone()]);
+        assert_eq!(client.replace_whitelist(&next), (1, 2));
+        let mut listed = Vec::new(&env);
+        for entry in client.list_whitelist_page(&0, &10).items.iter() {
+            listed.push_back(entry.address);
+        }
+        assert_eq!(listed, next);
+        assert!(!client.is_whitelisted(&a));
+        assert_eq!(client.whitelisted_count(), 3);
 
-    fn setup(env: &Env) -> (Address, Address) {
-        let owner: Address = Address::generate(env);
-        let contract_addr: Address = env.register_contract(None, Contract);
-        let client = ContractClient::new(env, &contract_addr);
-        client.init(&owner);
-        env.ledger().with_mut(|li| {
-            li.timestamp = 1_800_000_000;
-            li.sequence_number += 1;
-        });
-        (owner, contract_addr)
+        // Nothing left to change
+        assert_eq!(client.replace_whitelist(&next), (0, 0));
     }
 
+    // ---------- MAINTAINERS ----------
+
     #[test]
-    #[should_panic] // require_auth should fail without mocked auth
-    fn only_owner_can_store_panics_without_auth() {
+    fn maintainer_can_bump_but_not_store_or_whitelist() {
         let env = Env::default();
         let (owner, contract_addr) = setup(&env);
         let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let janitor = Address::generate(&env);
+        assert!(client.try_bump_instance(&janitor, &100_000).is_err());
 
-        let name = String::from_str(&env, "Confidential.pdf");
-        let hash = String::from_str(&env, "abc123");
+        client.set_maintainer(&janitor, &true);
+        assert!(client.is_maintainer(&janitor));
+        client.bump_instance(&janitor, &100_000);
+        client.store_document(&owner, &String::from_str(&env, "Keep.pdf"), &hash_n(&env, 1));
+        client.pin_document(&hash_n(&env, 1));
+        let hashes = Vec::from_array(&env, [hash_n(&env, 1)]);
+        client.bump_pinned(&janitor, &hashes, &1_000_000);
+        assert_eq!(doc_ttl(&env, &contract_addr, &hash_n(&env, 1)), 1_000_000);
 
-        // Now requires the actor argument; without mock auth this should panic.
-        client.store_document(&owner, &name, &hash);
+        let name = String::from_str(&env, "Sneaky.pdf");
+        assert!(client.try_store_document(&janitor, &name, &hash_n(&env, 2)).is_err());
+
+        client.set_maintainer(&janitor, &false);
+        assert!(client.try_bump_instance(&janitor, &100_000).is_err());
     }
 
     #[test]
-    fn store_and_verify_document_with_owner_auth() {
+    #[should_panic] // whitelisting needs the owner's auth
+    fn maintainer_cannot_whitelist() {
         let env = Env::default();
-        let (owner, contract_addr) = setup(&env);
+        let (_owner, contract_addr) = setup(&env);
         let client = ContractClient::new(&env, &contract_addr);
         env.mock_all_auths();
+        let janitor = Address::generate(&env);
+        client.set_maintainer(&janitor, &true);
 
-        let name = String::from_str(&env, "OfferLetter.pdf");
-        let hash = String::from_str(
-            &env,
-            "2d8f1bd06c6f0c2c2f2b2b4a7b3a9b2e4a5b8d6f9e0c1d3f4a6b7c8d9e0f1a2b",
-        );
-
-        // pass owner as the authorized actor
-        client.store_document(&owner, &name, &hash);
+        env.mock_auths(&[MockAuth {
+            address: &janitor,
+            invoke: &MockAuthInvoke {
+                contract: &contract_addr,
+                fn_name: "whitelist_address",
+                args: (janitor.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        client.whitelist_address(&janitor);
+    }
 
-        let stored = client.read_document(&hash).expect("document should exist");
-        assert_eq!(stored.name, name);
-        assert_eq!(stored.hash, hash);
-        assert_eq!(stored.added_by, owner);
-        assert!(stored.timestamp > 0);
+    // ---------- CONSENT ----------
 
-        let verified = client.verify_document(&hash).expect("should verify");
-        assert_eq!(verified.name, name);
-        assert_eq!(verified.hash, hash);
-        assert_eq!(verified.added_by, owner);
-        assert!(verified.timestamp > 0);
-        assert!(verified.verified_document);
+    #[test]
+    #[should_panic(expected = "no prior acknowledgment")]
+    fn consent_revocation_needs_prior_acknowledgment() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let subject = store_with_subject(&env, &client, &owner);
+        client.revoke_consent(&subject, &hash_n(&env, 1), &String::from_str(&env, "withdrawn"));
     }
 
     #[test]
-    fn store_and_verify_multiple_documents_with_owner_auth() {
+    fn revoked_consent_shows_in_verification() {
         let env = Env::default();
         let (owner, contract_addr) = setup(&env);
         let client = ContractClient::new(&env, &contract_addr);
         env.mock_all_auths();
+        let subject = store_with_subject(&env, &client, &owner);
+        let hash = hash_n(&env, 1);
+        client.witness_document(&subject, &hash);
+        assert!(!client.verify_document(&hash).unwrap().consent_revoked);
 
-        let name = String::from_str(&env, "OfferLetter.pdf");
-        let hash = String::from_str(
-            &env,
-            "2d8f1bd06c6f0c2c2f2b2b4a7b3a9b2e4a5b8d6f9e0c1d3f4a6b7c8d9e0f1a2b",
-        );
-        let hash1 = String::from_str(
-            &env,
-            "3d8f1bd06c6f0c2c2f2b2b4a7b3a9b2e4a5b8d6f9e0c1d3f4a6b7c8d9e0f1a2b",
-        );
+        let reason = String::from_str(&env, "art. 7(3)");
+        client.revoke_consent(&subject, &hash, &reason);
+        let verified = client.verify_document(&hash).unwrap();
+        assert!(verified.consent_revoked);
+        assert_eq!(verified.status, DocumentStatus::Active);
+        assert!(verified.verified_document);
+        assert!(client.try_revoke_consent(&subject, &hash, &reason).is_err());
 
-        // actor is required; use owner so no whitelist setup needed
-        client.store_document(&owner, &name, &hash);
-        client.store_document(&owner, &name, &hash1);
+        let note = String::from_str(&env, "forwarded to DPO");
+        client.annotate_consent_revocation(&hash, &note);
+        let record = client.get_consent_revocation(&hash).unwrap();
+        assert_eq!((record.reason, record.owner_note), (reason, note));
+    }
 
-        let stored = client.read_document(&hash).expect("document should exist");
-        let stored1 = client.read_document(&hash1).expect("document should exist");
-        assert_eq!(stored.name, name);
-        assert_eq!(stored.hash, hash);
-        assert_eq!(stored1.hash, hash1);
+    // ---------- NAMESPACE STATS ----------
+
+    #[test]
+    fn namespace_counters_move_independently() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let (ns_a, admin_a, ns_b, admin_b) = two_namespaces(&env, &client);
+        let member = Address::generate(&env);
+        client.whitelist_address_ns(&ns_a, &admin_a, &member);
+        client.whitelist_address_ns(&ns_a, &admin_a, &member);
+        let name = String::from_str(&env, "Slice.pdf");
+        client.store_document_ns(&ns_a, &member, &name, &hash_n(&env, 1));
+        client.store_document_ns(&ns_a, &admin_a, &name, &hash_n(&env, 2));
+
+        let a = client.namespace_stats(&ns_a);
+        assert_eq!(a.admin, admin_a);
+        assert_eq!(a.created_at, 1_800_000_000);
+        assert_eq!((a.document_count, a.whitelist_count), (2, 1));
+        let b = client.namespace_stats(&ns_b);
+        assert_eq!(b.admin, admin_b);
+        assert_eq!((b.document_count, b.whitelist_count), (0, 0));
+
+        client.move_document(&admin_a, &ns_a, &ns_b, &hash_n(&env, 2));
+        client.remove_from_whitelist_ns(&ns_a, &admin_a, &member);
+        client.remove_from_whitelist_ns(&ns_a, &admin_a, &member);
+        let a = client.namespace_stats(&ns_a);
+        assert_eq!((a.document_count, a.whitelist_count), (1, 0));
+        let b = client.namespace_stats(&ns_b);
+        assert_eq!((b.document_count, b.whitelist_count), (1, 0));
     }
 
     #[test]
-    fn whitelist_add_two_and_remove_one_with_owner_auth() {
+    fn list_namespaces_pages_in_creation_order() {
         let env = Env::default();
         let (_owner, contract_addr) = setup(&env);
         let client = ContractClient::new(&env, &contract_addr);
-        // Allow require_auth to pass in tests
         env.mock_all_auths();
+        let (ns_a, _admin_a, ns_b, _admin_b) = two_namespaces(&env, &client);
 
-        // two random addresses
-        let a1 = Address::generate(&env);
-        let a2 = Address::generate(&env);
+        assert_eq!(client.list_namespaces(&0, &10), Vec::from_array(&env, [ns_a, ns_b.clone()]));
+        assert_eq!(client.list_namespaces(&1, &10), Vec::from_array(&env, [ns_b]));
+        assert!(client.list_namespaces(&2, &10).is_empty());
+    }
 
-        // add both to whitelist (owner-only method; mock_all_auths lets it pass)
-        client.whitelist_address(&a1);
-        client.whitelist_address(&a2);
+    #[test]
+    #[should_panic(expected = "namespace not found")]
+    fn namespace_stats_unknown_namespace_panics() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        client.namespace_stats(&symbol_short!("nope"));
+    }
 
-        // check both are whitelisted
-        assert!(client.is_whitelisted(&a1), "a1 should be whitelisted");
-        assert!(client.is_whitelisted(&a2), "a2 should be whitelisted");
+    // ---------- ABSENCE ATTESTATION ----------
 
-        // remove one (a1) and check states
-        client.remove_from_whitelist(&a1);
-        assert!(!client.is_whitelisted(&a1), "a1 should NOT be whitelisted anymore");
-        assert!(client.is_whitelisted(&a2), "a2 should remain whitelisted");
+    #[test]
+    fn attest_absence_anchors_missing_hash_in_an_event() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        let hash = hash_n(&env, 7);
+
+        let attestation = client.attest_absence(&hash);
+        assert!(attestation.absent);
+        assert_eq!(attestation.hash, hash);
+        assert_eq!(attestation.timestamp, 1_800_000_000);
+        assert_eq!(attestation.ledger_sequence, env.ledger().sequence());
+        let topics = (symbol_short!("document"), symbol_short!("absent"), hash);
+        let payload = (attestation.ledger_sequence, attestation.timestamp);
+        assert_last_event(&env, topics.into_val(&env), payload.into_val(&env));
     }
-     #[test]
-    fn store_document_by_whitelisted_user() {
+
+    #[test]
+    fn attest_absence_for_registered_hash_is_silent() {
         let env = Env::default();
         let (owner, contract_addr) = setup(&env);
         let client = ContractClient::new(&env, &contract_addr);
         env.mock_all_auths();
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "Here.pdf"), &hash);
+        let seq = client.last_event_seq();
 
-        // create & whitelist a non-owner user
-        let user = Address::generate(&env);
-        client.whitelist_address(&user);
+        let attestation = client.attest_absence(&hash);
+        assert!(!attestation.absent);
+        assert_eq!(client.last_event_seq(), seq);
+    }
 
-        let name = String::from_str(&env, "Whitelisted-Doc.pdf");
-        let hash = String::from_str(
-            &env,
-            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
-        );
+    // ---------- DUPLICATE POLICY ----------
 
-        // should succeed because `user` is whitelisted
-        client.store_document(&user, &name, &hash);
+    fn set_duplicate_policy(client: &ContractClient, policy: DuplicatePolicy) {
+        client.set_config(&Config {
+            duplicate_policy: policy,
+            ..client.get_config()
+        });
+    }
 
-        let stored = client.read_document(&hash).expect("document should exist");
-        assert_eq!(stored.name, name);
-        assert_eq!(stored.hash, hash);
-        assert_eq!(stored.added_by, user, "added_by must be the whitelisted caller");
-        assert!(stored.timestamp > 0);
+    #[test]
+    #[should_panic(expected = "Document already registered")]
+    fn duplicate_policy_reject_is_the_default() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        assert_eq!(client.get_config().duplicate_policy, DuplicatePolicy::Reject);
+        let name = String::from_str(&env, "Once.pdf");
+        client.store_document(&owner, &name, &hash_n(&env, 1));
+        client.store_document(&owner, &name, &hash_n(&env, 1));
     }
 
     #[test]
-    #[should_panic(expected = "not authorized: only owner or whitelisted address")]
-    fn store_document_by_non_whitelisted_user_panics() {
+    fn duplicate_policy_ignore_returns_existing_receipt() {
         let env = Env::default();
-        let (_owner, contract_addr) = setup(&env);
+        let (owner, contract_addr) = setup(&env);
         let client = ContractClient::new(&env, &contract_addr);
-        env.mock_all_auths(); // make require_auth() pass so we fail specifically on whitelist
+        env.mock_all_auths();
+        set_duplicate_policy(&client, DuplicatePolicy::Ignore);
+        let hash = hash_n(&env, 1);
+        let first = client.store_document(&owner, &String::from_str(&env, "First.pdf"), &hash);
+        let seq = client.last_event_seq();
+        advance_time(&env, 100);
 
-        // random user NOT added to whitelist
+        let again = client.store_document(&owner, &String::from_str(&env, "Second.pdf"), &hash);
+        assert_eq!(again, first);
+        assert_eq!(client.last_event_seq(), seq);
+        assert_eq!(client.document_count(), 1);
+        assert_eq!(client.read_document(&hash).unwrap().name, first.name);
+        assert_eq!(client.get_document_versions(&hash).len(), 1);
+    }
+
+    #[test]
+    fn duplicate_policy_version_appends_and_reads_latest() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        set_duplicate_policy(&client, DuplicatePolicy::Version);
         let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "v0.pdf"), &hash);
+        client.add_document_controller(&owner, &hash, &user);
+        advance_time(&env, 100);
+        let receipt = client.store_document(&user, &String::from_str(&env, "v1.pdf"), &hash);
+        assert_eq!(receipt.timestamp, 1_800_000_100);
+        let topics = (symbol_short!("document"), symbol_short!("versioned"), hash.clone());
+        assert_last_event(&env, topics.into_val(&env), (1u32, user.clone()).into_val(&env));
+        advance_time(&env, 100);
+        client.store_document(&owner, &String::from_str(&env, "v2.pdf"), &hash);
 
-        let name = String::from_str(&env, "Not-Whitelisted-Doc.pdf");
-        let hash = String::from_str(
-            &env,
-            "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb",
-        );
+        let latest = client.read_document(&hash).unwrap();
+        assert_eq!(latest.name, String::from_str(&env, "v2.pdf"));
+        assert_eq!(latest.timestamp, 1_800_000_200);
+        let verified = client.verify_document(&hash).unwrap();
+        assert_eq!(verified.timestamp, latest.timestamp);
+        assert_eq!(verified.added_by, latest.added_by);
+        assert_eq!(verified.sequence, latest.sequence);
+        assert_eq!(verified.registered_at, 1_800_000_000);
+        let versions = client.get_document_versions(&hash);
+        assert_eq!(versions.len(), 3);
+        let v0 = versions.get(0).unwrap();
+        assert_eq!((v0.version, v0.name), (0, String::from_str(&env, "v0.pdf")));
+        let v1 = versions.get(1).unwrap();
+        assert_eq!((v1.version, v1.added_by, v1.timestamp), (1, user, 1_800_000_100));
+        assert_eq!(versions.get(2).unwrap().version, 2);
+        assert_eq!(client.document_count(), 1);
+    }
 
-        // should panic because user is neither owner nor whitelisted
-        client.store_document(&user, &name, &hash);
+    #[test]
+    fn duplicate_policy_change_applies_to_later_stores_only() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        set_duplicate_policy(&client, DuplicatePolicy::Version);
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "v0.pdf"), &hash);
+        client.store_document(&owner, &String::from_str(&env, "v1.pdf"), &hash);
+
+        set_duplicate_policy(&client, DuplicatePolicy::Reject);
+        assert_eq!(client.get_document_versions(&hash).len(), 2);
+        let name = String::from_str(&env, "v2.pdf");
+        assert!(client.try_store_document(&owner, &name, &hash).is_err());
+        assert_eq!(client.read_document(&hash).unwrap().name, String::from_str(&env, "v1.pdf"));
     }
 
-#[test]
-#[should_panic(expected = "Document already registered")]
-fn store_document_duplicate_hash_panics() {
-    let env = Env::default();
-    let (owner, contract_addr) = setup(&env);
-    let client = ContractClient::new(&env, &contract_addr);
-    env.mock_all_auths();
+    #[test]
+    fn duplicate_policy_version_needs_submitter_or_controller() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        set_duplicate_policy(&client, DuplicatePolicy::Version);
+        let submitter = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        client.whitelist_address(&submitter);
+        client.whitelist_address(&stranger);
+        let hash = hash_n(&env, 1);
+        client.store_document(&submitter, &String::from_str(&env, "v0.pdf"), &hash);
 
-    let name1 = String::from_str(&env, "Doc-v1.pdf");
-    let name2 = String::from_str(&env, "Doc-v2.pdf");
-    let hash = String::from_str(
-        &env,
-        "deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
-    );
+        let name = String::from_str(&env, "v1.pdf");
+        assert!(client.try_store_document(&stranger, &name, &hash).is_err());
+        assert!(client.try_store_document(&owner, &name, &hash).is_err());
+        client.store_document(&submitter, &name, &hash);
+        assert_eq!(client.get_document_controllers(&hash), Vec::from_array(&env, [submitter]));
+        assert_eq!(client.get_document_versions(&hash).len(), 2);
+    }
 
-    // First insert succeeds
-    client.store_document(&owner, &name1, &hash);
+    #[test]
+    fn erasure_drops_document_versions() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        set_duplicate_policy(&client, DuplicatePolicy::Version);
+        let subject = store_with_subject(&env, &client, &owner);
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "Jane Doe.pdf"), &hash);
 
-    // Second insert with same hash MUST panic
-    client.store_document(&owner, &name2, &hash);
-}
+        client.request_deletion(&subject, &hash, &String::from_str(&env, "erase"));
+        client.approve_deletion(&hash);
+        let versions = client.get_document_versions(&hash);
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions.get(0).unwrap().name, String::from_str(&env, ""));
+        assert_eq!(client.read_document(&hash).unwrap().name, String::from_str(&env, ""));
+        env.as_contract(&contract_addr, || {
+            let storage = env.storage().persistent();
+            assert!(!storage.has(&DataKey::DocVersion(hash.clone(), 1)));
+            assert!(!storage.has(&DataKey::DocVersionCount(hash.clone())));
+        });
+    }
 
     #[test]
-fn reads_owner_address() {
-    let env = Env::default();
-    let (owner, contract_addr) = setup(&env);
-    let client = ContractClient::new(&env, &contract_addr);
-    let got = client.owner_address();
-    assert_eq!(got, owner);
-}
+    fn redaction_scrubs_document_versions() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        set_duplicate_policy(&client, DuplicatePolicy::Version);
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "v0.pdf"), &hash);
+        client.store_document(&owner, &String::from_str(&env, "Jane Doe.pdf"), &hash);
+
+        client.redact_document(&hash);
+        let marker = String::from_str(&env, REDACTION_MARKER);
+        let versions = client.get_document_versions(&hash);
+        assert_eq!(versions.len(), 2);
+        assert!(versions.iter().all(|v| v.name == marker));
+        assert_eq!(client.read_document(&hash).unwrap().name, marker);
+    }
+
     #[test]
-    fn transfer_ownership_with_owner_auth() {
+    #[should_panic(expected = "document not active")]
+    fn duplicate_policy_version_rejects_revoked_documents() {
         let env = Env::default();
         let (owner, contract_addr) = setup(&env);
         let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        set_duplicate_policy(&client, DuplicatePolicy::Version);
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "v0.pdf"), &hash);
+        client.revoke_document(&owner, &hash);
+        client.store_document(&owner, &String::from_str(&env, "v1.pdf"), &hash);
+    }
 
-        // allow require_auth to pass for the owner
+    // ---------- RENOUNCE ----------
+
+    #[test]
+    fn renounce_whitelist_drops_store_access_immediately() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
         env.mock_all_auths();
+        let user = Address::generate(&env);
+        client.whitelist_address(&user);
+        let name = String::from_str(&env, "Before.pdf");
+        client.store_document(&user, &name, &hash_n(&env, 1));
 
-        // transfer to a new owner address
-        let new_owner = Address::generate(&env);
-        client.transfer_ownership(&new_owner);
+        client.renounce_whitelist(&user);
+        let reason = String::from_str(&env, "renounced");
+        let topics = (symbol_short!("whitelist"), symbol_short!("removed"), user.clone());
+        let payload = (reason.clone(), 1_800_000_000u64);
+        assert_last_event(&env, topics.into_val(&env), payload.into_val(&env));
+        assert!(!client.is_whitelisted(&user));
+        assert_eq!(client.get_last_removal(&user).unwrap().reason, reason);
+        assert_eq!(client.get_principal_epoch(&user), 1);
+        let name = String::from_str(&env, "After.pdf");
+        assert!(client.try_store_document(&user, &name, &hash_n(&env, 2)).is_err());
+    }
 
-        // verify ownership changed
-        // If you implemented `owner_address`, use that:
-        let got = client.owner_address();
-        assert_eq!(got, new_owner, "ownership should be transferred to new_owner");
+    #[test]
+    #[should_panic(expected = "owner cannot renounce")]
+    fn renounce_whitelist_by_owner_panics() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        client.renounce_whitelist(&owner);
     }
 
     #[test]
-    #[should_panic] // should fail because owner did not authorize
-    fn transfer_ownership_without_owner_auth_panics() {
+    #[should_panic(expected = "not whitelisted")]
+    fn renounce_whitelist_when_not_whitelisted_panics() {
         let env = Env::default();
         let (_owner, contract_addr) = setup(&env);
         let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        client.renounce_whitelist(&Address::generate(&env));
+    }
 
-        // DO NOT mock auth here so require_auth() fails
-        let new_owner = Address::generate(&env);
+    // ---------- VERIFICATION ANCHORS ----------
 
-        // This should panic: current owner didn't authorize this call
-        client.transfer_ownership(&new_owner);
+    #[test]
+    fn anchor_verification_emits_status_and_counts() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "Proof.pdf"), &hash);
+        assert_eq!(client.verification_anchor_count(&hash), 0);
+
+        assert_eq!(client.anchor_verification(&hash), 1);
+        let topics = (symbol_short!("document"), Symbol::new(&env, "verified_at"), hash.clone());
+        let sequence = env.ledger().sequence();
+        let payload = (DocumentStatus::Active, sequence, 1_800_000_000u64);
+        assert_last_event(&env, topics.clone().into_val(&env), payload.into_val(&env));
+
+        advance_time(&env, 86_400);
+        client.revoke_document(&owner, &hash);
+        assert_eq!(client.anchor_verification(&hash), 2);
+        let payload = (DocumentStatus::Revoked, sequence, 1_800_086_400u64);
+        assert_last_event(&env, topics.into_val(&env), payload.into_val(&env));
+        assert_eq!(client.verification_anchor_count(&hash), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Document not found")]
+    fn anchor_verification_of_unknown_hash_panics() {
+        let env = Env::default();
+        let (_owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        client.anchor_verification(&hash_n(&env, 1));
     }
 
+    #[test]
+    #[should_panic(expected = "registry sealed")]
+    fn anchor_verification_after_seal_panics() {
+        let env = Env::default();
+        let (owner, contract_addr) = setup(&env);
+        let client = ContractClient::new(&env, &contract_addr);
+        env.mock_all_auths();
+        let hash = hash_n(&env, 1);
+        client.store_document(&owner, &String::from_str(&env, "Anchored.pdf"), &hash);
+        client.seal_registry();
+        client.anchor_verification(&hash);
+    }
 }
  
@@ -0,0 +1,59 @@
+//! Test fixture for contracts that integrate with the registry.
+//!
+//! Compiled for this crate's own tests and, for downstream test suites, under
+//! the `testutils` feature (which needs `soroban-sdk/testutils`).
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+use crate::{Contract, ContractClient};
+
+/// A registered, initialized registry with a generated owner.
+///
+/// Auth is left to the caller: `new` doesn't touch the `Env`'s auth mode, so
+/// the seeding helpers (owner-only calls) need the owner authorized first,
+/// e.g. with `env.mock_all_auths()`.
+pub struct TestRegistry {
+    client: ContractClient<'static>,
+    owner: Address,
+}
+
+impl TestRegistry {
+    pub fn new(env: &Env) -> Self {
+        let address = env.register_contract(None, Contract);
+        let client = ContractClient::new(env, &address);
+        let owner = Address::generate(env);
+        client.init(&owner);
+        TestRegistry { client, owner }
+    }
+
+    /// Store `(name, hash)` pairs as the owner, in order
+    pub fn seed_documents(&self, docs: &[(&str, &str)]) {
+        let env = &self.client.env;
+        for (name, hash) in docs {
+            let name = String::from_str(env, name);
+            let hash = String::from_str(env, hash);
+            self.client.store_document(&self.owner, &name, &hash);
+        }
+    }
+
+    /// Whitelist every address in `addresses`
+    pub fn seed_whitelist(&self, addresses: &[Address]) {
+        for address in addresses {
+            self.client.whitelist_address(address);
+        }
+    }
+
+    pub fn client(&self) -> &ContractClient<'static> {
+        &self.client
+    }
+
+    pub fn owner(&self) -> &Address {
+        &self.owner
+    }
+
+    /// Address the registry is deployed at
+    pub fn address(&self) -> &Address {
+        &self.client.address
+    }
+}